@@ -0,0 +1,59 @@
+//! Micro-benchmarks des algorithmes du jour 1 sur des entrées générées de différentes tailles
+//! Lancer avec `cargo bench -p aoc2024-1`
+
+// Importation des bibliothèques nécessaires
+use aoc2024_1::{
+    calculate_similarity_score, calculate_total_distance, histogram_distance_and_similarity,
+    parse_data, Day1,
+}; // Les algorithmes mesurés
+use aoc_utils::generate::InputGenerator; // Pour générer les entrées mesurées
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion}; // Le cadre de mesure
+use std::hint::black_box; // Pour empêcher le compilateur d'éliminer le calcul
+
+/// Nombres de paires des entrées générées
+const SIZES: [usize; 3] = [1_000, 100_000, 1_000_000];
+
+/// Fonction pour générer deux listes de `n` identifiants avec le générateur d'entrées du jour 1
+/// Graine fixe : les mesures portent toujours sur les mêmes données
+fn generate_lists(n: usize) -> (Vec<i64>, Vec<i64>) {
+    parse_data(&Day1.generate(n, 42)).expect("entrée générée invalide")
+}
+
+/// Mesure les deux parties pour chaque taille d'entrée
+/// Les listes sont clonées hors de la mesure, car les fonctions les consomment
+/// Les sommes tiennent dans `i64` à toutes ces tailles : aucune mesure ne s'arrête sur un dépassement
+fn bench_day1(c: &mut Criterion) {
+    let mut group = c.benchmark_group("jour1");
+    group.sample_size(20);
+    for n in SIZES {
+        let lists = generate_lists(n);
+        group.bench_with_input(
+            BenchmarkId::new("distance_totale", n),
+            &lists,
+            |b, lists| {
+                b.iter_batched(
+                    || lists.clone(),
+                    |(left, right)| black_box(calculate_total_distance(left, right)),
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("similarite", n), &lists, |b, lists| {
+            b.iter_batched(
+                || lists.clone(),
+                |(left, right)| black_box(calculate_similarity_score(left, right)),
+                BatchSize::LargeInput,
+            )
+        });
+        // Les deux parties en un seul passage sur les histogrammes, sans clonage (les listes sont empruntées)
+        group.bench_with_input(
+            BenchmarkId::new("histogrammes", n),
+            &lists,
+            |b, (left, right)| b.iter(|| black_box(histogram_distance_and_similarity(left, right))),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_day1);
+criterion_main!(benches);
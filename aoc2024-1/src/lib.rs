@@ -0,0 +1,390 @@
+//! Jour 1 : distance totale et score de similarité entre deux listes d'identifiants
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::answer::Answer; // La réponse d'une partie
+use aoc_utils::checked::Checked; // Pour signaler les dépassements de capacité au lieu d'une réponse fausse
+use aoc_utils::counter::Counter; // Pour compter les occurrences de la liste de droite
+use aoc_utils::error::AocError; // Pour signaler une entrée mal formée ou absente
+use aoc_utils::generate::{seeded_rng, InputGenerator}; // Pour générer des entrées synthétiques
+use aoc_utils::input::{read_pairs_from, read_pairs_streaming, InputSource}; // Pour lire l'entrée depuis un fichier, l'entrée standard ou un texte
+use aoc_utils::parse::parse_columns; // Pour lire les deux colonnes de l'entrée
+use aoc_utils::solution::{Solution, Variant}; // L'interface commune aux solutions
+use std::path::Path; // Pour manipuler les chemins de fichiers
+use std::str::FromStr; // Pour choisir l'algorithme depuis la ligne de commande
+use tracing::{debug, debug_span, Level}; // Pour suivre les étapes intermédiaires avec `aoc2024 -v`
+
+#[cfg(feature = "viz")]
+mod visualize; // Animation des deux parties dans le terminal
+
+/// Fonction pour lire les données d'un fichier et retourner deux listes d'entiers
+/// `P` est un type générique qui représente un chemin vers le fichier
+/// Un fichier absent ou une ligne mal formée donne une erreur au lieu d'une réponse fausse
+pub fn read_data_from_file<P>(filename: P) -> Result<(Vec<i64>, Vec<i64>), AocError>
+where
+    P: AsRef<Path>, // Cette contrainte signifie que `P` doit pouvoir être converti en un chemin de type `Path`
+{
+    read_data(&InputSource::File(filename.as_ref().to_path_buf()))
+}
+
+/// Fonction pour lire les données de n'importe quelle source et retourner deux listes d'entiers
+/// Les fichiers et l'entrée standard sont lus par morceaux, sans garder tout le texte en mémoire
+pub fn read_data(source: &InputSource) -> Result<(Vec<i64>, Vec<i64>), AocError> {
+    match source {
+        InputSource::File(path) => read_pairs_streaming(path, Some(1)),
+        InputSource::Stdin => read_pairs_from(std::io::stdin().lock()),
+        InputSource::Inline(input) => parse_data(input),
+    }
+}
+
+/// Fonction pour analyser le texte de l'entrée et retourner deux listes d'entiers
+/// Chaque ligne non vide doit contenir exactement deux entiers, sinon l'erreur indique la ligne fautive
+///
+/// ```
+/// let (left, right) = aoc2024_1::parse_data("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+/// assert_eq!(aoc2024_1::calculate_total_distance(left, right).unwrap(), 11);
+/// ```
+pub fn parse_data(input: &str) -> Result<(Vec<i64>, Vec<i64>), AocError> {
+    // Lit les deux colonnes de nombres
+    let mut columns = parse_columns::<i64>(input, 2)?;
+
+    // Retourne les deux listes dans un tuple
+    let right_list = columns.pop().unwrap_or_default();
+    let left_list = columns.pop().unwrap_or_default();
+    Ok((left_list, right_list))
+}
+
+/// Fonction pour calculer la distance totale entre deux listes triées
+/// Une distance qui dépasse `i64` donne une erreur plutôt qu'une réponse fausse
+pub fn calculate_total_distance(
+    mut left_list: Vec<i64>,
+    mut right_list: Vec<i64>,
+) -> Result<i64, AocError> {
+    // Trie les deux listes en place (ordre croissant)
+    left_list.sort();
+    right_list.sort();
+
+    // Associe les éléments des deux listes dans l'ordre trié avec `zip`
+    // Calcule la différence absolue entre chaque paire, et somme toutes les différences
+    left_list
+        .iter() // Crée un itérateur sur les éléments de `left_list`
+        .zip(right_list.iter()) // Associe chaque élément de `left_list` avec l'élément correspondant de `right_list`
+        .map(|(&l, &r)| (Checked::new(l) - r).abs()) // Calcule la différence absolue entre les deux éléments
+        .sum::<Checked>() // Calcule la somme des différences
+        .get("distance totale")
+}
+
+/// Fonction pour calculer le score de similarité entre les deux listes
+/// Quand les valeurs sont assez groupées, les occurrences sont comptées dans des histogrammes
+/// plutôt que dans un dictionnaire
+/// Un score qui dépasse `i64` donne une erreur plutôt qu'une réponse fausse
+pub fn calculate_similarity_score(
+    left_list: Vec<i64>,
+    right_list: Vec<i64>,
+) -> Result<i64, AocError> {
+    if let Some(range) = value_range(&left_list, &right_list) {
+        let left = Histogram::build(&left_list, range);
+        let right = Histogram::build(&right_list, range);
+        return histogram_similarity(&left, &right);
+    }
+    similarity_with_hashmap(&left_list, &right_list)
+}
+
+/// Fonction pour calculer le score de similarité en comptant les occurrences dans un dictionnaire,
+/// quelle que soit l'étendue des valeurs
+pub fn similarity_with_hashmap(left_list: &[i64], right_list: &[i64]) -> Result<i64, AocError> {
+    // Compte les occurrences des éléments de la liste de droite
+    let right_counts: Counter<i64> = right_list.iter().copied().collect();
+
+    // Calcule le score de similarité : chaque élément de gauche compte autant de fois qu'il apparaît à droite
+    left_list
+        .iter()
+        .map(|&num| Checked::from(right_counts.get(&num) as u64) * num)
+        .sum::<Checked>()
+        .get("score de similarité")
+}
+
+/// Étendue maximale des valeurs (plus grande moins plus petite, plus un) pour utiliser les histogrammes
+/// Au-delà, les histogrammes coûteraient plus de mémoire que le tri ne coûte de temps
+pub const HISTOGRAM_MAX_RANGE: usize = 1 << 20;
+
+/// Algorithme utilisé pour la distance totale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Algorithm {
+    /// Histogrammes si l'étendue des valeurs le permet, tri sinon
+    #[default]
+    Auto,
+    /// Tri des deux listes puis association dans l'ordre
+    Sort,
+    /// Histogrammes parcourus dans l'ordre croissant (tri si l'étendue dépasse `HISTOGRAM_MAX_RANGE`)
+    Histogram,
+}
+
+impl FromStr for Algorithm {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Algorithm::Auto),
+            "sort" => Ok(Algorithm::Sort),
+            "histogram" => Ok(Algorithm::Histogram),
+            _ => Err(format!(
+                "algorithme inconnu {:?} : attendu auto, sort ou histogram",
+                s
+            )),
+        }
+    }
+}
+
+/// Plage de valeurs couverte par un histogramme : la plus petite valeur et le nombre de cases
+#[derive(Debug, Clone, Copy)]
+struct ValueRange {
+    min: i64,
+    len: usize,
+}
+
+/// Fonction pour calculer la plage commune des valeurs des deux listes
+/// Retourne `None` si les listes sont vides ou si l'étendue dépasse `HISTOGRAM_MAX_RANGE`
+fn value_range(left_list: &[i64], right_list: &[i64]) -> Option<ValueRange> {
+    let values = left_list.iter().chain(right_list);
+    let min = *values.clone().min()?;
+    let max = *values.max()?;
+    let len = usize::try_from(max.abs_diff(min)).ok()?.checked_add(1)?;
+    (len <= HISTOGRAM_MAX_RANGE).then_some(ValueRange { min, len })
+}
+
+/// Nombre d'occurrences de chaque valeur d'une liste ; la case `i` compte la valeur `min + i`
+struct Histogram {
+    min: i64,
+    counts: Vec<u32>,
+}
+
+impl Histogram {
+    /// Compte les valeurs d'une liste, qui doivent toutes être dans `range`
+    fn build(values: &[i64], range: ValueRange) -> Self {
+        let mut counts = vec![0; range.len];
+        for &value in values {
+            counts[value.abs_diff(range.min) as usize] += 1;
+        }
+        Histogram {
+            min: range.min,
+            counts,
+        }
+    }
+
+    /// La valeur comptée dans la case `index`
+    fn value(&self, index: usize) -> i64 {
+        self.min + index as i64
+    }
+}
+
+/// Fonction pour calculer la distance totale à partir des histogrammes des deux listes
+/// Deux curseurs parcourent les valeurs dans l'ordre croissant : le k-ième plus petit élément de gauche
+/// est associé au k-ième plus petit de droite, exactement comme après un tri, en O(n + étendue)
+fn histogram_distance(left: &Histogram, right: &Histogram) -> Result<i64, AocError> {
+    let (mut i, mut j) = (0, 0);
+    let (mut left_remaining, mut right_remaining) = (0, 0);
+    let mut distance = Checked::default();
+    loop {
+        // Avance chaque curseur jusqu'à la prochaine valeur présente
+        while left_remaining == 0 && i < left.counts.len() {
+            left_remaining = left.counts[i];
+            i += 1;
+        }
+        while right_remaining == 0 && j < right.counts.len() {
+            right_remaining = right.counts[j];
+            j += 1;
+        }
+        if left_remaining == 0 || right_remaining == 0 {
+            return distance.get("distance totale"); // Une des listes est épuisée
+        }
+
+        // Associe autant d'éléments que possible entre les deux valeurs courantes
+        let pairs = left_remaining.min(right_remaining);
+        // Les deux valeurs sont dans la même plage, de moins de `HISTOGRAM_MAX_RANGE` cases
+        let gap = (left.value(i - 1) - right.value(j - 1)).abs();
+        distance = distance + Checked::new(gap) * i64::from(pairs);
+        left_remaining -= pairs;
+        right_remaining -= pairs;
+    }
+}
+
+/// Fonction pour calculer le score de similarité à partir des histogrammes des deux listes
+/// Chaque valeur contribue `valeur × occurrences à gauche × occurrences à droite`
+fn histogram_similarity(left: &Histogram, right: &Histogram) -> Result<i64, AocError> {
+    left.counts
+        .iter()
+        .zip(&right.counts)
+        .enumerate()
+        .map(|(index, (&l, &r))| Checked::from(u64::from(l) * u64::from(r)) * left.value(index))
+        .sum::<Checked>()
+        .get("score de similarité")
+}
+
+/// Fonction pour calculer la distance totale avec l'algorithme choisi
+pub fn total_distance_with(
+    left_list: Vec<i64>,
+    right_list: Vec<i64>,
+    algorithm: Algorithm,
+) -> Result<i64, AocError> {
+    let range = match algorithm {
+        Algorithm::Sort => None,
+        Algorithm::Auto | Algorithm::Histogram => value_range(&left_list, &right_list),
+    };
+    match range {
+        Some(range) => histogram_distance(
+            &Histogram::build(&left_list, range),
+            &Histogram::build(&right_list, range),
+        ),
+        None => calculate_total_distance(left_list, right_list),
+    }
+}
+
+/// Fonction pour calculer la distance totale et le score de similarité avec les mêmes histogrammes,
+/// en O(n + étendue) et sans tri ni dictionnaire
+/// Retourne `Ok(None)` si l'étendue des valeurs dépasse `HISTOGRAM_MAX_RANGE`
+pub fn histogram_distance_and_similarity(
+    left_list: &[i64],
+    right_list: &[i64],
+) -> Result<Option<(i64, i64)>, AocError> {
+    let Some(range) = value_range(left_list, right_list) else {
+        return Ok(None);
+    };
+    let left = Histogram::build(left_list, range);
+    let right = Histogram::build(right_list, range);
+    Ok(Some((
+        histogram_distance(&left, &right)?,
+        histogram_similarity(&left, &right)?,
+    )))
+}
+
+/// Fonction pour analyser l'entrée dans sa propre phase de trace, en indiquant ce qui a été lu
+fn parse_traced(input: &str) -> Result<(Vec<i64>, Vec<i64>), AocError> {
+    let _span = debug_span!("analyse").entered();
+    let (left_list, right_list) = parse_data(input)?;
+    let blank_lines = input.lines().filter(|line| line.trim().is_empty()).count();
+    debug!(paires = left_list.len(), lignes_vides = blank_lines, "entrée analysée");
+    Ok((left_list, right_list))
+}
+
+/// Fonction pour tracer les 5 plus grandes distances entre paires (après tri), seulement si les traces
+/// de débogage sont affichées : le tri supplémentaire n'est pas payé sinon
+fn trace_largest_distances(left_list: &[i64], right_list: &[i64]) {
+    if !tracing::enabled!(Level::DEBUG) {
+        return;
+    }
+    let (mut left, mut right) = (left_list.to_vec(), right_list.to_vec());
+    left.sort();
+    right.sort();
+    let mut pairs: Vec<(u64, i64, i64)> = left
+        .into_iter()
+        .zip(right)
+        .map(|(l, r)| (l.abs_diff(r), l, r))
+        .collect();
+    pairs.sort_unstable_by(|a, b| b.cmp(a));
+    pairs.truncate(5);
+    debug!(?pairs, "plus grandes distances (distance, gauche, droite)");
+}
+
+/// Première partie en triant les deux listes puis en les associant avec `zip`
+pub fn part1_sort_zip(input: &str) -> Result<Answer, AocError> {
+    let (left_list, right_list) = parse_data(input)?;
+    Ok(calculate_total_distance(left_list, right_list)?.into())
+}
+
+/// Première partie avec les histogrammes (le tri reste utilisé si l'étendue des valeurs est trop grande)
+pub fn part1_histogram(input: &str) -> Result<Answer, AocError> {
+    let (left_list, right_list) = parse_data(input)?;
+    Ok(total_distance_with(left_list, right_list, Algorithm::Histogram)?.into())
+}
+
+/// Deuxième partie en comptant les occurrences dans un dictionnaire
+pub fn part2_hashmap(input: &str) -> Result<Answer, AocError> {
+    let (left_list, right_list) = parse_data(input)?;
+    Ok(similarity_with_hashmap(&left_list, &right_list)?.into())
+}
+
+/// Deuxième partie avec les histogrammes (le dictionnaire reste utilisé si l'étendue est trop grande)
+pub fn part2_histogram(input: &str) -> Result<Answer, AocError> {
+    let (left_list, right_list) = parse_data(input)?;
+    Ok(calculate_similarity_score(left_list, right_list)?.into())
+}
+
+/// Les implémentations de la première partie comparées par `aoc2024 run --compare`
+const PART1_VARIANTS: &[Variant] = &[
+    Variant {
+        name: "tri",
+        run: part1_sort_zip,
+    },
+    Variant {
+        name: "histogramme",
+        run: part1_histogram,
+    },
+];
+
+/// Les implémentations de la deuxième partie comparées par `aoc2024 run --compare`
+const PART2_VARIANTS: &[Variant] = &[
+    Variant {
+        name: "dictionnaire",
+        run: part2_hashmap,
+    },
+    Variant {
+        name: "histogramme",
+        run: part2_histogram,
+    },
+];
+
+/// Solution du jour 1, enregistrée dans le lanceur `aoc2024`
+pub struct Day1;
+
+impl Solution for Day1 {
+    /// Première partie : la distance totale entre les deux listes
+    fn part1(&self, input: &str) -> Result<Answer, AocError> {
+        let (left_list, right_list) = parse_traced(input)?;
+        trace_largest_distances(&left_list, &right_list);
+        Ok(total_distance_with(left_list, right_list, Algorithm::Auto)?.into())
+    }
+
+    /// Deuxième partie : le score de similarité entre les deux listes
+    fn part2(&self, input: &str) -> Result<Answer, AocError> {
+        let (left_list, right_list) = parse_traced(input)?;
+        Ok(calculate_similarity_score(left_list, right_list)?.into())
+    }
+
+    fn description(&self) -> &'static str {
+        "Partie 1 : les deux listes sont associées dans l'ordre croissant, par histogrammes quand \
+         les identifiants sont assez groupés (O(n + étendue)), par tri sinon (O(n log n)). \
+         Partie 2 : les occurrences de la liste de droite sont comptées (histogramme ou \
+         dictionnaire), puis chaque identifiant de gauche est multiplié par son nombre \
+         d'occurrences."
+    }
+
+    /// Le tri et les histogrammes pour la première partie, le dictionnaire et les histogrammes
+    /// pour la deuxième
+    fn variants(&self, part: u8) -> &'static [Variant] {
+        match part {
+            1 => PART1_VARIANTS,
+            _ => PART2_VARIANTS,
+        }
+    }
+}
+
+impl InputGenerator for Day1 {
+    /// `size` paires d'identifiants à 5 chiffres séparés par trois espaces, comme les vraies entrées :
+    /// environ la moitié des identifiants de droite reprennent quelques identifiants populaires de
+    /// gauche (répétés jusqu'à une vingtaine de fois), ce qui donne un score de similarité non nul
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let mut rng = seeded_rng(seed);
+        let left: Vec<i64> = (0..size).map(|_| rng.i64(10_000..100_000)).collect();
+        let popular = &left[..left.len().div_ceil(40)];
+        left.iter()
+            .map(|l| {
+                let r = if rng.bool() {
+                    popular[rng.usize(..popular.len())]
+                } else {
+                    rng.i64(10_000..100_000)
+                };
+                format!("{}   {}\n", l, r)
+            })
+            .collect()
+    }
+}
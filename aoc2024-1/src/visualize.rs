@@ -0,0 +1,100 @@
+// Importation des bibliothèques nécessaires
+use crate::{parse_data, Day1}; // La lecture de l'entrée et la solution animée
+use aoc_utils::checked::Checked; // Pour signaler les dépassements de capacité
+use aoc_utils::counter::Counter; // Pour compter les occurrences de la liste de droite
+use aoc_utils::error::AocError; // Pour signaler une entrée mal formée
+use aoc_utils::visualize::{Frame, Visualize}; // L'interface des animations
+
+/// Nombre maximal d'images d'une animation : sur une grande entrée, chaque image avance de plusieurs lignes
+const MAX_FRAMES: usize = 300;
+
+/// Nombre de lignes visibles autour de la ligne courante
+const WINDOW: usize = 20;
+
+impl Visualize for Day1 {
+    /// Partie 1 : les deux listes triées, associées ligne par ligne avec la distance de chaque paire
+    /// Partie 2 : chaque valeur de gauche, son nombre d'occurrences à droite et sa contribution au score
+    fn frames(&self, input: &str, part: u8) -> Result<Vec<Frame>, AocError> {
+        let (mut left_list, mut right_list) = parse_data(input)?;
+        let rows: Result<Vec<(String, i64)>, AocError> = match part {
+            1 => {
+                left_list.sort();
+                right_list.sort();
+                left_list
+                    .iter()
+                    .zip(&right_list)
+                    .map(|(&l, &r)| {
+                        let distance = (Checked::new(l) - r).abs().get("distance")?;
+                        Ok((format!("{:>8} ↔ {:<8} {:>8}", l, r, distance), distance))
+                    })
+                    .collect()
+            }
+            _ => {
+                let right_counts: Counter<i64> = right_list.iter().copied().collect();
+                left_list
+                    .iter()
+                    .map(|&num| {
+                        let count = right_counts.get(&num) as u64;
+                        let score = (Checked::from(count) * num).get("score")?;
+                        let row = format!("{:>8} × {:<4} {:>10}", num, count, score);
+                        Ok((row, score))
+                    })
+                    .collect()
+            }
+        };
+        let (rows, contributions): (Vec<String>, Vec<i64>) = rows?.into_iter().unzip();
+        build_frames(part, &rows, &contributions)
+    }
+}
+
+/// Fonction pour construire les images : une fenêtre de lignes qui défile, la ligne courante mise en
+/// valeur, et le total cumulé dans le titre
+/// Un total qui dépasse `i64` donne une erreur, comme dans la solution
+fn build_frames(part: u8, rows: &[String], contributions: &[i64]) -> Result<Vec<Frame>, AocError> {
+    let label = match part {
+        1 => "distance",
+        _ => "score",
+    };
+    let step = rows.len().div_ceil(MAX_FRAMES).max(1);
+    let mut frames = Vec::new();
+    let mut total = Checked::default();
+    let mut done = 0;
+    for current in (0..rows.len()).step_by(step) {
+        // Ajoute les contributions des lignes passées depuis l'image précédente
+        total = total
+            + contributions[done..=current]
+                .iter()
+                .copied()
+                .sum::<Checked>();
+        done = current + 1;
+        let start = current.saturating_sub(WINDOW / 2);
+        let end = (start + WINDOW).min(rows.len());
+        frames.push(Frame {
+            title: format!(
+                " Jour 1, partie {} : ligne {}/{}, {} {} ",
+                part,
+                current + 1,
+                rows.len(),
+                label,
+                total.get(label)?
+            ),
+            lines: rows[start..end].to_vec(),
+            highlight: Some(current - start),
+        });
+    }
+
+    // La dernière image montre le résultat complet
+    total = total + contributions[done..].iter().copied().sum::<Checked>();
+    let start = rows.len().saturating_sub(WINDOW);
+    frames.push(Frame {
+        title: format!(
+            " Jour 1, partie {} : {} totale {} ",
+            part,
+            label,
+            total.get(label)?
+        ),
+        lines: rows[start..].to_vec(),
+        highlight: None,
+    });
+    Ok(frames)
+}
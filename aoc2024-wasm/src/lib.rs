@@ -0,0 +1,32 @@
+//! Les solutions compilées en WebAssembly, pour une page web où l'entrée est collée et résolue
+//! directement dans le navigateur
+//! Construire avec `wasm-pack build aoc2024-wasm --target web`, puis depuis JavaScript :
+//! ```js
+//! import init, { solve } from "./pkg/aoc2024_wasm.js";
+//! await init();
+//! solve(1, 1, texteDeLEntree);
+//! ```
+
+// Importation des bibliothèques nécessaires
+use aoc2024::registry; // Les solutions, qui ne lisent que du texte
+use wasm_bindgen::prelude::*; // Pour exposer les fonctions à JavaScript
+
+/// Fonction pour résoudre une partie (1 ou 2) d'un jour sur le texte de l'entrée
+/// Retourne la réponse affichée, ou un message commençant par `Erreur : ` si le jour n'est pas
+/// implémenté ou si l'entrée est mal formée
+#[wasm_bindgen]
+pub fn solve(day: u8, part: u8, input: &str) -> String {
+    let Some(solution) = registry::get(day) else {
+        return format!("Erreur : le jour {} n'est pas encore implémenté", day);
+    };
+    match registry::run_part(solution, part, input) {
+        Ok(answer) => answer.to_string(),
+        Err(e) => format!("Erreur : {}", e),
+    }
+}
+
+/// Fonction pour lister les jours implémentés, par exemple pour remplir un menu de la page
+#[wasm_bindgen]
+pub fn days() -> Vec<u8> {
+    registry::DAYS.iter().map(|&(day, _)| day).collect()
+}
@@ -0,0 +1,58 @@
+//! Génération du registre des jours de 2024 à partir des membres de l'espace de travail
+//! Chaque membre `aoc2024-N` devient l'entrée `(N, &aoc2024_N::DayN)` du tableau `DAYS`,
+//! écrit dans `$OUT_DIR/days.rs` et inclus par `src/registry.rs`
+//! Le paquet doit aussi figurer dans les `[dependencies]` de `aoc2024/Cargo.toml` (ce que fait
+//! `aoc2024 new`) : un membre sans cette dépendance est signalé par un avertissement et ignoré
+
+// Importation des bibliothèques nécessaires
+use std::env; // Pour les dossiers du paquet et de sortie
+use std::fs; // Pour lire les manifestes et écrire le registre
+use std::path::Path; // Pour les chemins des fichiers
+
+#[path = "build/members.rs"]
+mod members; // Pour lire les membres et les dépendances dans les manifestes
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR absent");
+    let workspace = Path::new(&manifest_dir).join("../Cargo.toml");
+    let runner = Path::new(&manifest_dir).join("Cargo.toml");
+    println!("cargo:rerun-if-changed=../Cargo.toml");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=build/members.rs");
+
+    let read = |path: &Path| {
+        fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("lecture de {} impossible : {}", path.display(), e))
+    };
+    let runner_manifest = read(&runner);
+    let mut days = members::day_members(&read(&workspace));
+    days.sort_unstable();
+    days.dedup();
+    // Sans la dépendance, `aoc2024_N` serait introuvable à la compilation du registre
+    days.retain(|day| {
+        let name = format!("aoc2024-{day}");
+        let declared = members::has_dependency(&runner_manifest, &name);
+        if !declared {
+            println!(
+                "cargo:warning=le membre {name} n'est pas une dépendance de aoc2024/Cargo.toml : \
+                 ajoutez `{name} = {{ path = \"../{name}\" }}` à [dependencies] pour enregistrer le jour {day}"
+            );
+        }
+        declared
+    });
+
+    let entries: Vec<String> = days
+        .iter()
+        .map(|day| format!("    ({day}, &aoc2024_{day}::Day{day}),\n"))
+        .collect();
+    let source = format!(
+        "/// Toutes les solutions implémentées de 2024, indexées par numéro de jour\n\
+         /// Généré par `build.rs` : chaque membre `aoc2024-N` dont dépend le lanceur y figure\n\
+         pub static DAYS: &[(u8, &dyn Solution)] = &[\n{}];\n",
+        entries.concat()
+    );
+    let out = Path::new(&env::var("OUT_DIR").expect("OUT_DIR absent")).join("days.rs");
+    fs::write(&out, source)
+        .unwrap_or_else(|e| panic!("écriture de {} impossible : {}", out.display(), e));
+}
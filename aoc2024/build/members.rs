@@ -0,0 +1,43 @@
+//! Lecture des manifestes de l'espace de travail, partagée entre `build.rs` et les tests du registre
+//! Les manifestes sont lus à la main pour ne pas ajouter de dépendance de compilation
+
+/// Fonction pour extraire les numéros des jours `aoc2024-N` de `workspace.members`
+/// Le tableau peut s'étendre sur plusieurs lignes ; les commentaires `#` y sont ignorés
+pub fn day_members(manifest: &str) -> Vec<u8> {
+    let manifest = strip_comments(manifest);
+    let start = manifest
+        .find("members")
+        .and_then(|at| manifest[at..].find('[').map(|open| at + open + 1))
+        .expect("`workspace.members` absent de Cargo.toml");
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .expect("fin de `workspace.members` introuvable");
+    manifest[start..end]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter_map(|member| member.strip_prefix("aoc2024-"))
+        .filter_map(|day| day.parse().ok())
+        .collect()
+}
+
+/// Fonction pour savoir si la section `[dependencies]` du manifeste déclare le paquet `name`
+pub fn has_dependency(manifest: &str, name: &str) -> bool {
+    strip_comments(manifest)
+        .lines()
+        .map(str::trim)
+        .skip_while(|line| *line != "[dependencies]")
+        .skip(1)
+        .take_while(|line| !line.starts_with('['))
+        .filter_map(|line| line.split_once('='))
+        .any(|(key, _)| key.trim().trim_matches('"') == name)
+}
+
+/// Retire les commentaires `#` de chaque ligne du manifeste
+fn strip_comments(manifest: &str) -> String {
+    manifest
+        .lines()
+        .map(|line| line.split_once('#').map_or(line, |(code, _)| code))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,90 @@
+// Importation des bibliothèques nécessaires
+use serde::{Deserialize, Serialize}; // Pour lire et écrire le fichier des réponses
+use std::collections::BTreeMap; // Les jours du fichier, dans l'ordre
+use std::fs; // Pour lire et écrire le fichier des réponses
+use std::io; // Pour les erreurs de lecture et d'écriture
+use std::path::Path; // Pour le chemin du fichier des réponses
+
+/// Fichier des réponses confirmées, à la racine du dépôt
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Réponses confirmées des deux parties d'un jour
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+/// Toutes les réponses confirmées, indexées par jour (`day01`, `day02`, ...)
+/// Exemple de fichier :
+/// ```toml
+/// [day01]
+/// part1 = "2430334"
+/// part2 = "28786472"
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    days: BTreeMap<String, DayAnswers>,
+}
+
+/// Clé d'un jour dans le fichier, comme `day01`
+fn day_key(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+impl Answers {
+    /// Lit le fichier des réponses ; aucune réponse n'est connue s'il n'existe pas encore
+    pub fn load() -> io::Result<Answers> {
+        match fs::read_to_string(ANSWERS_FILE) {
+            Ok(text) => toml::from_str(&text).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{} invalide : {}", ANSWERS_FILE, e),
+                )
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Réécrit le fichier des réponses
+    pub fn save(&self) -> io::Result<()> {
+        let text = toml::to_string(self).map_err(io::Error::other)?;
+        fs::write(Path::new(ANSWERS_FILE), text)
+    }
+
+    /// Retourne la réponse confirmée d'une partie, si elle est connue
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        let answers = self.days.get(&day_key(day))?;
+        match part {
+            1 => answers.part1.as_deref(),
+            _ => answers.part2.as_deref(),
+        }
+    }
+
+    /// Enregistre la réponse confirmée d'une partie (en remplaçant l'ancienne)
+    pub fn set(&mut self, day: u8, part: u8, answer: String) {
+        let answers = self.days.entry(day_key(day)).or_default();
+        match part {
+            1 => answers.part1 = Some(answer),
+            _ => answers.part2 = Some(answer),
+        }
+    }
+
+    /// Toutes les réponses connues, sous la forme `(jour, partie, réponse)`, dans l'ordre
+    pub fn iter(&self) -> impl Iterator<Item = (u8, u8, &str)> {
+        // Les clés qui ne sont pas de la forme `dayNN` sont ignorées
+        let days = self.days.iter().filter_map(|(key, answers)| {
+            let day: u8 = key.strip_prefix("day")?.parse().ok()?;
+            Some((day, answers))
+        });
+        days.flat_map(|(day, answers)| {
+            [(1, &answers.part1), (2, &answers.part2)]
+                .into_iter()
+                .filter_map(move |(part, answer)| Some((day, part, answer.as_deref()?)))
+        })
+    }
+}
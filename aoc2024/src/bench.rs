@@ -0,0 +1,73 @@
+// Importation des bibliothèques nécessaires
+use crate::registry::run_part; // Pour exécuter une partie d'une solution
+use aoc_utils::error::AocError; // L'erreur retournée par les solutions
+use aoc_utils::solution::Solution; // L'interface commune aux solutions
+use serde::Serialize; // Pour la sortie JSON
+use std::time::{Duration, Instant}; // Pour mesurer les temps d'exécution
+
+/// Temps d'exécution mesurés pour une partie d'un jour
+#[derive(Debug, Clone, Serialize)]
+pub struct PartTiming {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,  // Réponse obtenue (la même à chaque itération)
+    pub iterations: u32, // Nombre d'exécutions mesurées
+    pub min_us: u64,     // Temps le plus court, en microsecondes
+    pub median_us: u64,  // Temps médian, en microsecondes
+    pub max_us: u64,     // Temps le plus long, en microsecondes
+}
+
+/// Fonction pour mesurer une partie : `warmup` exécutions non mesurées, puis `iterations` exécutions
+/// chronométrées une par une
+/// Panique si `iterations` vaut 0 ; s'arrête à la première erreur de la solution
+pub fn bench_part(
+    solution: &dyn Solution,
+    day: u8,
+    part: u8,
+    input: &str,
+    warmup: u32,
+    iterations: u32,
+) -> Result<PartTiming, AocError> {
+    assert!(iterations > 0, "il faut au moins une itération mesurée");
+
+    // Échauffement : remplit les caches et laisse le processeur monter en fréquence
+    for _ in 0..warmup {
+        run_part(solution, part, input)?;
+    }
+
+    let mut answer = None;
+    let mut durations: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let start = Instant::now();
+            answer = Some(run_part(solution, part, input)?);
+            Ok(start.elapsed())
+        })
+        .collect::<Result<_, AocError>>()?;
+    durations.sort_unstable();
+
+    let micros = |d: Duration| d.as_micros() as u64;
+    Ok(PartTiming {
+        day,
+        part,
+        answer: answer.map(|a| a.to_string()).unwrap_or_default(),
+        iterations,
+        min_us: micros(durations[0]),
+        median_us: micros(durations[(durations.len() - 1) / 2]),
+        max_us: micros(durations[durations.len() - 1]),
+    })
+}
+
+/// Fonction pour présenter les mesures sous forme de tableau aligné
+pub fn format_table(timings: &[PartTiming]) -> String {
+    let mut table = format!(
+        "{:>4} {:>6} {:>12} {:>12} {:>12}\n",
+        "jour", "partie", "min (µs)", "médiane (µs)", "max (µs)"
+    );
+    for t in timings {
+        table.push_str(&format!(
+            "{:>4} {:>6} {:>12} {:>12} {:>12}\n",
+            t.day, t.part, t.min_us, t.median_us, t.max_us
+        ));
+    }
+    table
+}
@@ -0,0 +1,74 @@
+// Importation des bibliothèques nécessaires
+use aoc_utils::error::AocError; // L'erreur retournée par les variantes
+use aoc_utils::solution::Variant; // Les implémentations comparées
+use std::time::{Duration, Instant}; // Pour mesurer les temps d'exécution
+
+/// Résultat d'une variante d'une partie : sa réponse et son meilleur temps
+#[derive(Debug, Clone)]
+pub struct VariantTiming {
+    pub name: &'static str,
+    pub answer: String,
+    pub best: Duration, // Le plus court des temps mesurés, le moins perturbé par le reste du système
+}
+
+/// Fonction pour exécuter chaque variante `runs` fois sur la même entrée et garder son meilleur temps
+/// S'arrête à la première erreur d'une variante
+pub fn compare_variants(
+    variants: &[Variant],
+    input: &str,
+    runs: u32,
+) -> Result<Vec<VariantTiming>, AocError> {
+    variants
+        .iter()
+        .map(|variant| {
+            let mut answer = String::new();
+            let mut best = Duration::MAX;
+            for _ in 0..runs.max(1) {
+                let start = Instant::now();
+                answer = (variant.run)(input)?.to_string();
+                best = best.min(start.elapsed());
+            }
+            Ok(VariantTiming {
+                name: variant.name,
+                answer,
+                best,
+            })
+        })
+        .collect()
+}
+
+/// Fonction pour vérifier que toutes les variantes donnent la même réponse
+/// Retourne la liste des désaccords avec la première variante, qui sert de référence
+pub fn disagreements(timings: &[VariantTiming]) -> Vec<String> {
+    let Some(reference) = timings.first() else {
+        return Vec::new();
+    };
+    timings[1..]
+        .iter()
+        .filter(|timing| timing.answer != reference.answer)
+        .map(|timing| {
+            format!(
+                "{} donne {} alors que {} donne {}",
+                timing.name, timing.answer, reference.name, reference.answer
+            )
+        })
+        .collect()
+}
+
+/// Fonction pour présenter les variantes d'une partie, avec leur temps relatif à la plus rapide
+pub fn format_comparison(day: u8, part: u8, timings: &[VariantTiming]) -> String {
+    let fastest = timings
+        .iter()
+        .map(|timing| timing.best)
+        .min()
+        .unwrap_or_default();
+    let mut table = format!("Jour {}, partie {} :\n", day, part);
+    for timing in timings {
+        let ratio = timing.best.as_secs_f64() / fastest.as_secs_f64().max(f64::MIN_POSITIVE);
+        table.push_str(&format!(
+            "  {:<16} {:>16} {:>12.2?}  ×{:.2}\n",
+            timing.name, timing.answer, timing.best, ratio
+        ));
+    }
+    table
+}
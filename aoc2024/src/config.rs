@@ -0,0 +1,59 @@
+// Importation des bibliothèques nécessaires
+use serde::Deserialize; // Pour lire le fichier de configuration
+use std::env; // Pour les variables d'environnement
+use std::fs; // Pour lire le fichier de configuration
+use std::io; // Pour les erreurs de lecture
+use std::path::PathBuf; // Pour le chemin du fichier de configuration
+
+/// Variable d'environnement contenant le cookie de session adventofcode.com
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Configuration du lanceur, lue dans `~/.config/aoc2024/config.toml`
+/// Exemple de fichier : `session = "53616c7465645f5f..."`
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    /// Cookie de session adventofcode.com
+    pub session: Option<String>,
+}
+
+impl Config {
+    /// Chemin du fichier de configuration (`$XDG_CONFIG_HOME/aoc2024/config.toml`,
+    /// ou `~/.config/aoc2024/config.toml`)
+    pub fn path() -> Option<PathBuf> {
+        let base = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(base.join("aoc2024").join("config.toml"))
+    }
+
+    /// Lit le fichier de configuration ; une configuration vide est retournée s'il n'existe pas
+    pub fn load() -> io::Result<Config> {
+        let Some(path) = Config::path().filter(|path| path.exists()) else {
+            return Ok(Config::default());
+        };
+        let text = fs::read_to_string(&path)?;
+        toml::from_str(&text).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("configuration invalide dans {} : {}", path.display(), e),
+            )
+        })
+    }
+
+    /// Retourne le cookie de session : la variable `AOC_SESSION` est prioritaire sur le fichier
+    pub fn session(&self) -> Option<String> {
+        env::var(SESSION_ENV)
+            .ok()
+            .or_else(|| self.session.clone())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty())
+    }
+}
+
+/// Erreur commune aux commandes qui ont besoin du cookie de session (`fetch`, `submit`, `leaderboard`)
+pub fn missing_session() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        "aucun cookie de session : définir AOC_SESSION ou `session` dans ~/.config/aoc2024/config.toml",
+    )
+}
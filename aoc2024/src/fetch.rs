@@ -0,0 +1,105 @@
+// Importation des bibliothèques nécessaires
+use crate::config; // Pour signaler l'absence de cookie de session
+use aoc_utils::input::day_input_path; // Pour l'emplacement des entrées
+use std::fs; // Pour écrire l'entrée téléchargée
+use std::io; // Pour les erreurs de lecture et d'écriture
+use std::path::{Path, PathBuf}; // Pour le chemin de l'entrée en cache
+use std::time::{Duration, SystemTime, UNIX_EPOCH}; // Pour l'heure de déblocage des puzzles
+
+/// Fonction pour calculer le chemin de l'entrée en cache d'un jour dans le répertoire `dir` de son année,
+/// comme `inputs/2024/day01.txt`
+pub fn input_path(dir: &Path, day: u8) -> PathBuf {
+    day_input_path(dir, day)
+}
+
+/// Fonction pour télécharger l'entrée d'un jour d'une année depuis adventofcode.com
+pub fn download_input(year: u16, day: u8, session: &str) -> io::Result<String> {
+    let url = format!("https://adventofcode.com/{}/day/{}/input", year, day);
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={}", session))
+        // Le site demande aux outils automatiques de s'identifier
+        .set("User-Agent", "github.com/Ludo000/aoc2024")
+        .call()
+        .map_err(|e| io::Error::other(format!("échec du téléchargement de {} : {}", url, e)))?;
+    response.into_string()
+}
+
+/// Fonction pour récupérer l'entrée d'un jour : le fichier en cache s'il existe (il n'est jamais
+/// téléchargé une seconde fois), sinon l'entrée est téléchargée puis enregistrée
+/// Retourne le chemin du fichier et indique s'il vient d'être téléchargé
+pub fn fetch_input(
+    dir: &Path,
+    year: u16,
+    day: u8,
+    session: Option<&str>,
+) -> io::Result<(PathBuf, bool)> {
+    let path = input_path(dir, day);
+    if path.exists() {
+        return Ok((path, false));
+    }
+
+    let session = session.ok_or_else(config::missing_session)?;
+    let input = download_input(year, day, session)?;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&path, input)?;
+    Ok((path, true))
+}
+
+/// Heure (UTC) de déblocage des puzzles, chaque jour de décembre (minuit à l'heure de New York)
+const UNLOCK_HOUR_UTC: u64 = 5;
+
+/// Fonction pour calculer l'heure de déblocage du puzzle d'un jour : le N décembre à 05:00 UTC
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    // Nombre de jours depuis le 1er janvier 1970 (algorithme `days_from_civil` de Howard Hinnant,
+    // l'année commençant en mars : décembre est le 10e mois à partir de mars)
+    let year = u64::from(year);
+    let (era, year_of_era) = (year / 400, year % 400);
+    let day_of_year = (153 * 9 + 2) / 5 + u64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    UNIX_EPOCH + Duration::from_secs(days * 86_400 + UNLOCK_HOUR_UTC * 3_600)
+}
+
+/// Fonction pour afficher une durée restante, comme `2 h 03 min 12 s` (ou `3 j 02 h 03 min 12 s`)
+pub fn format_countdown(remaining: Duration) -> String {
+    let seconds = remaining.as_secs();
+    let (days, hours) = (seconds / 86_400, seconds / 3_600 % 24);
+    let (minutes, seconds) = (seconds / 60 % 60, seconds % 60);
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{} s", seconds),
+        (0, 0, _) => format!("{} min {:02} s", minutes, seconds),
+        (0, _, _) => format!("{} h {:02} min {:02} s", hours, minutes, seconds),
+        _ => format!(
+            "{} j {:02} h {:02} min {:02} s",
+            days, hours, minutes, seconds
+        ),
+    }
+}
+
+/// Fonction pour attendre le déblocage du puzzle d'un jour, avec un compte à rebours sur la sortie
+/// d'erreur ; retourne immédiatement si le puzzle est déjà débloqué
+pub fn wait_for_unlock(year: u16, day: u8) {
+    let unlock = unlock_time(year, day);
+    let mut waited = false;
+    while let Ok(remaining) = unlock.duration_since(SystemTime::now()) {
+        waited = true;
+        eprint!(
+            "\rJour {} débloqué dans {}   ",
+            day,
+            format_countdown(remaining)
+        );
+        // Se réveille à chaque seconde pile, pour que le compte à rebours reste régulier
+        let tick = Duration::from_nanos(u64::from(remaining.subsec_nanos()));
+        std::thread::sleep(if tick.is_zero() {
+            Duration::from_secs(1)
+        } else {
+            tick
+        });
+    }
+    if waited {
+        eprintln!("\rJour {} débloqué !{}", day, " ".repeat(30));
+    }
+}
@@ -0,0 +1,226 @@
+// Importation des bibliothèques nécessaires
+use crate::config; // Pour signaler l'absence de cookie de session
+use serde::Deserialize; // Pour lire le classement téléchargé
+use std::cmp::Reverse; // Pour classer du meilleur score au moins bon
+use std::collections::BTreeMap; // Les membres et leurs jours, dans l'ordre
+use std::fs; // Pour le classement en cache
+use std::io; // Pour les erreurs de lecture, d'écriture et de téléchargement
+use std::path::{Path, PathBuf}; // Pour le chemin du classement en cache
+use std::time::{Duration, SystemTime}; // Pour l'âge du classement en cache
+
+/// Délai minimal entre deux téléchargements d'un même classement : le site demande de ne pas
+/// interroger l'API plus d'une fois toutes les 15 minutes
+pub const CACHE_TTL: Duration = Duration::from_secs(15 * 60);
+
+/// Classement privé, tel que renvoyé par `https://adventofcode.com/<année>/leaderboard/private/view/<id>.json`
+#[derive(Debug, Clone, Deserialize)]
+pub struct Leaderboard {
+    pub event: String, // L'année, comme `"2024"`
+    pub owner_id: u64,
+    pub members: BTreeMap<String, Member>, // Indexés par identifiant
+}
+
+/// Un membre du classement et ses étoiles
+#[derive(Debug, Clone, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>, // Absent pour les membres anonymes
+    pub stars: u32,
+    pub local_score: u64,
+    pub last_star_ts: u64, // Heure de la dernière étoile (secondes depuis 1970), 0 sans étoile
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, DayProgress>,
+}
+
+/// Les étoiles d'un membre pour un jour
+#[derive(Debug, Clone, Deserialize)]
+pub struct DayProgress {
+    #[serde(rename = "1")]
+    pub part1: Star,
+    #[serde(rename = "2", default)]
+    pub part2: Option<Star>,
+}
+
+/// Une étoile obtenue
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64, // Heure d'obtention (secondes depuis 1970)
+}
+
+impl Member {
+    /// Nom affiché du membre, comme sur le site pour les membres anonymes
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonyme #{})", self.id),
+        }
+    }
+
+    /// Temps écoulé entre les deux étoiles d'un jour, si la deuxième partie est résolue
+    pub fn delta(&self, day: u8) -> Option<Duration> {
+        let progress = self.completion_day_level.get(&day)?;
+        let part2 = progress.part2?;
+        Some(Duration::from_secs(
+            part2.get_star_ts.saturating_sub(progress.part1.get_star_ts),
+        ))
+    }
+}
+
+impl Leaderboard {
+    /// Les membres du mieux au moins bien classé : score local, puis nombre d'étoiles,
+    /// puis la dernière étoile la plus ancienne
+    pub fn ranked(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by_key(|m| {
+            (
+                Reverse(m.local_score),
+                Reverse(m.stars),
+                m.last_star_ts,
+                m.id,
+            )
+        });
+        members
+    }
+}
+
+/// Fonction pour calculer le chemin du classement en cache, comme `inputs/2024/leaderboard-123456.json`
+pub fn cache_path(dir: &Path, id: u64) -> PathBuf {
+    dir.join(format!("leaderboard-{}.json", id))
+}
+
+/// Fonction pour lire le texte JSON d'un classement
+pub fn parse_leaderboard(text: &str) -> io::Result<Leaderboard> {
+    serde_json::from_str(text).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("classement invalide : {}", e),
+        )
+    })
+}
+
+/// Fonction pour télécharger un classement privé depuis adventofcode.com
+pub fn download_leaderboard(year: u16, id: u64, session: &str) -> io::Result<String> {
+    let url = format!(
+        "https://adventofcode.com/{}/leaderboard/private/view/{}.json",
+        year, id
+    );
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={}", session))
+        // Le site demande aux outils automatiques de s'identifier
+        .set("User-Agent", "github.com/Ludo000/aoc2024")
+        .call()
+        .map_err(|e| io::Error::other(format!("échec du téléchargement de {} : {}", url, e)))?;
+    response.into_string()
+}
+
+/// Fonction pour récupérer un classement : celui en cache s'il a moins de `CACHE_TTL`, sinon il est
+/// téléchargé puis enregistré dans le répertoire `dir` de son année
+/// Retourne le classement et, s'il vient du cache, son âge
+pub fn fetch_leaderboard(
+    dir: &Path,
+    year: u16,
+    id: u64,
+    session: Option<&str>,
+) -> io::Result<(Leaderboard, Option<Duration>)> {
+    let path = cache_path(dir, id);
+    let age = fs::metadata(&path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok());
+    if let Some(age) = age.filter(|&age| age < CACHE_TTL) {
+        return Ok((parse_leaderboard(&fs::read_to_string(&path)?)?, Some(age)));
+    }
+
+    let session = session.ok_or_else(config::missing_session)?;
+    let text = download_leaderboard(year, id, session)?;
+    // Vérifie le classement avant de l'enregistrer : une page d'erreur ne doit pas rester en cache
+    let leaderboard = parse_leaderboard(&text)?;
+    fs::create_dir_all(dir)?;
+    fs::write(&path, text)?;
+    Ok((leaderboard, None))
+}
+
+/// Fonction pour afficher un écart entre deux étoiles en peu de caractères, comme `3m12s` ou `2h05m`
+fn format_delta(delta: Duration) -> String {
+    let seconds = delta.as_secs();
+    let (days, hours) = (seconds / 86_400, seconds / 3_600 % 24);
+    let (minutes, seconds) = (seconds / 60 % 60, seconds % 60);
+    match (days, hours, minutes) {
+        (0, 0, 0) => format!("{}s", seconds),
+        (0, 0, _) => format!("{}m{:02}s", minutes, seconds),
+        (0, _, _) => format!("{}h{:02}m", hours, minutes),
+        _ => format!("{}j{:02}h", days, hours),
+    }
+}
+
+/// Fonction pour construire le tableau d'un classement : rang, score, étoiles et une colonne par jour
+/// (`★` pour les deux parties, `☆` pour la première seulement), puis l'écart entre les deux étoiles
+/// de chaque jour
+/// Seuls les jours où au moins un membre a une étoile sont affichés
+pub fn render_leaderboard(leaderboard: &Leaderboard) -> String {
+    let members = leaderboard.ranked();
+    let last_day = members
+        .iter()
+        .flat_map(|m| m.completion_day_level.keys())
+        .copied()
+        .max()
+        .unwrap_or(0);
+    let days = 1..=last_day;
+    let names: Vec<String> = members.iter().map(|m| m.display_name()).collect();
+    let width = names
+        .iter()
+        .map(|name| name.chars().count())
+        .chain(["membre".len()])
+        .max()
+        .unwrap_or_default();
+
+    let mut table = format!(
+        "Classement {} ({} membres)\n{:>4}  {:>5}  {:>7}  {:<width$}  {}\n",
+        leaderboard.event,
+        members.len(),
+        "rang",
+        "score",
+        "étoiles",
+        "membre",
+        days.clone()
+            .map(|day| (day % 10).to_string())
+            .collect::<String>(),
+    );
+    for (rank, (member, name)) in members.iter().zip(&names).enumerate() {
+        let stars: String = days
+            .clone()
+            .map(|day| match member.completion_day_level.get(&day) {
+                Some(DayProgress { part2: Some(_), .. }) => '★',
+                Some(_) => '☆',
+                None => '·',
+            })
+            .collect();
+        let row = format!(
+            "{:>4}  {:>5}  {:>7}  {:<width$}  {}",
+            rank + 1,
+            member.local_score,
+            member.stars,
+            name,
+            stars
+        );
+        table.push_str(row.trim_end());
+        table.push('\n');
+    }
+
+    if last_day > 0 {
+        table.push_str("\nÉcart entre les deux parties :\n");
+        let header: String = days.clone().map(|day| format!("{:>7}", day)).collect();
+        table.push_str(&format!("{:<width$} {}\n", "membre", header));
+        for (member, name) in members.iter().zip(&names) {
+            let deltas: String = days
+                .clone()
+                .map(|day| {
+                    let delta = member.delta(day).map_or("-".to_string(), format_delta);
+                    format!("{:>7}", delta)
+                })
+                .collect();
+            table.push_str(&format!("{:<width$} {}\n", name, deltas));
+        }
+    }
+    table
+}
@@ -0,0 +1,32 @@
+//! Lanceur des solutions de l'Advent of Code 2024 : registre des jours et outils associés
+//! Sans la fonctionnalité `cli` (activée par défaut), seul le registre est compilé
+
+#[cfg(feature = "cli")]
+pub mod answers; // Réponses confirmées, pour détecter les régressions
+#[cfg(feature = "cli")]
+pub mod bench; // Mesure des temps d'exécution
+#[cfg(feature = "cli")]
+pub mod compare; // Comparaison des variantes d'une partie
+#[cfg(feature = "cli")]
+pub mod config; // Configuration du lanceur (cookie de session, ...)
+#[cfg(feature = "cli")]
+pub mod fetch; // Téléchargement et cache des entrées
+#[cfg(feature = "cli")]
+pub mod leaderboard; // Classements privés et leur cache
+#[cfg(feature = "cli")]
+pub mod manifest; // Manifeste des exemples et de leurs réponses attendues
+#[cfg(feature = "cli")]
+pub mod output; // Mise en forme des résultats (texte ou JSON)
+pub mod registry; // Correspondance entre les numéros de jour et les solutions
+#[cfg(feature = "cli")]
+pub mod report; // Rapport d'exécution en Markdown ou HTML
+#[cfg(feature = "cli")]
+pub mod runs; // Durées des dernières exécutions
+#[cfg(feature = "cli")]
+pub mod scaffold; // Génération du squelette d'un nouveau jour
+#[cfg(feature = "cli")]
+pub mod status; // Tableau d'avancement du calendrier
+#[cfg(feature = "cli")]
+pub mod submit; // Soumission des réponses et historique des verdicts
+#[cfg(feature = "cli")]
+pub mod watch; // Surveillance des sources et des exemples d'un jour
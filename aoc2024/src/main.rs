@@ -0,0 +1,986 @@
+// Importation des bibliothèques nécessaires
+use aoc2024::answers::Answers; // Les réponses confirmées
+use aoc2024::config::{self, Config}; // Pour le cookie de session
+use aoc2024::output::{OutputFormat, RunRecord, RunStatus}; // Pour afficher les résultats
+use aoc2024::report::{DayReport, ReportFormat}; // Pour le rapport d'exécution
+use aoc2024::submit::{self, Submission, Verdict}; // Pour soumettre les réponses
+use aoc2024::{
+    bench, compare, fetch, leaderboard, manifest, registry, report, runs, scaffold, status, watch,
+}; // Les autres outils du lanceur
+use aoc_utils::answer::Answer; // La réponse d'une partie
+use aoc_utils::error::AocError; // Pour reconnaître une entrée absente
+use aoc_utils::input::{input_dir, year_dir, InputSource}; // Pour le répertoire des entrées et leur lecture
+use aoc_utils::parse::read_input; // Pour lire les entrées téléchargées
+use clap::{Parser, Subcommand}; // Pour lire les arguments de la ligne de commande
+use rayon::prelude::*; // Pour exécuter tous les jours en parallèle
+use std::collections::HashMap; // Pour ne lire chaque entrée qu'une fois
+use std::error::Error; // Pour regrouper les erreurs des différentes commandes
+use std::fs; // Pour écrire une entrée générée
+use std::io; // Pour les opérations d'entrée/sortie
+use std::path::{Path, PathBuf}; // Pour la racine du dépôt et le répertoire des entrées
+use std::process::ExitCode; // Pour signaler un échec sans afficher l'erreur brute
+use std::sync::mpsc; // Pour attendre la réponse d'une partie avec un délai
+use std::thread; // Pour isoler chaque partie de `run --all`
+use std::time::{Duration, Instant}; // Pour chronométrer chaque partie
+use tracing::info_span; // Pour mesurer chaque partie dans les traces
+use tracing_subscriber::fmt::format::FmtSpan; // Pour afficher la durée de chaque phase
+
+/// Lanceur unique pour toutes les solutions de l'Advent of Code 2024
+#[derive(Parser)]
+#[command(name = "aoc2024", version, about)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Répertoire des entrées ; `$AOC_INPUT_DIR`, ou `inputs` par défaut
+    #[arg(long, global = true)]
+    input_dir: Option<PathBuf>,
+    /// Profil dont les entrées sont utilisées, dans `<répertoire des entrées>/<profil>/`
+    /// (par exemple `--profile ami` pour comparer avec l'entrée d'un ami)
+    #[arg(long, global = true)]
+    profile: Option<String>,
+    /// Année des puzzles ; les entrées sont dans `<répertoire des entrées>/<année>/`
+    /// Les réponses confirmées, l'historique des soumissions et les durées ne concernent que 2024
+    #[arg(long, global = true, default_value_t = registry::DEFAULT_YEAR, value_parser = clap::value_parser!(u16).range(2015..))]
+    year: u16,
+    /// Affiche les traces des solutions et la durée de chaque phase (`-v`), ou tous les détails (`-vv`)
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+}
+
+/// Les sous-commandes du lanceur
+#[derive(Subcommand)]
+enum Command {
+    /// Exécute une partie (ou les deux) d'un jour, ou de tous les jours avec `--all`
+    Run {
+        /// Numéro du jour (1 à 25)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+        day: Option<u8>,
+        /// Partie à exécuter (1 ou 2) ; les deux si elle n'est pas précisée
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Fichier d'entrée, ou `-` pour l'entrée standard ; `inputs/<année>/dayNN.txt` par défaut
+        #[arg(long, conflicts_with = "all")]
+        input: Option<InputSource>,
+        /// Exécute tous les jours du registre, chacun avec son entrée `inputs/<année>/dayNN.txt`
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+        /// Avec `--all`, exécute toutes les parties en même temps sur plusieurs cœurs
+        #[arg(long, conflicts_with = "day")]
+        parallel: bool,
+        /// Avec `--all`, délai au-delà duquel une partie est abandonnée et signalée (`30s`, `500ms`, `2m`),
+        /// ou `none` pour attendre chaque partie sans limite
+        // Le chemin complet de `Option` empêche clap de traiter l'argument comme facultatif :
+        // il a toujours une valeur, `None` venant de `none`
+        #[arg(long, conflicts_with = "day", value_parser = parse_timeout, default_value = "30s")]
+        timeout: std::option::Option<Duration>,
+        /// Format des résultats : texte lisible ou un objet JSON par partie
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        output: OutputFormat,
+        /// Anime la partie demandée (la première par défaut) dans le terminal au lieu d'afficher
+        /// la réponse ; nécessite la fonctionnalité `viz` (`cargo run --features viz`)
+        #[arg(long, conflicts_with = "all")]
+        visualize: bool,
+        /// Avec `--visualize`, nombre d'images par seconde
+        #[arg(long, default_value_t = 30, value_parser = clap::value_parser!(u32).range(1..=240))]
+        fps: u32,
+        /// Exécute toutes les variantes déclarées de chaque partie, vérifie qu'elles donnent la même
+        /// réponse et compare leurs temps
+        #[arg(long, conflicts_with_all = ["all", "visualize"])]
+        compare: bool,
+    },
+    /// Mesure les temps d'exécution des deux parties d'un jour (ou de tous les jours)
+    Bench {
+        /// Numéro du jour, ou `all` pour tous les jours implémentés
+        #[arg(long, value_parser = parse_day_selection)]
+        day: DaySelection,
+        /// Nombre d'exécutions d'échauffement, non mesurées
+        #[arg(long, default_value_t = 3)]
+        warmup: u32,
+        /// Nombre d'exécutions mesurées
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        iterations: u32,
+        /// Affiche les résultats en JSON plutôt qu'en tableau
+        #[arg(long)]
+        json: bool,
+    },
+    /// Affiche l'avancement du calendrier : jours implémentés, étoiles et durées
+    Status,
+    /// Télécharge l'entrée d'un jour dans `inputs/<année>/dayNN.txt` (sauf si elle y est déjà)
+    Fetch {
+        /// Numéro du jour (1 à 25)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Attend le déblocage du puzzle (05:00 UTC) avec un compte à rebours, puis télécharge aussitôt
+        #[arg(long)]
+        wait: bool,
+        /// Génère aussi le squelette du jour, comme `new` (sauf s'il existe déjà)
+        #[arg(long)]
+        new: bool,
+    },
+    /// Calcule la réponse d'une partie et la soumet à adventofcode.com
+    Submit {
+        /// Numéro du jour (1 à 25)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Partie à soumettre (1 ou 2)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Exécute un jour et enregistre ses réponses comme confirmées dans `answers.toml`
+    Record {
+        /// Numéro du jour (1 à 25)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Partie à enregistrer (1 ou 2) ; les deux si elle n'est pas précisée
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+    },
+    /// Réexécute les solutions et échoue si une réponse diffère de `answers.toml`
+    Verify {
+        /// Numéro du jour (1 à 25)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+        day: Option<u8>,
+        /// Vérifie toutes les réponses enregistrées
+        #[arg(long, conflicts_with = "day")]
+        all: bool,
+    },
+    /// Exécute tous les jours et écrit un rapport (réponses, durées, notes de chaque solution)
+    Report {
+        /// Format du rapport
+        #[arg(long, value_enum, default_value_t = ReportFormat::Md)]
+        format: ReportFormat,
+        /// Fichier du rapport ; `REPORT.md` ou `REPORT.html` selon le format par défaut
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// Masque les réponses, pour partager le rapport publiquement
+        #[arg(long)]
+        redact: bool,
+    },
+    /// Génère une entrée synthétique, de même structure qu'une vraie entrée, pour les tests et mesures
+    Gen {
+        /// Numéro du jour (1 à 25)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Taille de l'entrée (pour le jour 1, le nombre de paires)
+        #[arg(long, default_value_t = 1000)]
+        size: usize,
+        /// Graine du générateur, pour reproduire la même entrée ; tirée au hasard (et affichée) sinon
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Fichier où écrire l'entrée ; sortie standard par défaut
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Affiche un classement privé de l'année : rang, étoiles de chaque jour et écart entre les deux
+    /// parties ; le classement est conservé 15 minutes, comme le demande le site
+    Leaderboard {
+        /// Identifiant du classement (le nombre à la fin de son adresse)
+        #[arg(long)]
+        id: u64,
+    },
+    /// Vérifie un jour : réponses des exemples du manifeste, puis de la vraie entrée si elle est présente
+    Check {
+        /// Numéro du jour (1 à 25)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Relance `check` (après recompilation) à chaque modification des sources ou des exemples d'un jour
+    Watch {
+        /// Numéro du jour (1 à 25)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Génère le squelette d'un nouveau jour et l'enregistre dans le lanceur
+    New {
+        /// Numéro du jour (1 à 25)
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+}
+
+/// Fonction pour lire un délai : un nombre suivi de `ms`, `s` ou `m` (des secondes sans unité),
+/// ou `none` pour désactiver le délai
+fn parse_timeout(text: &str) -> Result<Option<Duration>, String> {
+    if text == "none" {
+        return Ok(None);
+    }
+    let (number, unit) = match text.find(|c: char| !c.is_ascii_digit()) {
+        Some(i) => text.split_at(i),
+        None => (text, "s"),
+    };
+    let value: u64 = number
+        .parse()
+        .map_err(|_| format!("délai invalide : {} (par exemple `30s` ou `none`)", text))?;
+    let timeout = match unit {
+        "ms" => Duration::from_millis(value),
+        "s" => Duration::from_secs(value),
+        "m" => value
+            .checked_mul(60)
+            .map(Duration::from_secs)
+            .ok_or_else(|| format!("délai trop long : {}", text))?,
+        _ => return Err(format!("unité de délai inconnue : {} (ms, s ou m)", unit)),
+    };
+    if timeout.is_zero() {
+        return Err("le délai doit être positif (`none` pour le désactiver)".to_string());
+    }
+    Ok(Some(timeout))
+}
+
+/// Résultat d'une commande : n'importe quelle erreur, affichée en clair par `main`
+type CliResult<T> = Result<T, Box<dyn Error>>;
+
+/// Sélection d'un jour précis ou de tous les jours
+#[derive(Clone, Copy)]
+enum DaySelection {
+    All,
+    Day(u8),
+}
+
+/// Fonction pour lire une sélection de jours : un numéro de 1 à 25 ou `all`
+fn parse_day_selection(text: &str) -> Result<DaySelection, String> {
+    if text == "all" {
+        return Ok(DaySelection::All);
+    }
+    match text.parse() {
+        Ok(day @ 1..=25) => Ok(DaySelection::Day(day)),
+        _ => Err(format!(
+            "jour invalide {:?} : attendu un nombre de 1 à 25 ou `all`",
+            text
+        )),
+    }
+}
+
+/// Fonction pour mesurer les jours sélectionnés et afficher le rapport
+fn bench(
+    inputs: &Path,
+    year: u16,
+    selection: DaySelection,
+    warmup: u32,
+    iterations: u32,
+    json: bool,
+) -> CliResult<()> {
+    let days: Vec<u8> = match selection {
+        DaySelection::All => registry::days(year).iter().map(|&(day, _)| day).collect(),
+        DaySelection::Day(day) => vec![day],
+    };
+
+    let mut timings = Vec::new();
+    for day in days {
+        let solution = registry::get_in(year, day).ok_or_else(|| unknown_day(year, day))?;
+        let path = fetch::input_path(inputs, day);
+        // Avec `all`, un jour sans entrée est simplement ignoré
+        let input = match (read_input(&path, Some(day)), selection) {
+            (Ok(input), _) => input,
+            (Err(AocError::MissingInput { .. }), DaySelection::All) => {
+                eprintln!(
+                    "Jour {} ignoré : entrée {} introuvable",
+                    day,
+                    path.display()
+                );
+                continue;
+            }
+            (Err(e), _) => return Err(e.into()),
+        };
+        for part in [1, 2] {
+            timings.push(bench::bench_part(
+                solution, day, part, &input, warmup, iterations,
+            )?);
+        }
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&timings)?);
+    } else {
+        print!("{}", bench::format_table(&timings));
+    }
+    Ok(())
+}
+
+/// Erreur pour un jour qui n'est pas dans le registre
+fn unknown_day(year: u16, day: u8) -> io::Error {
+    let message = match year {
+        registry::DEFAULT_YEAR => format!("le jour {} n'est pas encore implémenté", day),
+        _ => format!("le jour {} de {} n'est pas encore implémenté", day, year),
+    };
+    io::Error::new(io::ErrorKind::Unsupported, message)
+}
+
+/// Fonction pour refuser une commande dont les fichiers (réponses confirmées, historique des
+/// soumissions, durées) ne concernent que l'année par défaut
+fn require_default_year(year: u16, command: &str) -> CliResult<()> {
+    if year == registry::DEFAULT_YEAR {
+        return Ok(());
+    }
+    Err(format!(
+        "`{}` ne gère que {} : ses fichiers ne distinguent pas les années",
+        command,
+        registry::DEFAULT_YEAR
+    )
+    .into())
+}
+
+/// Fonction pour exécuter une partie d'un jour et retourner sa réponse
+fn solve(year: u16, day: u8, part: u8, input: &str) -> CliResult<Answer> {
+    let solution = registry::get_in(year, day).ok_or_else(|| unknown_day(year, day))?;
+    let _span = info_span!("partie", year, day, part).entered();
+    Ok(registry::run_part(solution, part, input)?)
+}
+
+/// Fonction pour exécuter une partie en la chronométrant
+/// L'erreur éventuelle est convertie en texte pour pouvoir traverser les fils d'exécution
+fn timed_solve(year: u16, day: u8, part: u8, input: &str) -> RunRecord {
+    let start = Instant::now();
+    let result = solve(year, day, part, input).map(|answer| answer.to_string());
+    RunRecord::new(
+        day,
+        part,
+        result.map_err(|e| e.to_string()),
+        start.elapsed(),
+    )
+}
+
+/// Fonction pour exécuter une partie dans son propre fil d'exécution, pour qu'une panique ou une
+/// partie trop longue n'interrompe pas les autres
+/// Au-delà de `timeout`, la partie est signalée comme hors délai ; son fil n'est pas interrompu
+/// (c'est impossible), il continue en arrière-plan jusqu'à la fin du programme
+fn isolated_solve(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: String,
+    timeout: Option<Duration>,
+) -> RunRecord {
+    let (sender, receiver) = mpsc::channel();
+    let start = Instant::now();
+    let spawned = thread::Builder::new()
+        .name(format!("jour {} partie {}", day, part))
+        .spawn(move || {
+            // Le récepteur a peut-être déjà abandonné : la réponse tardive est ignorée
+            let _ = sender.send(timed_solve(year, day, part, &input));
+        });
+    let handle = match spawned {
+        Ok(handle) => handle,
+        Err(e) => return RunRecord::new(day, part, Err(e.to_string()), Duration::ZERO),
+    };
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver
+            .recv()
+            .map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+    };
+    match received {
+        Ok(record) => record,
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            RunRecord::timed_out(day, part, timeout.unwrap_or_default())
+        }
+        // Le fil s'est arrêté sans répondre : il a paniqué
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            let payload = handle.join().err();
+            let message = payload
+                .as_ref()
+                .and_then(|p| {
+                    p.downcast_ref::<&str>()
+                        .copied()
+                        .or(p.downcast_ref::<String>().map(String::as_str))
+                })
+                .unwrap_or("cause inconnue");
+            RunRecord::new(
+                day,
+                part,
+                Err(format!("panique : {}", message)),
+                start.elapsed(),
+            )
+        }
+    }
+}
+
+/// Nombre d'exécutions de chaque variante par `run --compare` ; seul le meilleur temps est gardé
+const COMPARE_RUNS: u32 = 5;
+
+/// Fonction pour comparer les variantes des parties demandées d'un jour
+/// Échoue si une partie n'a pas de variantes ou si deux variantes ne donnent pas la même réponse
+fn compare_variants(year: u16, day: u8, parts: &[u8], input: &str) -> CliResult<()> {
+    let solution = registry::get_in(year, day).ok_or_else(|| unknown_day(year, day))?;
+    let mut failures = Vec::new();
+    for &part in parts {
+        let variants = solution.variants(part);
+        if variants.is_empty() {
+            failures.push(format!("partie {} : aucune variante déclarée", part));
+            continue;
+        }
+        let timings = compare::compare_variants(variants, input, COMPARE_RUNS)?;
+        print!("{}", compare::format_comparison(day, part, &timings));
+        failures.extend(
+            compare::disagreements(&timings)
+                .into_iter()
+                .map(|message| format!("partie {} : {}", part, message)),
+        );
+    }
+    if !failures.is_empty() {
+        return Err(failures.join("\n").into());
+    }
+    Ok(())
+}
+
+/// Fonction pour animer une partie d'un jour dans le terminal
+#[cfg(feature = "viz")]
+fn animate(year: u16, day: u8, part: u8, input: &str, fps: u32) -> CliResult<()> {
+    require_default_year(year, "run --visualize")?;
+    registry::get(day).ok_or_else(|| unknown_day(year, day))?;
+    let visualization = registry::visualization(day).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("le jour {} ne propose pas d'animation", day),
+        )
+    })?;
+    let frames = visualization.frames(input, part)?;
+    aoc_utils::visualize::play(&frames, fps)?;
+    Ok(())
+}
+
+/// Sans la fonctionnalité `viz`, l'animation n'est pas disponible
+#[cfg(not(feature = "viz"))]
+fn animate(_year: u16, _day: u8, _part: u8, _input: &str, _fps: u32) -> CliResult<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "animation indisponible : recompiler avec `--features viz`",
+    )
+    .into())
+}
+
+/// Fonction pour exécuter les parties demandées de tous les jours du registre
+/// Chaque réponse est affichée dès qu'elle est connue ; en parallèle, elles arrivent dans le désordre,
+/// d'où le récapitulatif final trié par jour (en texte seulement : en JSON, chaque enregistrement
+/// porte déjà son jour et sa partie)
+/// Un jour en échec n'empêche pas les autres de s'exécuter : chaque partie s'exécute dans son propre
+/// fil, qui peut paniquer ou dépasser `timeout` sans interrompre les autres ; en texte, les parties
+/// en échec sont rappelées à la fin (`FAILED` ou `TIMEOUT`)
+/// Les durées ne sont conservées que pour l'année par défaut
+fn run_all(
+    inputs: &Path,
+    year: u16,
+    parts: &[u8],
+    parallel: bool,
+    timeout: Option<Duration>,
+    format: OutputFormat,
+) -> CliResult<()> {
+    let jobs: Vec<(u8, u8)> = registry::days(year)
+        .iter()
+        .flat_map(|&(day, _)| parts.iter().map(move |&part| (day, part)))
+        .collect();
+
+    let run_job = |&(day, part): &(u8, u8)| {
+        let record = match read_input(fetch::input_path(inputs, day), Some(day)) {
+            Ok(input) => isolated_solve(year, day, part, input, timeout),
+            Err(e) => RunRecord::new(day, part, Err(e.to_string()), Duration::ZERO),
+        };
+        record.print(format);
+        record
+    };
+    let results: Vec<RunRecord> = if parallel {
+        jobs.par_iter().map(run_job).collect()
+    } else {
+        jobs.iter().map(run_job).collect()
+    };
+
+    if parallel && format == OutputFormat::Text {
+        println!("\nRécapitulatif :");
+        for record in &results {
+            println!("{}", record.render(format));
+        }
+    }
+
+    let failures: Vec<&RunRecord> = results.iter().filter(|record| record.is_err()).collect();
+    if !failures.is_empty() && format == OutputFormat::Text {
+        println!("\nÉchecs :");
+        for record in &failures {
+            println!(
+                "  Jour {}, partie {} : {}",
+                record.day,
+                record.part,
+                record.status.label()
+            );
+        }
+    }
+
+    if year == registry::DEFAULT_YEAR {
+        runs::record(inputs, &results)?;
+    }
+    if !failures.is_empty() {
+        let timeouts = failures
+            .iter()
+            .filter(|record| record.status == RunStatus::Timeout)
+            .count();
+        return Err(format!(
+            "{} partie(s) en échec sur {}, dont {} hors délai",
+            failures.len(),
+            results.len(),
+            timeouts
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Fonction pour exécuter les deux parties de tous les jours de l'année et produire leur rapport
+/// Un jour sans entrée est ignoré avec un avertissement ; une partie en échec figure dans le rapport
+fn build_report(inputs: &Path, year: u16, format: ReportFormat, redact: bool) -> String {
+    let mut days = Vec::new();
+    for &(day, solution) in registry::days(year) {
+        let input = match read_input(fetch::input_path(inputs, day), Some(day)) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Jour {} ignoré : {}", day, e);
+                continue;
+            }
+        };
+        days.push(DayReport {
+            day,
+            description: solution.description(),
+            records: [1, 2]
+                .iter()
+                .map(|&part| timed_solve(year, day, part, &input))
+                .collect(),
+        });
+    }
+    report::render_report(year, &days, format, redact)
+}
+
+/// Fonction pour calculer la réponse d'une partie et la soumettre, sauf si l'historique
+/// montre déjà qu'elle est inutile
+fn submit_answer(inputs: &Path, day: u8, part: u8) -> CliResult<()> {
+    let input = read_input(fetch::input_path(inputs, day), Some(day))?;
+    let answer = solve(registry::DEFAULT_YEAR, day, part, &input)?.to_string();
+    let history = submit::load_history(inputs)?;
+    if let Some(known) = submit::known_verdict(&history, day, part, &answer) {
+        println!(
+            "Réponse {} non soumise : {} a déjà été soumis ({})",
+            answer,
+            known.answer,
+            describe(&known.verdict)
+        );
+        return Ok(());
+    }
+
+    let session = Config::load()?
+        .session()
+        .ok_or_else(config::missing_session)?;
+    let page = submit::post_answer(day, part, &answer, &session)?;
+    let verdict = submit::parse_verdict(&page);
+    match (&verdict, submit::wait_time(&page)) {
+        (Verdict::RateLimited, Some(wait)) => {
+            println!("{} : attendre {}", describe(&verdict), wait)
+        }
+        _ => println!("Réponse {} : {}", answer, describe(&verdict)),
+    }
+
+    // Une bonne réponse devient une réponse confirmée, protégée par `verify`
+    if verdict == Verdict::Correct {
+        let mut answers = Answers::load()?;
+        answers.set(day, part, answer.clone());
+        answers.save()?;
+    }
+    if verdict.is_definitive() {
+        submit::record(
+            inputs,
+            Submission {
+                day,
+                part,
+                answer,
+                verdict,
+            },
+        )?;
+    }
+    Ok(())
+}
+
+/// Fonction pour exécuter un jour sur son entrée téléchargée et enregistrer ses réponses
+fn record_answers(inputs: &Path, day: u8, parts: &[u8]) -> CliResult<()> {
+    let input = read_input(fetch::input_path(inputs, day), Some(day))?;
+    let mut answers = Answers::load()?;
+    for &part in parts {
+        let answer = solve(registry::DEFAULT_YEAR, day, part, &input)?.to_string();
+        println!("Jour {}, partie {} : {} enregistrée", day, part, answer);
+        answers.set(day, part, answer);
+    }
+    answers.save()?;
+    Ok(())
+}
+
+/// Fonction pour réexécuter les réponses enregistrées (d'un jour, ou toutes) et signaler
+/// chaque réponse qui a changé
+/// Un jour dont l'entrée est absente est ignoré avec un avertissement
+fn verify_answers(inputs: &Path, day: Option<u8>) -> CliResult<()> {
+    let answers = Answers::load()?;
+    let (mut checked, mut changed) = (0, 0);
+    let mut texts = HashMap::new(); // Chaque entrée n'est lue qu'une fois pour les deux parties
+
+    for (d, part, expected) in answers
+        .iter()
+        .filter(|&(d, _, _)| day.is_none_or(|day| d == day))
+    {
+        let input = texts
+            .entry(d)
+            .or_insert_with(|| read_input(fetch::input_path(inputs, d), Some(d)));
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("Jour {}, partie {} ignorée : {}", d, part, e);
+                continue;
+            }
+        };
+        let answer = solve(registry::DEFAULT_YEAR, d, part, input)?;
+        checked += 1;
+        if answer == expected {
+            println!("Jour {}, partie {} : {} (inchangée)", d, part, expected);
+        } else {
+            changed += 1;
+            println!(
+                "Jour {}, partie {} : CHANGÉE\n  - attendue : {}\n  + obtenue  : {}",
+                d, part, expected, answer
+            );
+        }
+    }
+
+    if changed > 0 {
+        return Err(format!(
+            "{} réponse(s) changée(s) sur {} vérifiée(s)",
+            changed, checked
+        )
+        .into());
+    }
+    println!("{} réponse(s) vérifiée(s), aucune régression", checked);
+    Ok(())
+}
+
+/// Fonction pour générer le squelette d'un jour et afficher les fichiers créés ou modifiés
+/// Le squelette est généré à la racine du dépôt, d'où le lanceur est exécuté
+fn scaffold_new_day(year: u16, day: u8) -> CliResult<()> {
+    for path in scaffold::scaffold_day(Path::new("."), year, day)? {
+        println!("  {}", path.display());
+    }
+    println!(
+        "Jour {} généré : compléter {}/src/lib.rs",
+        day,
+        scaffold::crate_name(year, day)
+    );
+    Ok(())
+}
+
+/// Fonction pour vérifier un jour et résumer le résultat sur une ligne, comme
+/// `Jour 1 — exemple : ✓ 11, ✓ 31 — entrée : ✓ 2430334, ✓ 28786472 (3 ms)`
+/// Les réponses de l'exemple viennent du manifeste, celles de l'entrée des réponses confirmées
+/// (pour l'année par défaut seulement) ; une réponse sans attendu est affichée sans marque
+/// Échoue si une partie échoue ou si une réponse diffère de celle attendue
+fn check_day(inputs: &Path, year: u16, day: u8) -> CliResult<()> {
+    let start = Instant::now();
+    let root = Path::new(".");
+    registry::get_in(year, day).ok_or_else(|| unknown_day(year, day))?;
+    let cases = manifest::load(root, year)?
+        .remove(&manifest::day_key(day))
+        .ok_or_else(|| format!("le jour {} n'a pas d'exemple dans le manifeste", day))?;
+    let confirmed = match year {
+        registry::DEFAULT_YEAR => Answers::load()?,
+        _ => Answers::default(),
+    };
+
+    let mut failed = false;
+    let mut check_parts = |input: &str, expected: &dyn Fn(u8) -> Option<String>| {
+        let marks: Vec<String> = [1, 2]
+            .into_iter()
+            .map(
+                |part| match (solve(year, day, part, input), expected(part)) {
+                    (Ok(answer), Some(expected)) if answer == expected.as_str() => {
+                        format!("✓ {}", answer)
+                    }
+                    (Ok(answer), Some(expected)) => {
+                        failed = true;
+                        format!("✗ {} (attendu {})", answer, expected)
+                    }
+                    (Ok(answer), None) => answer.to_string(),
+                    (Err(e), _) => {
+                        failed = true;
+                        format!("✗ erreur ({})", e)
+                    }
+                },
+            )
+            .collect();
+        marks.join(", ")
+    };
+
+    let example_path = manifest::examples_dir(root, year).join(&cases.example.file);
+    let example = check_parts(&read_input(example_path, None)?, &|part| {
+        cases.example.expected(part).map(str::to_string)
+    });
+    let input = match read_input(fetch::input_path(inputs, day), Some(day)) {
+        Ok(text) => check_parts(&text, &|part| confirmed.get(day, part).map(str::to_string)),
+        Err(AocError::MissingInput { .. }) => "absente".to_string(),
+        Err(e) => return Err(e.into()),
+    };
+
+    println!(
+        "Jour {} — exemple : {} — entrée : {} ({} ms)",
+        day,
+        example,
+        input,
+        start.elapsed().as_millis()
+    );
+    if failed {
+        return Err(format!("le jour {} ne donne pas les réponses attendues", day).into());
+    }
+    Ok(())
+}
+
+/// Fonction pour surveiller un jour et relancer `check` à chaque modification
+/// `check` est relancé avec `cargo run` pour que les sources modifiées soient recompilées ;
+/// les erreurs de compilation, comme les échecs, s'affichent sans arrêter la surveillance
+/// `global_args` sont les options globales à transmettre (`--year`, `--input-dir`, `--profile`)
+fn watch_day(global_args: &[String], year: u16, day: u8) -> CliResult<()> {
+    let check = || {
+        let status = std::process::Command::new("cargo")
+            .args(["run", "--quiet", "-p", "aoc2024", "--"])
+            .args(global_args)
+            .args(["check", "--day", &day.to_string()])
+            .status();
+        if let Err(e) = status {
+            eprintln!("Erreur : impossible de lancer cargo ({})", e);
+        }
+    };
+
+    let paths = watch::watched_paths(Path::new("."), year, day);
+    check();
+    println!(
+        "Surveillance du jour {} (Ctrl+C pour arrêter) : {}",
+        day,
+        paths
+            .iter()
+            .map(|(path, _)| path.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    watch::watch(&paths, check)?;
+    Ok(())
+}
+
+/// Fonction pour décrire un verdict en français
+fn describe(verdict: &Verdict) -> &'static str {
+    match verdict {
+        Verdict::Correct => "bonne réponse",
+        Verdict::TooHigh => "réponse trop grande",
+        Verdict::TooLow => "réponse trop petite",
+        Verdict::Wrong => "mauvaise réponse",
+        Verdict::RateLimited => "soumission trop rapprochée de la précédente",
+        Verdict::AlreadySolved => "partie déjà résolue ou pas encore débloquée",
+        Verdict::Unknown => "réponse du site non reconnue",
+    }
+}
+
+/// Fonction pour exécuter la commande demandée
+fn run(cli: Cli) -> CliResult<()> {
+    // Le répertoire des entrées est celui de l'année choisie, comme `inputs/2024`
+    let year = cli.year;
+    let inputs = year_dir(
+        &input_dir(cli.input_dir.as_deref(), cli.profile.as_deref()),
+        year,
+    );
+    let inputs = inputs.as_path();
+
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            parallel,
+            timeout,
+            output,
+            visualize,
+            fps,
+            compare,
+            ..
+        } => {
+            // Sans partie précisée, exécute les deux
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            match day {
+                Some(day) => {
+                    // Par défaut, lit l'entrée téléchargée par `fetch`
+                    // Le texte est lu une seule fois pour les deux parties (l'entrée standard ne se relit pas)
+                    let source =
+                        input.unwrap_or_else(|| InputSource::File(fetch::input_path(inputs, day)));
+                    let input = source.read(Some(day))?;
+                    if visualize {
+                        return animate(year, day, part.unwrap_or(1), &input, fps);
+                    }
+                    if compare {
+                        return compare_variants(year, day, &parts, &input);
+                    }
+                    let mut records = Vec::new();
+                    for part in parts {
+                        let record = timed_solve(year, day, part, &input);
+                        if let Some(error) = record.error {
+                            return Err(error.into());
+                        }
+                        record.print(output);
+                        records.push(record);
+                    }
+                    if year == registry::DEFAULT_YEAR {
+                        runs::record(inputs, &records)?;
+                    }
+                }
+                None => run_all(inputs, year, &parts, parallel, timeout, output)?,
+            }
+        }
+        Command::Bench {
+            day,
+            warmup,
+            iterations,
+            json,
+        } => bench(inputs, year, day, warmup, iterations, json)?,
+        Command::Status => {
+            require_default_year(year, "status")?;
+            let registered: Vec<u8> = registry::DAYS.iter().map(|&(day, _)| day).collect();
+            print!(
+                "{}",
+                status::status_table(&registered, &Answers::load()?, &runs::load(inputs)?)
+            );
+        }
+        Command::Fetch { day, wait, new } => {
+            let config = Config::load()?;
+            if wait {
+                fetch::wait_for_unlock(year, day);
+            }
+            let (path, downloaded) =
+                fetch::fetch_input(inputs, year, day, config.session().as_deref())?;
+            if downloaded {
+                println!("Entrée du jour {} téléchargée dans {}", day, path.display());
+            } else {
+                println!(
+                    "Entrée du jour {} déjà présente dans {}",
+                    day,
+                    path.display()
+                );
+            }
+            if new && Path::new(&scaffold::crate_name(year, day)).exists() {
+                println!("Squelette du jour {} déjà présent", day);
+            } else if new {
+                scaffold_new_day(year, day)?;
+            }
+        }
+        Command::Submit { day, part } => {
+            require_default_year(year, "submit")?;
+            submit_answer(inputs, day, part)?
+        }
+        Command::Record { day, part } => {
+            require_default_year(year, "record")?;
+            let parts = match part {
+                Some(part) => vec![part],
+                None => vec![1, 2],
+            };
+            record_answers(inputs, day, &parts)?
+        }
+        Command::Verify { day, .. } => {
+            require_default_year(year, "verify")?;
+            verify_answers(inputs, day)?
+        }
+        Command::Report {
+            format,
+            output,
+            redact,
+        } => {
+            let path = output.unwrap_or_else(|| PathBuf::from(format.default_file()));
+            let report = build_report(inputs, year, format, redact);
+            fs::write(&path, report)?;
+            println!("Rapport écrit dans {}", path.display());
+        }
+        Command::Gen {
+            day,
+            size,
+            seed,
+            output,
+        } => {
+            require_default_year(year, "gen")?;
+            registry::get(day).ok_or_else(|| unknown_day(year, day))?;
+            let generator = registry::generator(day).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::Unsupported,
+                    format!("le jour {} ne propose pas de générateur d'entrées", day),
+                )
+            })?;
+            let seed = seed.unwrap_or_else(|| {
+                let seed = fastrand::u64(..);
+                eprintln!("Graine : {}", seed);
+                seed
+            });
+            let input = generator.generate(size, seed);
+            match output {
+                Some(path) => fs::write(&path, input)?,
+                None => print!("{}", input),
+            }
+        }
+        Command::Leaderboard { id } => {
+            let config = Config::load()?;
+            let (board, age) =
+                leaderboard::fetch_leaderboard(inputs, year, id, config.session().as_deref())?;
+            if let Some(age) = age {
+                println!(
+                    "Classement en cache depuis {} (téléchargé au plus toutes les 15 min)",
+                    fetch::format_countdown(age)
+                );
+            }
+            print!("{}", leaderboard::render_leaderboard(&board));
+        }
+        Command::Check { day } => check_day(inputs, year, day)?,
+        Command::Watch { day } => {
+            let mut global_args = vec!["--year".to_string(), year.to_string()];
+            if let Some(dir) = &cli.input_dir {
+                global_args.push("--input-dir".to_string());
+                global_args.push(dir.display().to_string());
+            }
+            if let Some(profile) = &cli.profile {
+                global_args.push("--profile".to_string());
+                global_args.push(profile.clone());
+            }
+            watch_day(&global_args, year, day)?
+        }
+        Command::New { day } => scaffold_new_day(year, day)?,
+    }
+
+    Ok(()) // Retour explicite que tout s'est bien passé
+}
+
+/// Fonction pour afficher les traces sur la sortie d'erreur selon le nombre de `-v`
+/// Sans `-v`, seuls les avertissements sont affichés ; avec, la fermeture de chaque phase
+/// (analyse, partie 1, partie 2...) affiche sa durée
+fn init_tracing(verbose: u8) {
+    let level = match verbose {
+        0 => tracing::Level::WARN,
+        1 => tracing::Level::DEBUG,
+        _ => tracing::Level::TRACE,
+    };
+    let spans = match verbose {
+        0 => FmtSpan::NONE,
+        _ => FmtSpan::CLOSE,
+    };
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_span_events(spans)
+        .with_writer(io::stderr)
+        .init();
+}
+
+/// Point d'entrée principal du programme
+/// Les erreurs sont affichées avec leur message (ligne fautive, commande `fetch` à lancer, ...)
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_tracing(cli.verbose);
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Erreur : {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
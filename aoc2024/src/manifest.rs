@@ -0,0 +1,62 @@
+// Importation des bibliothèques nécessaires
+use crate::scaffold; // Pour l'emplacement des exemples de chaque année
+use serde::Deserialize; // Pour lire le manifeste
+use std::collections::BTreeMap; // Les jours du manifeste, dans l'ordre
+use std::fs; // Pour lire le manifeste
+use std::io; // Pour les erreurs de lecture
+use std::path::{Path, PathBuf}; // Pour les chemins des fichiers
+
+/// Un fichier d'entrée et les réponses attendues pour ses deux parties
+#[derive(Debug, Clone, Deserialize)]
+pub struct Case {
+    pub file: PathBuf, // Relatif au dossier des exemples
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Case {
+    /// Réponse attendue d'une partie, si elle est renseignée
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+}
+
+/// Les cas déclarés pour un jour
+#[derive(Debug, Clone, Deserialize)]
+pub struct DayCases {
+    pub example: Case,       // L'exemple de l'énoncé, toujours présent
+    pub input: Option<Case>, // Une vraie entrée, ignorée si le fichier manque
+}
+
+/// Le manifeste `tests.toml` d'une année, indexé par jour (`day01`, `day02`, ...)
+pub type Manifest = BTreeMap<String, DayCases>;
+
+/// Clé d'un jour dans le manifeste, comme `day01`
+pub fn day_key(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+/// Dossier contenant le manifeste et les exemples d'une année, dans le dépôt `root`
+pub fn examples_dir(root: &Path, year: u16) -> PathBuf {
+    root.join(scaffold::examples_dir(year))
+}
+
+/// Fonction pour lire le manifeste d'une année du dépôt `root`
+pub fn load(root: &Path, year: u16) -> io::Result<Manifest> {
+    let path = examples_dir(root, year).join("tests.toml");
+    let text = fs::read_to_string(&path).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!("manifeste {} illisible : {}", path.display(), e),
+        )
+    })?;
+    toml::from_str(&text).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("manifeste {} invalide : {}", path.display(), e),
+        )
+    })
+}
@@ -0,0 +1,124 @@
+// Importation des bibliothèques nécessaires
+use clap::ValueEnum; // Pour choisir le format depuis la ligne de commande
+use serde::Serialize; // Pour la sortie JSON
+use std::time::Duration; // Pour la durée d'exécution
+
+/// Format d'affichage des résultats du lanceur
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum OutputFormat {
+    /// Lignes lisibles, comme « Jour 1, partie 1 : 2430334 (2.04ms) »
+    #[default]
+    Text,
+    /// Un objet JSON par ligne, pour les scripts
+    Json,
+}
+
+/// Issue de l'exécution d'une partie
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RunStatus {
+    /// La partie a donné une réponse
+    Ok,
+    /// La partie a échoué : erreur de lecture ou d'analyse, ou panique
+    Failed,
+    /// La partie n'a pas fini dans le délai imparti (`run --all --timeout`)
+    Timeout,
+}
+
+impl RunStatus {
+    /// Étiquette du récapitulatif, comme `FAILED`
+    pub fn label(self) -> &'static str {
+        match self {
+            RunStatus::Ok => "OK",
+            RunStatus::Failed => "FAILED",
+            RunStatus::Timeout => "TIMEOUT",
+        }
+    }
+}
+
+/// Résultat de l'exécution d'une partie : sa réponse ou son erreur, et sa durée
+#[derive(Debug, Clone, Serialize)]
+pub struct RunRecord {
+    pub day: u8,
+    pub part: u8,
+    pub status: RunStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>, // Absente si la partie a échoué
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>, // Présente seulement si la partie a échoué
+    pub duration_us: u64, // Durée d'exécution, en microsecondes
+}
+
+impl RunRecord {
+    /// Crée l'enregistrement d'une partie à partir de son résultat
+    pub fn new(day: u8, part: u8, result: Result<String, String>, elapsed: Duration) -> Self {
+        let (status, answer, error) = match result {
+            Ok(answer) => (RunStatus::Ok, Some(answer), None),
+            Err(error) => (RunStatus::Failed, None, Some(error)),
+        };
+        RunRecord {
+            day,
+            part,
+            status,
+            answer,
+            error,
+            duration_us: elapsed.as_micros() as u64,
+        }
+    }
+
+    /// Crée l'enregistrement d'une partie abandonnée après `timeout`
+    pub fn timed_out(day: u8, part: u8, timeout: Duration) -> Self {
+        RunRecord {
+            day,
+            part,
+            status: RunStatus::Timeout,
+            answer: None,
+            error: Some(format!("pas de réponse après {:.2?}", timeout)),
+            duration_us: timeout.as_micros() as u64,
+        }
+    }
+
+    /// Indique si la partie a échoué (délai dépassé compris)
+    pub fn is_err(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// Fonction pour mettre en forme l'enregistrement, sur une seule ligne
+    pub fn render(&self, format: OutputFormat) -> String {
+        match format {
+            OutputFormat::Json => {
+                serde_json::to_string(self).expect("un enregistrement est toujours sérialisable")
+            }
+            OutputFormat::Text => {
+                let elapsed = Duration::from_micros(self.duration_us);
+                match (&self.answer, &self.error) {
+                    (_, Some(error)) if self.status == RunStatus::Timeout => format!(
+                        "Jour {}, partie {} : délai dépassé : {}",
+                        self.day, self.part, error
+                    ),
+                    (_, Some(error)) => format!(
+                        "Jour {}, partie {} : erreur : {}",
+                        self.day, self.part, error
+                    ),
+                    (answer, None) => format!(
+                        "Jour {}, partie {} : {} ({:.2?})",
+                        self.day,
+                        self.part,
+                        answer.as_deref().unwrap_or_default(),
+                        elapsed
+                    ),
+                }
+            }
+        }
+    }
+
+    /// Fonction pour afficher l'enregistrement : les erreurs en texte vont sur la sortie d'erreur,
+    /// tout le reste (y compris les erreurs en JSON) sur la sortie standard
+    pub fn print(&self, format: OutputFormat) {
+        if self.is_err() && format == OutputFormat::Text {
+            eprintln!("{}", self.render(format));
+        } else {
+            println!("{}", self.render(format));
+        }
+    }
+}
@@ -0,0 +1,74 @@
+// Importation des bibliothèques nécessaires
+use aoc_utils::answer::Answer; // La réponse retournée par les solutions
+use aoc_utils::error::AocError; // L'erreur retournée par les solutions
+use aoc_utils::generate::InputGenerator; // Pour générer des entrées synthétiques
+use aoc_utils::solution::Solution; // L'interface commune aux solutions
+#[cfg(feature = "viz")]
+use aoc_utils::visualize::Visualize; // L'interface des animations
+
+/// Année des solutions utilisée quand `--year` n'est pas précisé
+pub const DEFAULT_YEAR: u16 = 2024;
+
+// Le tableau `DAYS` des solutions de 2024 est généré par `build.rs` à partir des membres
+// `aoc2024-N` de l'espace de travail dont dépend le lanceur : `aoc2024 new` déclare les deux
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+/// Les solutions d'une année, indexées par numéro de jour
+pub type Days = &'static [(u8, &'static dyn Solution)];
+
+/// Les solutions de chaque année, y compris celles des années précédentes ajoutées après coup
+/// Une autre année a son propre tableau, comme `DAYS_2023`, créé par `aoc2024 new --year 2023`
+pub static YEARS: &[(u16, Days)] = &[(2024, DAYS)];
+
+/// Fonction pour retrouver les solutions d'une année (aucune si l'année n'est pas enregistrée)
+pub fn days(year: u16) -> Days {
+    YEARS
+        .iter()
+        .find(|&&(number, _)| number == year)
+        .map_or(&[], |&(_, days)| days)
+}
+
+/// Fonction pour retrouver la solution d'un jour d'une année, si elle est implémentée
+pub fn get_in(year: u16, day: u8) -> Option<&'static dyn Solution> {
+    days(year)
+        .iter()
+        .find(|&&(number, _)| number == day)
+        .map(|&(_, solution)| solution)
+}
+
+/// Fonction pour retrouver la solution d'un jour de l'année par défaut, si elle est implémentée
+pub fn get(day: u8) -> Option<&'static dyn Solution> {
+    get_in(DEFAULT_YEAR, day)
+}
+
+/// Les solutions de 2024 qui savent générer des entrées synthétiques, indexées par numéro de jour
+pub static GENERATORS: &[(u8, &dyn InputGenerator)] = &[(1, &aoc2024_1::Day1)];
+
+/// Fonction pour retrouver le générateur d'entrées d'un jour, si sa solution en propose un
+pub fn generator(day: u8) -> Option<&'static dyn InputGenerator> {
+    GENERATORS
+        .iter()
+        .find(|&&(number, _)| number == day)
+        .map(|&(_, generator)| generator)
+}
+
+/// Les solutions qui savent s'animer dans le terminal, indexées par numéro de jour
+#[cfg(feature = "viz")]
+pub static VISUALIZATIONS: &[(u8, &dyn Visualize)] = &[(1, &aoc2024_1::Day1)];
+
+/// Fonction pour retrouver l'animation d'un jour, si sa solution en propose une
+#[cfg(feature = "viz")]
+pub fn visualization(day: u8) -> Option<&'static dyn Visualize> {
+    VISUALIZATIONS
+        .iter()
+        .find(|&&(number, _)| number == day)
+        .map(|&(_, visualization)| visualization)
+}
+
+/// Fonction pour exécuter une partie (1 ou 2) d'une solution sur une entrée déjà lue
+pub fn run_part(solution: &dyn Solution, part: u8, input: &str) -> Result<Answer, AocError> {
+    match part {
+        1 => solution.part1(input),
+        _ => solution.part2(input),
+    }
+}
@@ -0,0 +1,147 @@
+// Importation des bibliothèques nécessaires
+use crate::output::RunRecord; // Les résultats d'exécution rapportés
+use clap::ValueEnum; // Pour choisir le format depuis la ligne de commande
+use std::time::Duration; // Pour afficher les durées
+
+/// Format du rapport d'exécution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum ReportFormat {
+    /// Document Markdown, lisible tel quel et affiché par les forges
+    #[default]
+    Md,
+    /// Page HTML autonome
+    Html,
+}
+
+impl ReportFormat {
+    /// Nom du fichier du rapport par défaut
+    pub fn default_file(&self) -> &'static str {
+        match self {
+            ReportFormat::Md => "REPORT.md",
+            ReportFormat::Html => "REPORT.html",
+        }
+    }
+}
+
+/// Ce que le rapport présente pour un jour : ses notes et le résultat de chaque partie
+#[derive(Debug, Clone)]
+pub struct DayReport {
+    pub day: u8,
+    pub description: &'static str, // Notes de la solution, vides si elle n'en propose pas
+    pub records: Vec<RunRecord>,   // Les parties exécutées, dans l'ordre
+}
+
+/// Texte affiché à la place d'une réponse masquée
+const REDACTED: &str = "(masquée)";
+
+/// Fonction pour afficher une durée en microsecondes, comme `2.04ms`
+fn format_duration(duration_us: u64) -> String {
+    format!("{:.2?}", Duration::from_micros(duration_us))
+}
+
+/// Fonction pour décrire le résultat d'une partie : sa réponse (ou `(masquée)`), ou son erreur
+fn outcome(record: &RunRecord, redact: bool) -> String {
+    match (&record.answer, &record.error) {
+        (_, Some(error)) => format!("erreur : {}", error),
+        _ if redact => REDACTED.to_string(),
+        (answer, None) => answer.clone().unwrap_or_default(),
+    }
+}
+
+/// Fonction pour produire le rapport de tous les jours exécutés
+/// Avec `redact`, les réponses sont masquées (les réponses ne se partagent pas plus que les entrées)
+pub fn render_report(year: u16, days: &[DayReport], format: ReportFormat, redact: bool) -> String {
+    match format {
+        ReportFormat::Md => render_markdown(year, days, redact),
+        ReportFormat::Html => render_html(year, days, redact),
+    }
+}
+
+/// Durée totale et nombre de parties en échec de tous les jours
+fn summary(days: &[DayReport]) -> (u64, usize) {
+    let records = days.iter().flat_map(|day| &day.records);
+    let total = records.clone().map(|record| record.duration_us).sum();
+    (total, records.filter(|record| record.is_err()).count())
+}
+
+/// Fonction pour produire le rapport en Markdown
+fn render_markdown(year: u16, days: &[DayReport], redact: bool) -> String {
+    let mut report = format!("# Advent of Code {}\n\n", year);
+    report.push_str("| Jour | Partie | Réponse | Durée |\n|---:|---:|---|---:|\n");
+    for day in days {
+        for record in &day.records {
+            report.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                record.day,
+                record.part,
+                // Une réponse en grille s'étale sur plusieurs lignes dans la même case
+                outcome(record, redact)
+                    .replace('|', "\\|")
+                    .replace('\n', "<br>"),
+                format_duration(record.duration_us)
+            ));
+        }
+    }
+
+    let (total, failures) = summary(days);
+    report.push_str(&format!(
+        "\n**Durée totale : {}** ({} jour(s), {} partie(s) en échec)\n",
+        format_duration(total),
+        days.len(),
+        failures
+    ));
+
+    for day in days.iter().filter(|day| !day.description.is_empty()) {
+        report.push_str(&format!("\n## Jour {}\n\n{}\n", day.day, day.description));
+    }
+    report
+}
+
+/// Fonction pour échapper un texte inséré dans une page HTML
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Fonction pour produire le rapport en HTML
+fn render_html(year: u16, days: &[DayReport], redact: bool) -> String {
+    let title = format!("Advent of Code {}", year);
+    let mut report = format!(
+        "<!DOCTYPE html>\n<html lang=\"fr\">\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n\
+         </head>\n<body>\n<h1>{}</h1>\n<table>\n\
+         <tr><th>Jour</th><th>Partie</th><th>Réponse</th><th>Durée</th></tr>\n",
+        title, title
+    );
+    for day in days {
+        for record in &day.records {
+            report.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                record.day,
+                record.part,
+                escape_html(&outcome(record, redact)).replace('\n', "<br>"),
+                format_duration(record.duration_us)
+            ));
+        }
+    }
+    report.push_str("</table>\n");
+
+    let (total, failures) = summary(days);
+    report.push_str(&format!(
+        "<p><strong>Durée totale : {}</strong> ({} jour(s), {} partie(s) en échec)</p>\n",
+        format_duration(total),
+        days.len(),
+        failures
+    ));
+
+    for day in days.iter().filter(|day| !day.description.is_empty()) {
+        report.push_str(&format!(
+            "<h2>Jour {}</h2>\n<p>{}</p>\n",
+            day.day,
+            escape_html(day.description)
+        ));
+    }
+    report.push_str("</body>\n</html>\n");
+    report
+}
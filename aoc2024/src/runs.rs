@@ -0,0 +1,54 @@
+// Importation des bibliothèques nécessaires
+use crate::output::RunRecord; // Les résultats d'exécution à conserver
+use serde::{Deserialize, Serialize}; // Pour le fichier des durées
+use std::collections::BTreeMap; // Les jours du fichier, dans l'ordre
+use std::fs; // Pour lire et écrire le fichier des durées
+use std::io; // Pour les erreurs de lecture et d'écriture
+use std::path::{Path, PathBuf}; // Pour le chemin du fichier des durées
+
+/// Durées de la dernière exécution réussie de chaque partie d'un jour, en microsecondes
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct RunTimes {
+    pub part1_us: Option<u64>,
+    pub part2_us: Option<u64>,
+}
+
+impl RunTimes {
+    /// Durée de la dernière exécution d'une partie, si elle a déjà été exécutée
+    pub fn get(&self, part: u8) -> Option<u64> {
+        match part {
+            1 => self.part1_us,
+            _ => self.part2_us,
+        }
+    }
+}
+
+/// Fonction pour calculer le chemin du fichier des durées, conservé avec les entrées de l'année
+/// (`inputs/2024/runs.json` par défaut)
+pub fn runs_path(dir: &Path) -> PathBuf {
+    dir.join("runs.json")
+}
+
+/// Fonction pour lire les durées conservées dans `dir`, indexées par jour
+/// Aucune durée n'est connue si le fichier n'existe pas encore
+pub fn load(dir: &Path) -> io::Result<BTreeMap<u8, RunTimes>> {
+    match fs::read_to_string(runs_path(dir)) {
+        Ok(text) => Ok(serde_json::from_str(&text)?),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Fonction pour conserver la durée de chaque partie réussie (les échecs ne remplacent rien)
+pub fn record(dir: &Path, records: &[RunRecord]) -> io::Result<()> {
+    let mut runs = load(dir)?;
+    for record in records.iter().filter(|record| !record.is_err()) {
+        let times = runs.entry(record.day).or_default();
+        match record.part {
+            1 => times.part1_us = Some(record.duration_us),
+            _ => times.part2_us = Some(record.duration_us),
+        }
+    }
+    fs::create_dir_all(dir)?;
+    fs::write(runs_path(dir), serde_json::to_string_pretty(&runs)?)
+}
@@ -0,0 +1,289 @@
+// Importation des bibliothèques nécessaires
+use crate::registry::DEFAULT_YEAR; // L'année dont les jours sont générés par `build.rs` et vont dans `examples/`
+use std::fs; // Pour créer et modifier les fichiers du dépôt
+use std::io; // Pour les erreurs de lecture et d'écriture
+use std::path::{Path, PathBuf}; // Pour les chemins des fichiers générés
+use toml_edit::{value, DocumentMut, InlineTable}; // Pour modifier les manifestes sans perdre leur mise en forme
+
+/// Modèle du manifeste du paquet d'un jour
+const CARGO_TEMPLATE: &str = r#"[package]
+name = "aoc{year}-{day}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc_utils = { path = "../aoc_utils" }
+"#;
+
+/// Modèle de la bibliothèque d'un jour : la structure de la solution et ses deux parties à écrire
+const LIB_TEMPLATE: &str = r#"//! Jour {day} de {year} : à compléter
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::answer::Answer; // La réponse d'une partie
+use aoc_utils::error::AocError; // Pour signaler une entrée mal formée
+use aoc_utils::solution::Solution; // L'interface commune aux solutions
+
+/// Solution du jour {day}, enregistrée dans le lanceur `aoc2024`
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    /// Première partie
+    fn part1(&self, _input: &str) -> Result<Answer, AocError> {
+        todo!("jour {day}, partie 1")
+    }
+
+    /// Deuxième partie
+    fn part2(&self, _input: &str) -> Result<Answer, AocError> {
+        todo!("jour {day}, partie 2")
+    }
+}
+"#;
+
+/// Modèle de l'entrée du jour dans le manifeste des exemples : les réponses restent à renseigner
+const MANIFEST_TEMPLATE: &str = r#"
+[day{nn}.example]
+file = "day{nn}_example.txt"
+# part1 = "todo"
+# part2 = "todo"
+"#;
+
+/// Nom du paquet d'un jour, comme `aoc2024-1` (ou `aoc2023-1` pour un jour d'une année précédente)
+pub fn crate_name(year: u16, day: u8) -> String {
+    format!("aoc{}-{}", year, day)
+}
+
+/// Dossier des exemples d'une année, relatif à la racine du dépôt : `examples` pour l'année par défaut,
+/// `examples/<année>` pour les autres
+pub fn examples_dir(year: u16) -> PathBuf {
+    match year {
+        DEFAULT_YEAR => PathBuf::from("examples"),
+        _ => Path::new("examples").join(year.to_string()),
+    }
+}
+
+/// Fonction pour générer le squelette d'un nouveau jour d'une année dans l'espace de travail `root` :
+/// le paquet `aoc<année>-N`, son exemple vide, son entrée dans le manifeste `tests.toml` des exemples,
+/// sa déclaration dans l'espace de travail et son enregistrement dans le registre du lanceur
+/// Les jours de l'année par défaut sont enregistrés par `aoc2024/build.rs` dès leur déclaration
+/// dans l'espace de travail ; ceux des autres années sont ajoutés à leur tableau `DAYS_<année>`
+/// Échoue sans rien modifier si le paquet du jour existe déjà ou si le jour est déjà enregistré
+/// Retourne les fichiers créés ou modifiés
+pub fn scaffold_day(root: &Path, year: u16, day: u8) -> io::Result<Vec<PathBuf>> {
+    let crate_name = crate_name(year, day);
+    let crate_dir = root.join(&crate_name);
+    if crate_dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("le paquet {} existe déjà", crate_dir.display()),
+        ));
+    }
+    // Le registre est lu (et le jour vérifié absent) avant de créer quoi que ce soit
+    let registry = root.join("aoc2024/src/registry.rs");
+    let source = fs::read_to_string(&registry).map_err(|e| {
+        io::Error::new(
+            e.kind(),
+            format!(
+                "{} n'est pas la racine du dépôt aoc2024 : {}",
+                root.display(),
+                e
+            ),
+        )
+    })?;
+    let registered = match year {
+        DEFAULT_YEAR => None,
+        _ => Some(register_day(&source, year, day)?),
+    };
+
+    let fill = |template: &str| {
+        template
+            .replace("{year}", &year.to_string())
+            .replace("{day}", &day.to_string())
+            .replace("{nn}", &format!("{:02}", day))
+    };
+    let mut touched = Vec::new();
+
+    // Le paquet du jour
+    fs::create_dir_all(crate_dir.join("src"))?;
+    touched.push(write_new(
+        &crate_dir.join("Cargo.toml"),
+        &fill(CARGO_TEMPLATE),
+    )?);
+    touched.push(write_new(
+        &crate_dir.join("src/lib.rs"),
+        &fill(LIB_TEMPLATE),
+    )?);
+
+    // L'exemple vide et ses réponses attendues, vérifiées par `aoc2024/tests/examples.rs`
+    let examples = root.join(examples_dir(year));
+    fs::create_dir_all(&examples)?;
+    touched.push(write_new(
+        &examples.join(format!("day{:02}_example.txt", day)),
+        "",
+    )?);
+    let manifest = examples.join("tests.toml");
+    let mut tests = fs::read_to_string(&manifest).unwrap_or_default();
+    tests.push_str(&fill(MANIFEST_TEMPLATE));
+    fs::write(&manifest, tests)?;
+    touched.push(manifest);
+
+    // Déclaration dans l'espace de travail
+    let workspace = root.join("Cargo.toml");
+    edit_toml(&workspace, |doc| {
+        let members = doc["workspace"]["members"]
+            .as_array_mut()
+            .ok_or_else(|| invalid("`workspace.members` absent de Cargo.toml"))?;
+        let mut names: Vec<String> = members
+            .iter()
+            .filter_map(|m| m.as_str().map(String::from))
+            .collect();
+        names.push(crate_name.clone());
+        names.sort();
+        *members = names.iter().collect();
+        Ok(())
+    })?;
+    touched.push(workspace);
+
+    // Dépendance du lanceur vers le nouveau paquet
+    let runner = root.join("aoc2024/Cargo.toml");
+    edit_toml(&runner, |doc| {
+        let deps = doc["dependencies"]
+            .as_table_mut()
+            .ok_or_else(|| invalid("`dependencies` absent de aoc2024/Cargo.toml"))?;
+        let mut path = InlineTable::new();
+        path.insert("path", format!("../{}", crate_name).into());
+        deps[&crate_name] = value(path);
+        deps.sort_values();
+        Ok(())
+    })?;
+    touched.push(runner);
+
+    if let Some(registered) = registered {
+        fs::write(&registry, registered)?;
+        touched.push(registry);
+    }
+
+    Ok(touched)
+}
+
+/// Fonction pour créer un fichier, en refusant d'écraser un fichier existant
+fn write_new(path: &Path, contents: &str) -> io::Result<PathBuf> {
+    fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| io::Write::write_all(&mut file, contents.as_bytes()))?;
+    Ok(path.to_path_buf())
+}
+
+/// Fonction pour lire un manifeste TOML, le modifier avec `edit` puis le réécrire
+fn edit_toml(path: &Path, edit: impl FnOnce(&mut DocumentMut) -> io::Result<()>) -> io::Result<()> {
+    let mut doc: DocumentMut = fs::read_to_string(path)?
+        .parse()
+        .map_err(|e| invalid(&format!("{} : {}", path.display(), e)))?;
+    edit(&mut doc)?;
+    fs::write(path, doc.to_string())
+}
+
+/// Fonction pour ajouter un jour d'une année précédente au tableau `DAYS_<année>` du registre
+/// Pour une nouvelle année, son tableau est d'abord créé (vide) juste avant `YEARS`, puis ajouté à `YEARS`
+fn register_day(source: &str, year: u16, day: u8) -> io::Result<String> {
+    let array = format!("DAYS_{}", year);
+    let prefix = format!("pub static {}: &[(u8, &dyn Solution)] = &[", array);
+    let mut source = source.to_string();
+    if !source.contains(&prefix) {
+        let years_prefix = "pub static YEARS: &[(u16, Days)] = &[";
+        let years_doc = "/// Les solutions de chaque année";
+        let at = source
+            .find(years_doc)
+            .ok_or_else(|| invalid("tableau `YEARS` introuvable dans le registre"))?;
+        let declaration = format!(
+            "/// Toutes les solutions implémentées de {}, indexées par numéro de jour\n{}];\n\n",
+            year, prefix
+        );
+        source.insert_str(at, &declaration);
+        source = insert_entry(
+            &source,
+            years_prefix,
+            year,
+            format!("({}, {})", year, array),
+            format!("l'année {} est déjà enregistrée", year),
+        )?;
+    }
+    let entry = format!(
+        "({}, &{}::Day{})",
+        day,
+        crate_name(year, day).replace('-', "_"),
+        day
+    );
+    let duplicate = format!("le jour {} de {} est déjà enregistré", day, year);
+    insert_entry(&source, &prefix, day.into(), entry, duplicate)
+}
+
+/// Fonction pour ajouter une entrée `(clé, ...)` à un tableau du registre qui commence par `prefix`
+/// Le tableau est réécrit trié par clé, sur une ligne s'il tient en 100 colonnes (comme le fait rustfmt)
+/// `duplicate` est le message d'erreur si la clé est déjà présente
+fn insert_entry(
+    source: &str,
+    prefix: &str,
+    key: u16,
+    entry: String,
+    duplicate: String,
+) -> io::Result<String> {
+    let name = prefix
+        .trim_start_matches("pub static ")
+        .split(':')
+        .next()
+        .unwrap_or(prefix);
+    let start = source
+        .find(prefix)
+        .ok_or_else(|| invalid(&format!("tableau `{}` introuvable dans le registre", name)))?;
+    let body_start = start + prefix.len();
+    let body_end = body_start
+        + source[body_start..]
+            .find("];")
+            .ok_or_else(|| invalid(&format!("fin du tableau `{}` introuvable", name)))?;
+
+    // Chaque entrée est de la forme `(N, &aoc2024_N::DayN)` (ou `(2024, DAYS)` pour `YEARS`)
+    let mut entries: Vec<(u16, String)> = source[body_start..body_end]
+        .split("),")
+        .map(|entry| entry.trim().trim_end_matches(')').trim())
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let number = entry
+                .trim_start_matches('(')
+                .split(',')
+                .next()
+                .and_then(|n| n.trim().parse().ok())
+                .ok_or_else(|| invalid(&format!("entrée du registre illisible : {}", entry)))?;
+            Ok((number, format!("{})", entry)))
+        })
+        .collect::<io::Result<_>>()?;
+    if entries.iter().any(|&(number, _)| number == key) {
+        return Err(invalid(&duplicate));
+    }
+    entries.push((key, entry));
+    entries.sort_by_key(|&(number, _)| number);
+
+    let items: Vec<&str> = entries.iter().map(|(_, entry)| entry.as_str()).collect();
+    let single_line = format!("{}{}];", prefix, items.join(", "));
+    let array = if single_line.len() <= 100 {
+        single_line
+    } else {
+        let lines: String = items
+            .iter()
+            .map(|item| format!("    {},\n", item))
+            .collect();
+        format!("{}\n{}];", prefix, lines)
+    };
+    Ok(format!(
+        "{}{}{}",
+        &source[..start],
+        array,
+        &source[body_end + 2..]
+    ))
+}
+
+/// Erreur pour un fichier du dépôt qui n'a pas la forme attendue
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
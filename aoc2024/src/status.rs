@@ -0,0 +1,56 @@
+// Importation des bibliothèques nécessaires
+use crate::answers::Answers; // Les parties résolues
+use crate::runs::RunTimes; // Les durées des dernières exécutions
+use std::collections::BTreeMap; // Les durées, indexées par jour
+use std::time::Duration; // Pour afficher les durées
+
+/// Fonction pour construire le tableau d'avancement des 25 jours du calendrier :
+/// jours implémentés (présents dans `registered`), durée de la dernière exécution de chaque partie,
+/// et une étoile par partie résolue (réponse confirmée dans `answers`), comme sur le site
+/// La dernière ligne totalise les étoiles et les durées
+pub fn status_table(registered: &[u8], answers: &Answers, runs: &BTreeMap<u8, RunTimes>) -> String {
+    let mut table = format!(
+        "{:>4}  {:<10}  {:>10}  {:>10}  {}\n",
+        "jour", "implémenté", "partie 1", "partie 2", "étoiles"
+    );
+    let (mut stars, mut total_us) = (0, 0);
+
+    for day in 1..=25 {
+        let times = runs.get(&day).copied().unwrap_or_default();
+        let time = |part: u8| {
+            times.get(part).map_or("-".to_string(), |us| {
+                format!("{:.2?}", Duration::from_micros(us))
+            })
+        };
+        total_us += times.get(1).unwrap_or(0) + times.get(2).unwrap_or(0);
+
+        let solved = (1..=2)
+            .filter(|&part| answers.get(day, part).is_some())
+            .count();
+        stars += solved;
+
+        let implemented = if registered.contains(&day) {
+            "oui"
+        } else {
+            "non"
+        };
+        let row = format!(
+            "{:>4}  {:<10}  {:>10}  {:>10}  {}",
+            day,
+            implemented,
+            time(1),
+            time(2),
+            "⭐".repeat(solved)
+        );
+        // Pas d'espaces en fin de ligne pour les jours sans étoile
+        table.push_str(row.trim_end());
+        table.push('\n');
+    }
+
+    table.push_str(&format!(
+        "Étoiles : {}/50, durée totale : {:.2?}\n",
+        stars,
+        Duration::from_micros(total_us)
+    ));
+    table
+}
@@ -0,0 +1,129 @@
+// Importation des bibliothèques nécessaires
+use serde::{Deserialize, Serialize}; // Pour l'historique des soumissions
+use std::fs; // Pour lire et écrire l'historique
+use std::io; // Pour les erreurs de réseau et de fichiers
+use std::path::{Path, PathBuf}; // Pour le chemin de l'historique
+
+/// Verdict du site après la soumission d'une réponse
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Verdict {
+    Correct,       // La réponse est juste
+    TooHigh,       // La réponse est fausse et trop grande
+    TooLow,        // La réponse est fausse et trop petite
+    Wrong,         // La réponse est fausse, sans indication
+    RateLimited,   // Soumission trop rapprochée de la précédente : rien n'a été vérifié
+    AlreadySolved, // La partie est déjà résolue (ou pas encore débloquée)
+    Unknown,       // Réponse du site non reconnue
+}
+
+impl Verdict {
+    /// Indique si le verdict porte réellement sur la réponse (et mérite d'être conservé)
+    pub fn is_definitive(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Correct | Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong
+        )
+    }
+}
+
+/// Une réponse déjà soumise et son verdict
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Fonction pour calculer le chemin de l'historique des soumissions, conservé avec les entrées
+/// de l'année (`inputs/2024/submissions.json` par défaut)
+pub fn history_path(dir: &Path) -> PathBuf {
+    dir.join("submissions.json")
+}
+
+/// Fonction pour lire l'historique des soumissions conservé dans `dir` (vide si le fichier
+/// n'existe pas encore)
+pub fn load_history(dir: &Path) -> io::Result<Vec<Submission>> {
+    match fs::read_to_string(history_path(dir)) {
+        Ok(text) => Ok(serde_json::from_str(&text)?),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Fonction pour ajouter une soumission à l'historique
+pub fn record(dir: &Path, submission: Submission) -> io::Result<()> {
+    let mut history = load_history(dir)?;
+    history.push(submission);
+    fs::create_dir_all(dir)?;
+    fs::write(history_path(dir), serde_json::to_string_pretty(&history)?)
+}
+
+/// Fonction pour retrouver, dans l'historique, ce qui rend une soumission inutile :
+/// la même réponse déjà soumise, une bonne réponse déjà trouvée, ou une réponse numérique
+/// hors des bornes établies par les verdicts « trop grand » et « trop petit »
+pub fn known_verdict<'a>(
+    history: &'a [Submission],
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Option<&'a Submission> {
+    let previous: Vec<&Submission> = history
+        .iter()
+        .filter(|s| s.day == day && s.part == part)
+        .collect();
+    if let Some(same) = previous.iter().find(|s| s.answer == answer) {
+        return Some(same);
+    }
+    if let Some(correct) = previous.iter().find(|s| s.verdict == Verdict::Correct) {
+        return Some(correct);
+    }
+
+    let value: i128 = answer.trim().parse().ok()?;
+    previous.into_iter().find(|s| {
+        let bound = s.answer.trim().parse::<i128>();
+        match (&s.verdict, bound) {
+            (Verdict::TooHigh, Ok(high)) => value >= high,
+            (Verdict::TooLow, Ok(low)) => value <= low,
+            _ => false,
+        }
+    })
+}
+
+/// Fonction pour reconnaître le verdict dans la page renvoyée par le site
+pub fn parse_verdict(page: &str) -> Verdict {
+    if page.contains("That's the right answer") {
+        Verdict::Correct
+    } else if page.contains("You gave an answer too recently") {
+        Verdict::RateLimited
+    } else if page.contains("You don't seem to be solving the right level") {
+        Verdict::AlreadySolved
+    } else if page.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if page.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if page.contains("That's not the right answer") {
+        Verdict::Wrong
+    } else {
+        Verdict::Unknown
+    }
+}
+
+/// Fonction pour extraire le temps d'attente annoncé par le site, comme « 4m 12s »
+pub fn wait_time(page: &str) -> Option<&str> {
+    let start = page.find("You have ")? + "You have ".len();
+    let end = start + page[start..].find(" left to wait")?;
+    Some(&page[start..end])
+}
+
+/// Fonction pour envoyer une réponse à adventofcode.com et retourner la page de réponse
+pub fn post_answer(day: u8, part: u8, answer: &str, session: &str) -> io::Result<String> {
+    let url = format!("https://adventofcode.com/2024/day/{}/answer", day);
+    let response = ureq::post(&url)
+        .set("Cookie", &format!("session={}", session))
+        // Le site demande aux outils automatiques de s'identifier
+        .set("User-Agent", "github.com/Ludo000/aoc2024")
+        .send_form(&[("level", &part.to_string()), ("answer", answer)])
+        .map_err(|e| io::Error::other(format!("échec de la soumission à {} : {}", url, e)))?;
+    response.into_string()
+}
@@ -0,0 +1,51 @@
+// Importation des bibliothèques nécessaires
+use crate::{manifest, scaffold}; // Pour les dossiers à surveiller
+use notify::{EventKind, RecursiveMode, Watcher}; // Pour être prévenu des modifications
+use std::path::{Path, PathBuf}; // Pour les chemins surveillés
+use std::sync::mpsc; // Pour recevoir les modifications
+use std::time::Duration; // Pour regrouper les modifications rapprochées
+
+/// Délai pendant lequel les modifications rapprochées (un éditeur qui écrit plusieurs fichiers,
+/// ou le même fichier en plusieurs fois) sont regroupées en une seule
+pub const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Fonction pour lister les dossiers à surveiller pour un jour, avec leur mode de surveillance :
+/// les sources du jour et des utilitaires, et le dossier des exemples (manifeste compris)
+pub fn watched_paths(root: &Path, year: u16, day: u8) -> Vec<(PathBuf, RecursiveMode)> {
+    vec![
+        (
+            root.join(scaffold::crate_name(year, day)).join("src"),
+            RecursiveMode::Recursive,
+        ),
+        (root.join("aoc_utils").join("src"), RecursiveMode::Recursive),
+        (
+            manifest::examples_dir(root, year),
+            RecursiveMode::NonRecursive,
+        ),
+    ]
+}
+
+/// Fonction pour surveiller les dossiers donnés et appeler `on_change` après chaque modification
+/// Les événements qui ne modifient rien (simples lectures) sont ignorés ; ne s'arrête que sur erreur
+pub fn watch(
+    paths: &[(PathBuf, RecursiveMode)],
+    mut on_change: impl FnMut(),
+) -> notify::Result<()> {
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    for (path, mode) in paths {
+        watcher.watch(path, *mode)?;
+    }
+
+    while let Ok(event) = receiver.recv() {
+        if matches!(event?.kind, EventKind::Access(_)) {
+            continue;
+        }
+        // Vide les événements qui suivent de près, pour ne relancer qu'une fois
+        while let Ok(event) = receiver.recv_timeout(DEBOUNCE) {
+            event?;
+        }
+        on_change();
+    }
+    Ok(())
+}
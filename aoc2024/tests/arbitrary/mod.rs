@@ -0,0 +1,45 @@
+//! Générateurs d'entrées aléatoires pour les tests de propriétés
+//! Pour un nouveau jour, implémenter `ArbitraryInput` ici et ajouter ses propriétés dans `properties.rs`
+
+// Importation des bibliothèques nécessaires
+use proptest::collection::vec; // Pour générer des listes de taille aléatoire
+use proptest::prelude::*; // Les stratégies de génération
+use std::fmt::Debug; // Pour afficher les entrées des cas qui échouent
+
+/// Générateur d'entrées valides pour un jour
+/// Les données sont générées sous forme structurée (plus lisibles quand proptest réduit un contre-exemple),
+/// puis mises en forme comme une vraie entrée pour la solution du registre
+pub trait ArbitraryInput {
+    /// Le jour dont les entrées sont générées
+    const DAY: u8;
+    /// Les données d'une entrée
+    type Data: Clone + Debug;
+
+    /// Stratégie de génération des données
+    fn strategy() -> BoxedStrategy<Self::Data>;
+
+    /// Texte de l'entrée correspondant aux données, au format de l'énoncé
+    fn render(data: &Self::Data) -> String;
+}
+
+/// Entrées du jour 1 : deux listes de même longueur d'identifiants à 5 chiffres
+/// Les listes restent courtes pour que chaque cas s'exécute vite
+pub struct Day1Input;
+
+impl ArbitraryInput for Day1Input {
+    const DAY: u8 = 1;
+    type Data = (Vec<i64>, Vec<i64>);
+
+    fn strategy() -> BoxedStrategy<Self::Data> {
+        (0..100usize)
+            .prop_flat_map(|n| (vec(10_000..100_000i64, n), vec(10_000..100_000i64, n)))
+            .boxed()
+    }
+
+    fn render((left, right): &Self::Data) -> String {
+        left.iter()
+            .zip(right)
+            .map(|(l, r)| format!("{}   {}\n", l, r))
+            .collect()
+    }
+}
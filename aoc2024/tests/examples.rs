@@ -0,0 +1,96 @@
+//! Vérifie chaque solution du registre sur les exemples et entrées déclarés dans `examples/tests.toml`
+//! (et `examples/<année>/tests.toml` pour les années précédentes)
+
+// Importation des bibliothèques nécessaires
+use aoc2024::manifest::{self, Case, DayCases}; // Le manifeste des exemples
+use aoc2024::registry; // Les solutions à vérifier
+use std::collections::BTreeMap; // Les jours du manifeste, dans l'ordre
+use std::fs; // Pour lire les entrées
+use std::path::PathBuf; // Pour les chemins des fichiers
+
+/// Racine du dépôt
+fn root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+/// Dossier contenant le manifeste et les exemples d'une année
+fn examples_dir(year: u16) -> PathBuf {
+    manifest::examples_dir(&root(), year)
+}
+
+/// Fonction pour lire le manifeste d'une année, indexé par jour (`day01`, `day02`, ...)
+fn load_manifest(year: u16) -> BTreeMap<String, DayCases> {
+    manifest::load(&root(), year).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Fonction pour exécuter un cas et retourner la liste des réponses incorrectes
+fn check_case(year: u16, day: u8, label: &str, case: &Case) -> Vec<String> {
+    let solution = registry::get_in(year, day).unwrap();
+    let input = fs::read_to_string(examples_dir(year).join(&case.file)).unwrap();
+    [1, 2]
+        .into_iter()
+        .filter_map(|part| {
+            let expected = case.expected(part)?;
+            let answer = match registry::run_part(solution, part, &input) {
+                Ok(answer) if answer == expected => return None,
+                Ok(answer) => answer.to_string(),
+                Err(e) => format!("erreur ({})", e),
+            };
+            Some({
+                format!(
+                    "{}, jour {} ({}), partie {} : obtenu {}, attendu {}",
+                    year, day, label, part, answer, expected
+                )
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn every_registered_day_has_an_example() {
+    let mut missing = Vec::new();
+    for &(year, days) in registry::YEARS {
+        let manifest = load_manifest(year);
+        missing.extend(
+            days.iter()
+                .map(|&(day, _)| (year, day))
+                .filter(|&(_, day)| !manifest.contains_key(&manifest::day_key(day))),
+        );
+    }
+    assert!(missing.is_empty(), "jours sans exemple : {:?}", missing);
+}
+
+#[test]
+fn answers_match_manifest() {
+    let mut failures = Vec::new();
+    for &(year, _) in registry::YEARS {
+        for (key, cases) in load_manifest(year) {
+            let day: u8 = key
+                .strip_prefix("day")
+                .and_then(|n| n.parse().ok())
+                .unwrap_or_else(|| panic!("clé de jour invalide : {}", key));
+            assert!(
+                registry::get_in(year, day).is_some(),
+                "{} ({}) n'est pas dans le registre",
+                key,
+                year
+            );
+
+            failures.extend(check_case(year, day, "exemple", &cases.example));
+            match &cases.input {
+                Some(case) if examples_dir(year).join(&case.file).exists() => {
+                    failures.extend(check_case(year, day, "entrée", case))
+                }
+                Some(case) => {
+                    eprintln!("{} : entrée {} absente, ignorée", key, case.file.display())
+                }
+                None => {}
+            }
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "réponses incorrectes :\n{}",
+        failures.join("\n")
+    );
+}
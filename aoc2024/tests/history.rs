@@ -0,0 +1,58 @@
+//! Vérifie que l'historique des soumissions et les durées sont conservés dans le répertoire
+//! d'entrées choisi, et non dans `inputs/`
+
+// Importation des bibliothèques nécessaires
+use aoc2024::output::RunRecord; // Les résultats d'exécution à conserver
+use aoc2024::runs; // Les durées des dernières exécutions
+use aoc2024::submit::{self, Submission, Verdict}; // L'historique des soumissions
+use std::fs; // Pour nettoyer le répertoire temporaire
+use std::path::PathBuf; // Pour le répertoire temporaire
+use std::time::Duration; // Pour la durée des exécutions
+
+/// Répertoire temporaire propre à un test, vidé au préalable
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc2024-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn runs_are_stored_in_the_given_directory() {
+    let dir = scratch_dir("runs").join("2024");
+    let records = [
+        RunRecord::new(3, 1, Ok("42".to_string()), Duration::from_micros(120)),
+        RunRecord::new(3, 2, Err("échec".to_string()), Duration::from_micros(5)),
+    ];
+    runs::record(&dir, &records).unwrap();
+
+    assert_eq!(runs::runs_path(&dir), dir.join("runs.json"));
+    assert!(runs::runs_path(&dir).exists());
+    let times = runs::load(&dir).unwrap();
+    assert_eq!(times[&3].get(1), Some(120));
+    assert_eq!(times[&3].get(2), None, "un échec ne remplace aucune durée");
+    fs::remove_dir_all(dir.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn submissions_are_stored_in_the_given_directory() {
+    let dir = scratch_dir("submissions");
+    assert!(submit::load_history(&dir).unwrap().is_empty());
+    submit::record(
+        &dir,
+        Submission {
+            day: 5,
+            part: 2,
+            answer: "100".to_string(),
+            verdict: Verdict::TooHigh,
+        },
+    )
+    .unwrap();
+
+    let history = submit::load_history(&dir).unwrap();
+    assert_eq!(history.len(), 1);
+    assert!(submit::known_verdict(&history, 5, 2, "150").is_some());
+    assert!(submit::load_history(&scratch_dir("elsewhere"))
+        .unwrap()
+        .is_empty());
+    fs::remove_dir_all(&dir).unwrap();
+}
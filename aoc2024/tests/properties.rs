@@ -0,0 +1,130 @@
+//! Tests de propriétés : les solutions sont vérifiées sur des entrées générées aléatoirement
+//! par les générateurs de `arbitrary`, plutôt que sur quelques exemples fixes
+
+mod arbitrary;
+
+// Importation des bibliothèques nécessaires
+use aoc2024::registry; // Les solutions à vérifier
+use aoc2024_1::{calculate_similarity_score, calculate_total_distance, parse_data}; // Les fonctions du jour 1
+use aoc_utils::error::AocError; // Pour reconnaître un dépassement de capacité
+use arbitrary::{ArbitraryInput, Day1Input}; // Les générateurs d'entrées
+use proptest::collection::vec; // Pour générer des listes de taille aléatoire
+use proptest::prelude::*; // Les stratégies de génération et les macros de test
+
+/// Fonction pour vérifier qu'une solution du registre répond aux deux parties sur une entrée générée,
+/// et que chacune de ses variantes donne la même réponse
+fn solves_generated_input<G: ArbitraryInput>(data: &G::Data) -> Result<(), TestCaseError> {
+    let solution = registry::get(G::DAY).expect("jour absent du registre");
+    let input = G::render(data);
+    for part in [1, 2] {
+        let answer = registry::run_part(solution, part, &input);
+        prop_assert!(answer.is_ok(), "partie {} : {:?}", part, answer);
+        for variant in solution.variants(part) {
+            let variant_answer = (variant.run)(&input);
+            prop_assert_eq!(
+                variant_answer.as_ref().ok(),
+                answer.as_ref().ok(),
+                "partie {}, variante {}",
+                part,
+                variant.name
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Les données du jour 1, suivies d'une permutation de chacune des deux listes
+type Day1Permutations = (<Day1Input as ArbitraryInput>::Data, Vec<i64>, Vec<i64>);
+
+/// Stratégie générant les données du jour 1 avec leurs permutations
+fn day1_with_permutations() -> impl Strategy<Value = Day1Permutations> {
+    Day1Input::strategy().prop_flat_map(|(left, right)| {
+        let shuffled_left = Just(left.clone()).prop_shuffle();
+        let shuffled_right = Just(right.clone()).prop_shuffle();
+        (Just((left, right)), shuffled_left, shuffled_right)
+    })
+}
+
+/// Stratégie générant des listes du jour 1 aux valeurs extrêmes, qui font dépasser les sommes :
+/// tout l'intervalle de `i64`, mêlé de petites valeurs pour que les histogrammes servent aussi
+fn day1_extreme_lists() -> impl Strategy<Value = (Vec<i64>, Vec<i64>)> {
+    let value = || prop_oneof![any::<i64>(), -50i64..50];
+    (0..20usize).prop_flat_map(move |n| (vec(value(), n), vec(value(), n)))
+}
+
+/// Distance totale et score de similarité calculés en `i128`, où ils ne peuvent pas dépasser
+fn day1_reference(left: &[i64], right: &[i64]) -> (i128, i128) {
+    let (mut sorted_left, mut sorted_right) = (left.to_vec(), right.to_vec());
+    sorted_left.sort();
+    sorted_right.sort();
+    let distance = sorted_left
+        .iter()
+        .zip(&sorted_right)
+        .map(|(&l, &r)| (i128::from(l) - i128::from(r)).abs())
+        .sum();
+    let score = left
+        .iter()
+        .map(|&l| i128::from(l) * right.iter().filter(|&&r| r == l).count() as i128)
+        .sum();
+    (distance, score)
+}
+
+proptest! {
+    #[test]
+    fn day1_solves_generated_input(data in Day1Input::strategy()) {
+        solves_generated_input::<Day1Input>(&data)?;
+    }
+
+    #[test]
+    fn day1_input_roundtrips(data in Day1Input::strategy()) {
+        prop_assert_eq!(parse_data(&Day1Input::render(&data)).unwrap(), data);
+    }
+
+    #[test]
+    fn day1_distance_is_zero_iff_sorted_lists_are_equal((left, right) in Day1Input::strategy()) {
+        let (mut sorted_left, mut sorted_right) = (left.clone(), right.clone());
+        sorted_left.sort();
+        sorted_right.sort();
+        let distance = calculate_total_distance(left, right).unwrap();
+        prop_assert_eq!(distance == 0, sorted_left == sorted_right);
+    }
+
+    #[test]
+    fn day1_distance_is_zero_against_a_permutation(((left, _), shuffled, _) in day1_with_permutations()) {
+        prop_assert_eq!(calculate_total_distance(left, shuffled).unwrap(), 0);
+    }
+
+    #[test]
+    fn day1_similarity_is_invariant_under_permutation(
+        ((left, right), shuffled_left, shuffled_right) in day1_with_permutations()
+    ) {
+        let score = calculate_similarity_score(left.clone(), right.clone()).unwrap();
+        prop_assert_eq!(calculate_similarity_score(shuffled_left, right).unwrap(), score);
+        prop_assert_eq!(calculate_similarity_score(left, shuffled_right).unwrap(), score);
+    }
+
+    #[test]
+    fn day1_variants_report_overflow_instead_of_a_wrong_answer(
+        (left, right) in day1_extreme_lists()
+    ) {
+        let solution = registry::get(Day1Input::DAY).expect("jour absent du registre");
+        let input: String = left.iter().zip(&right).map(|(l, r)| format!("{} {}\n", l, r)).collect();
+        let (distance, score) = day1_reference(&left, &right);
+        for (part, expected) in [(1, distance), (2, score)] {
+            for variant in solution.variants(part) {
+                match ((variant.run)(&input), i64::try_from(expected)) {
+                    (Ok(answer), Ok(expected)) => prop_assert_eq!(answer.to_string(), expected.to_string()),
+                    (Err(AocError::Overflow(_)), Err(_)) => {}
+                    (result, _) => prop_assert!(
+                        false,
+                        "partie {}, variante {} : {:?} au lieu de {}",
+                        part,
+                        variant.name,
+                        result,
+                        expected
+                    ),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Vérifie que le registre généré par `build.rs` contient exactement les jours déclarés dans
+//! l'espace de travail
+
+// Importation des bibliothèques nécessaires
+use aoc2024::registry; // Le registre des solutions
+use serde_json::Value; // Pour lire la sortie de `cargo metadata`
+use std::process::Command; // Pour lancer `cargo metadata`
+
+#[path = "../build/members.rs"]
+mod members; // L'analyse des manifestes utilisée par `build.rs`
+
+/// Numéros des paquets `aoc2024-N` de l'espace de travail, d'après `cargo metadata`
+fn workspace_days() -> Vec<u8> {
+    let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = Command::new(cargo)
+        .args(["metadata", "--no-deps", "--format-version", "1"])
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("lancement de `cargo metadata` impossible");
+    assert!(output.status.success(), "`cargo metadata` a échoué");
+    let metadata: Value = serde_json::from_slice(&output.stdout).unwrap();
+    let mut days: Vec<u8> = metadata["packages"]
+        .as_array()
+        .expect("`packages` absent de `cargo metadata`")
+        .iter()
+        .filter_map(|package| {
+            package["name"]
+                .as_str()?
+                .strip_prefix("aoc2024-")?
+                .parse()
+                .ok()
+        })
+        .collect();
+    days.sort_unstable();
+    days
+}
+
+#[test]
+fn generated_days_match_workspace_members() {
+    let registered: Vec<u8> = registry::DAYS.iter().map(|&(day, _)| day).collect();
+    // Un jour par membre `aoc2024-N`, triés dans l'ordre des jours
+    assert_eq!(registered, workspace_days());
+}
+
+#[test]
+fn generated_days_are_reachable_through_the_default_year() {
+    // Le tableau généré est bien celui de l'année par défaut
+    assert_eq!(
+        registry::days(registry::DEFAULT_YEAR).len(),
+        registry::DAYS.len()
+    );
+    assert!(registry::get(1).is_some());
+}
+
+#[test]
+fn multi_line_members_are_read() {
+    let manifest = r#"
+[workspace]
+resolver = "2"
+members = [
+    "aoc2024",
+    "aoc2024-1",  # premier jour
+    "aoc2024-12",
+    "aoc2024-wasm",
+    "aoc_utils",
+]
+"#;
+    assert_eq!(members::day_members(manifest), [1, 12]);
+}
+
+#[test]
+fn single_line_members_are_read() {
+    let manifest = "[workspace]\nmembers = [\"aoc2024\", \"aoc2024-3\", \"aoc2024-25\"]\n";
+    assert_eq!(members::day_members(manifest), [3, 25]);
+}
+
+#[test]
+fn runner_dependencies_are_detected() {
+    let manifest = r#"
+[package]
+name = "aoc2024"
+
+[dependencies]
+aoc2024-1 = { path = "../aoc2024-1" }
+# aoc2024-2 = { path = "../aoc2024-2" }
+aoc_utils = { path = "../aoc_utils" }
+
+[dev-dependencies]
+aoc2024-3 = { path = "../aoc2024-3" }
+"#;
+    assert!(members::has_dependency(manifest, "aoc2024-1"));
+    assert!(!members::has_dependency(manifest, "aoc2024-2"));
+    assert!(!members::has_dependency(manifest, "aoc2024-3"));
+}
@@ -0,0 +1,48 @@
+//! Micro-benchmarks des tables de correspondance indexées face à une `HashMap<u8, V>`
+//! Lancer avec `cargo bench -p aoc_utils --bench lookup`
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::lookup::{CharTable, LookupTable}; // Les tables mesurées
+use criterion::{criterion_group, criterion_main, Criterion}; // Le cadre de mesure
+use std::collections::HashMap; // La référence à battre
+use std::hint::black_box; // Pour empêcher le compilateur d'éliminer le calcul
+
+/// Nombre de recherches par mesure
+const LOOKUPS: usize = 10_000;
+
+/// Fonction pour générer les caractères ASCII recherchés
+/// Graine fixe : les mesures portent toujours sur les mêmes données
+fn generate_keys() -> Vec<u8> {
+    let mut rng = fastrand::Rng::with_seed(124);
+    (0..LOOKUPS).map(|_| rng.u8(..128)).collect()
+}
+
+/// Mesure la somme des valeurs associées aux mêmes 10 000 caractères dans chaque table
+fn bench_lookup(c: &mut Criterion) {
+    let keys = generate_keys();
+    let value = |c: u8| u64::from(c) * 7;
+
+    let mut chars = CharTable::new(0);
+    let mut table = LookupTable::new(128, 0);
+    let mut map = HashMap::new();
+    for c in 0..128u8 {
+        chars.set(c, value(c));
+        table.set(c, value(c));
+        map.insert(c, value(c));
+    }
+
+    let mut group = c.benchmark_group("recherche");
+    group.bench_function("char_table", |b| {
+        b.iter(|| black_box(keys.iter().map(|&c| chars.get(c)).sum::<u64>()))
+    });
+    group.bench_function("lookup_table", |b| {
+        b.iter(|| black_box(keys.iter().map(|&c| table.get(c)).sum::<u64>()))
+    });
+    group.bench_function("hash_map", |b| {
+        b.iter(|| black_box(keys.iter().map(|c| map[c]).sum::<u64>()))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_lookup);
+criterion_main!(benches);
@@ -0,0 +1,96 @@
+//! Micro-benchmarks des recherches à coûts sur des labyrinthes du jour 16 de différentes tailles
+//! Lancer avec `cargo bench -p aoc_utils --bench search`
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::grid::Grid; // Le labyrinthe
+use aoc_utils::point::Point2D; // Les cases du labyrinthe
+use aoc_utils::search::weighted_bfs; // La recherche mesurée
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion}; // Le cadre de mesure
+use std::cmp::Reverse; // Pour le tas de Dijkstra
+use std::collections::{BinaryHeap, HashMap}; // Le tas et les distances de Dijkstra
+use std::hint::black_box; // Pour empêcher le compilateur d'éliminer le calcul
+
+/// Côtés des labyrinthes générés (141 est la taille d'une vraie entrée du jour 16)
+const SIZES: [usize; 3] = [41, 141, 401];
+
+/// État du renne : sa case et son orientation (un pas d'une case)
+type Reindeer = (Point2D<i64>, Point2D<i64>);
+
+/// Fonction pour générer un labyrinthe carré entouré de murs, avec un quart de murs intérieurs
+/// Graine fixe : les mesures portent toujours sur les mêmes données
+fn generate_maze(size: usize) -> Grid<bool> {
+    let mut rng = fastrand::Rng::with_seed(16);
+    let cells = (0..size * size)
+        .map(|i| {
+            let (row, col) = (i / size, i % size);
+            let border = row == 0 || col == 0 || row == size - 1 || col == size - 1;
+            border || rng.u8(..4) == 0
+        })
+        .collect();
+    let mut grid = Grid::from_vec(size, size, cells);
+    // Le départ (en bas à gauche) et l'arrivée (en haut à droite) sont toujours libres
+    grid.set(Point2D::new(size as i64 - 2, 1), false);
+    grid.set(Point2D::new(1, size as i64 - 2), false);
+    grid
+}
+
+/// Déplacements du renne : avancer coûte 1, tourner d'un quart de tour coûte 1000
+fn reindeer_moves(walls: &Grid<bool>, &(pos, dir): &Reindeer) -> Vec<(Reindeer, usize)> {
+    let mut moves = vec![
+        ((pos, Point2D::new(-dir.y, dir.x)), 1000),
+        ((pos, Point2D::new(dir.y, -dir.x)), 1000),
+    ];
+    let ahead = pos + dir;
+    if walls.get(ahead) == Some(&false) {
+        moves.push(((ahead, dir), 1));
+    }
+    moves
+}
+
+/// Dijkstra classique avec un tas binaire, la référence de `weighted_bfs`
+fn dijkstra(walls: &Grid<bool>, start: Reindeer) -> HashMap<Reindeer, usize> {
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut heap = BinaryHeap::from([Reverse((0, start))]);
+    while let Some(Reverse((distance, state))) = heap.pop() {
+        if distance > distances[&state] {
+            continue;
+        }
+        for (next, cost) in reindeer_moves(walls, &state) {
+            let candidate = distance + cost;
+            if distances.get(&next).is_none_or(|&d| candidate < d) {
+                distances.insert(next, candidate);
+                heap.push(Reverse((candidate, next)));
+            }
+        }
+    }
+    distances
+}
+
+/// Compare l'algorithme de Dial (seaux) au tas de Dijkstra, des mêmes départs sur les mêmes labyrinthes
+fn bench_reindeer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("jour16");
+    group.sample_size(20);
+    for size in SIZES {
+        let walls = generate_maze(size);
+        // Le renne part vers l'est
+        let start = (Point2D::new(size as i64 - 2, 1), Point2D::new(0, 1));
+        group.bench_with_input(
+            BenchmarkId::new("weighted_bfs", size),
+            &walls,
+            |b, walls| {
+                b.iter(|| {
+                    black_box(weighted_bfs(start, 1000, |state| {
+                        reindeer_moves(walls, state)
+                    }))
+                })
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("dijkstra", size), &walls, |b, walls| {
+            b.iter(|| black_box(dijkstra(walls, start)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_reindeer);
+criterion_main!(benches);
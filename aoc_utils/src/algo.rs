@@ -0,0 +1,190 @@
+// Importation des bibliothèques nécessaires
+use std::collections::HashMap; // Pour mémoriser les valeurs déjà calculées
+use std::hash::Hash; // Pour exiger des états hachables
+
+/// Fonction pour détecter le cycle d'une suite `start, next(start), next(next(start)), ...`
+/// Utilise l'algorithme de Brent, qui ne garde que deux états en mémoire (aucune table de hachage)
+/// Retourne `(mu, lambda)` : l'indice du premier état du cycle et la longueur du cycle
+/// La suite doit finir par boucler, sinon la fonction ne termine pas
+pub fn find_cycle<S: Eq + Clone, F: Fn(&S) -> S>(start: &S, next: F) -> (u64, u64) {
+    // Première phase : trouve la longueur du cycle `lambda` en doublant la fenêtre de recherche
+    let mut power = 1;
+    let mut lambda = 1;
+    let mut tortoise = start.clone();
+    let mut hare = next(start);
+    while tortoise != hare {
+        if power == lambda {
+            tortoise = hare.clone(); // Déplace la tortue à la position du lièvre
+            power *= 2;
+            lambda = 0;
+        }
+        hare = next(&hare);
+        lambda += 1;
+    }
+
+    // Deuxième phase : place le lièvre `lambda` pas devant la tortue, puis avance les deux ensemble
+    // jusqu'à ce qu'ils se rencontrent, au début du cycle
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    for _ in 0..lambda {
+        hare = next(&hare);
+    }
+    let mut mu = 0;
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        mu += 1;
+    }
+
+    (mu, lambda)
+}
+
+/// Fonction pour détecter le cycle d'une suite avec l'algorithme de Floyd (tortue et lièvre)
+/// Même résultat que `find_cycle`, mais avec environ trois fois plus d'appels à `next` :
+/// à réserver aux cas où l'on veut la version la plus simple à relire
+pub fn find_cycle_floyd<S: Eq + Clone, F: Fn(&S) -> S>(start: &S, next: F) -> (u64, u64) {
+    // Première phase : le lièvre avance deux fois plus vite jusqu'à rattraper la tortue dans le cycle
+    let mut tortoise = next(start);
+    let mut hare = next(&next(start));
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&next(&hare));
+    }
+
+    // Deuxième phase : la tortue repart du début ; ils se rencontrent au premier état du cycle
+    let mut mu = 0;
+    tortoise = start.clone();
+    while tortoise != hare {
+        tortoise = next(&tortoise);
+        hare = next(&hare);
+        mu += 1;
+    }
+
+    // Troisième phase : le lièvre fait un tour du cycle pour en mesurer la longueur
+    let mut lambda = 1;
+    hare = next(&tortoise);
+    while tortoise != hare {
+        hare = next(&hare);
+        lambda += 1;
+    }
+
+    (mu, lambda)
+}
+
+/// Fonction pour obtenir l'état après `target_step` transitions sans toutes les simuler
+/// Le cycle est détecté une fois, puis `target_step` est ramené à la position équivalente dans le cycle
+pub fn find_cycle_and_jump<S: Eq + Clone, F: Fn(&S) -> S>(
+    start: S,
+    next: F,
+    target_step: u64,
+) -> S {
+    let (mu, lambda) = find_cycle(&start, &next);
+
+    // Avant le cycle, la position est atteinte directement ; après, on se ramène à un tour de cycle
+    let steps = if target_step < mu {
+        target_step
+    } else {
+        mu + (target_step - mu) % lambda
+    };
+
+    // Simule uniquement les `steps` transitions nécessaires (au plus `mu + lambda`)
+    let mut state = start;
+    for _ in 0..steps {
+        state = next(&state);
+    }
+    state
+}
+
+/// Récursion mémoïsée : `f` reçoit une fonction `recur` pour les sous-problèmes et l'état à calculer
+/// Chaque état n'est calculé qu'une fois, et le cache est conservé d'un appel de `get` à l'autre
+/// (pratique pour sommer sur plusieurs états de départ qui partagent leurs sous-problèmes)
+pub struct Memo<S, V, F> {
+    cache: HashMap<S, V>, // Valeurs déjà calculées
+    f: F,                 // La fonction récursive
+}
+
+impl<S, V, F> Memo<S, V, F>
+where
+    S: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(S) -> V, S) -> V,
+{
+    /// Crée une récursion mémoïsée au cache vide
+    pub fn new(f: F) -> Self {
+        Memo {
+            cache: HashMap::new(),
+            f,
+        }
+    }
+
+    /// Retourne la valeur d'un état, en la calculant (avec ses sous-problèmes) si besoin
+    pub fn get(&mut self, state: S) -> V {
+        recur(&self.f, &mut self.cache, state)
+    }
+
+    /// Nombre d'états en cache
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Indique si aucun état n'a encore été calculé
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+/// Fonction pour calculer un état avec le cache, en passant à `f` de quoi rappeler la récursion
+fn recur<S, V, F>(f: &F, cache: &mut HashMap<S, V>, state: S) -> V
+where
+    S: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(S) -> V, S) -> V,
+{
+    if let Some(value) = cache.get(&state) {
+        return value.clone();
+    }
+    let value = f(&mut |next| recur(f, cache, next), state.clone());
+    cache.insert(state, value.clone());
+    value
+}
+
+/// Fonction pour calculer une récursion mémoïsée à partir d'un seul état, par exemple :
+/// `memoize(|recur, n: u64| if n < 2 { n } else { recur(n - 1) + recur(n - 2) }, 90)`
+pub fn memoize<S, V, F>(f: F, state: S) -> V
+where
+    S: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(S) -> V, S) -> V,
+{
+    Memo::new(f).get(state)
+}
+
+/// Fonction pour trouver la plus petite valeur initiale qui fait produire au programme sa propre suite
+/// d'octets (jour 17, partie 2)
+/// `step_fn` encapsule le comportement de l'ordinateur : elle exécute le programme avec la valeur
+/// initiale donnée (le registre A) et retourne tout ce qu'il affiche
+/// Cette recherche suppose que le programme consomme 3 bits de la valeur à chaque sortie,
+/// si bien que les bits de poids fort déterminent les dernières sorties : la réponse est construite
+/// 3 bits par 3 bits, en partant du dernier octet du programme
+pub fn quine_search<FN: Fn(u64) -> Vec<u8>>(program: &[u8], step_fn: FN) -> Option<u64> {
+    // Parcours en profondeur : (valeur partielle, nombre d'octets de la fin du programme déjà reproduits)
+    let mut stack = vec![(0u64, 0usize)];
+
+    while let Some((candidate, matched)) = stack.pop() {
+        if matched == program.len() {
+            return Some(candidate);
+        }
+        let suffix = &program[program.len() - matched - 1..];
+        // Empile les extensions en ordre décroissant pour explorer d'abord la plus petite
+        for extension in (0..8).rev() {
+            let Some(next) = candidate.checked_mul(8).map(|c| c | extension) else {
+                continue; // La valeur ne tient plus dans un `u64`
+            };
+            if step_fn(next) == suffix {
+                stack.push((next, matched + 1));
+            }
+        }
+    }
+
+    None // Aucune valeur ne reproduit le programme
+}
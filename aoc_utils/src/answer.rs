@@ -0,0 +1,98 @@
+// Importation des bibliothèques nécessaires
+use crate::grid::Grid; // Pour les réponses qui se lisent sur une grille de lettres
+use std::fmt; // Pour afficher les réponses
+
+/// Réponse d'une partie de puzzle
+/// Les nombres gardent leur type (pas de dépassement silencieux au-delà de `i32`), et les réponses
+/// textuelles sont soit une chaîne, soit une grille de caractères à lire à l'écran
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    I64(i64),
+    U64(u64),
+    String(String),
+    Grid(Grid<char>),
+}
+
+impl fmt::Display for Answer {
+    /// Les nombres et les chaînes s'affichent tels quels, une grille ligne par ligne
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::I64(n) => write!(f, "{}", n),
+            Answer::U64(n) => write!(f, "{}", n),
+            Answer::String(s) => write!(f, "{}", s),
+            Answer::Grid(grid) => {
+                let rows: Vec<String> = (0..grid.height())
+                    .map(|r| grid.row_slice(r).iter().collect())
+                    .collect();
+                write!(f, "{}", rows.join("\n"))
+            }
+        }
+    }
+}
+
+impl PartialEq<str> for Answer {
+    /// Compare la réponse affichée à un texte attendu (comme les réponses des exemples)
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Answer::String(s) => s == other,
+            _ => {
+                let shown = self.to_string();
+                shown == other
+            }
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Answer::I64(n.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::I64(n)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::U64(n.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::U64(n)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::U64(n as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::String(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::String(s.to_string())
+    }
+}
+
+impl From<Grid<char>> for Answer {
+    fn from(grid: Grid<char>) -> Self {
+        Answer::Grid(grid)
+    }
+}
@@ -0,0 +1,44 @@
+/// Fonction pour assembler un nombre à partir de ses bits, `bits[0]` étant le bit de poids faible
+/// Comme pour les fils `z00`..`z44` du jour 24
+pub fn bits_to_u64(bits: &[bool]) -> u64 {
+    assert!(bits.len() <= 64, "un u64 ne contient que 64 bits");
+    bits.iter()
+        .enumerate()
+        .fold(0, |acc, (i, &bit)| acc | (u64::from(bit) << i))
+}
+
+/// Fonction pour découper un nombre en `width` bits, du poids faible au poids fort
+pub fn u64_to_bits(n: u64, width: usize) -> Vec<bool> {
+    (0..width).map(|i| i < 64 && (n >> i) & 1 == 1).collect()
+}
+
+/// Fonction pour extraire le champ de `len` bits commençant au bit `start`
+/// Par exemple `extract_bits(0b11010, 1, 3) == 0b101`
+pub fn extract_bits(n: u64, start: usize, len: usize) -> u64 {
+    if start >= 64 {
+        return 0; // Au-delà du 64e bit, il n'y a que des zéros
+    }
+    let shifted = n >> start;
+    // Un masque de 64 bits ou plus garde tout le nombre
+    if len >= 64 {
+        shifted
+    } else {
+        shifted & ((1 << len) - 1)
+    }
+}
+
+/// Fonction pour obtenir une copie du nombre avec le bit `pos` mis à `val` (le nombre d'origine n'est pas modifié)
+pub fn set_bit(n: u64, pos: usize, val: bool) -> u64 {
+    assert!(pos < 64, "un u64 ne contient que 64 bits");
+    if val {
+        n | (1 << pos)
+    } else {
+        n & !(1 << pos)
+    }
+}
+
+/// Fonction pour afficher un nombre en binaire sur `width` chiffres, complété par des zéros à gauche
+/// Par exemple `format_binary(5, 8) == "00000101"`
+pub fn format_binary(n: u64, width: usize) -> String {
+    format!("{:0width$b}", n, width = width)
+}
@@ -0,0 +1,218 @@
+// Importation des bibliothèques nécessaires
+use std::collections::HashMap; // Pour retrouver en O(1) l'emplacement d'une clé
+use std::hash::Hash; // Pour exiger des clés hachables
+
+/// Indice signifiant « aucun élément » dans la liste doublement chaînée
+const NONE: usize = usize::MAX;
+
+/// Maillon de la liste doublement chaînée qui mémorise l'ordre d'utilisation
+struct Node<K, V> {
+    key: K,      // La clé, conservée pour pouvoir l'effacer de l'index lors d'une éviction
+    value: V,    // La valeur mise en cache
+    prev: usize, // Maillon utilisé plus récemment (vers la tête)
+    next: usize, // Maillon utilisé moins récemment (vers la queue)
+}
+
+/// Cache de taille bornée qui évince l'entrée la moins récemment utilisée (LRU)
+/// Les maillons sont stockés dans un vecteur et chaînés par indices, sans allocation par entrée
+pub struct LruCache<K, V> {
+    capacity: usize,          // Nombre maximal d'entrées
+    index: HashMap<K, usize>, // Position de chaque clé dans `nodes`
+    nodes: Vec<Node<K, V>>,   // Les maillons de la liste
+    head: usize,              // Entrée la plus récemment utilisée
+    tail: usize,              // Entrée la moins récemment utilisée
+}
+
+impl<K: Hash + Eq + Clone, V> LruCache<K, V> {
+    /// Crée un cache vide pouvant contenir `capacity` entrées
+    /// Panique si la capacité est nulle
+    pub fn new(capacity: usize) -> Self {
+        assert!(
+            capacity > 0,
+            "la capacité d'un cache LRU doit être positive"
+        );
+        LruCache {
+            capacity,
+            index: HashMap::with_capacity(capacity),
+            nodes: Vec::with_capacity(capacity),
+            head: NONE,
+            tail: NONE,
+        }
+    }
+
+    /// Nombre d'entrées actuellement en cache
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Indique si le cache est vide
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Nombre maximal d'entrées du cache
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Retourne la valeur associée à la clé et la marque comme la plus récemment utilisée
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let i = *self.index.get(key)?;
+        self.move_to_front(i);
+        Some(&self.nodes[i].value)
+    }
+
+    /// Insère (ou remplace) une valeur, en évinçant l'entrée la moins récemment utilisée si le cache est plein
+    pub fn insert(&mut self, key: K, value: V) {
+        self.insert_slot(key, value);
+    }
+
+    /// Retourne la valeur en cache, ou la calcule avec `compute` et l'insère si elle est absente
+    /// `compute` n'est jamais appelé quand la clé est déjà présente
+    pub fn get_or_insert(&mut self, key: K, compute: impl FnOnce() -> V) -> &V {
+        let i = match self.index.get(&key) {
+            Some(&i) => {
+                self.move_to_front(i);
+                i
+            }
+            None => self.insert_slot(key, compute()),
+        };
+        &self.nodes[i].value
+    }
+
+    /// Insère la valeur et retourne l'indice du maillon qui la contient
+    fn insert_slot(&mut self, key: K, value: V) -> usize {
+        // Clé déjà présente : on remplace la valeur et on la remet en tête
+        if let Some(&i) = self.index.get(&key) {
+            self.nodes[i].value = value;
+            self.move_to_front(i);
+            return i;
+        }
+
+        let i = if self.nodes.len() < self.capacity {
+            // Il reste de la place : on ajoute un nouveau maillon
+            self.nodes.push(Node {
+                key: key.clone(),
+                value,
+                prev: NONE,
+                next: NONE,
+            });
+            self.nodes.len() - 1
+        } else {
+            // Cache plein : on réutilise le maillon de la queue (le moins récemment utilisé)
+            let i = self.tail;
+            self.unlink(i);
+            self.index.remove(&self.nodes[i].key);
+            self.nodes[i].key = key.clone();
+            self.nodes[i].value = value;
+            i
+        };
+
+        self.index.insert(key, i);
+        self.push_front(i);
+        i
+    }
+
+    /// Déplace un maillon en tête de liste
+    fn move_to_front(&mut self, i: usize) {
+        if self.head != i {
+            self.unlink(i);
+            self.push_front(i);
+        }
+    }
+
+    /// Détache un maillon de la liste en reliant ses voisins entre eux
+    fn unlink(&mut self, i: usize) {
+        let (prev, next) = (self.nodes[i].prev, self.nodes[i].next);
+        if prev == NONE {
+            self.head = next;
+        } else {
+            self.nodes[prev].next = next;
+        }
+        if next == NONE {
+            self.tail = prev;
+        } else {
+            self.nodes[next].prev = prev;
+        }
+    }
+
+    /// Insère un maillon détaché en tête de liste
+    fn push_front(&mut self, i: usize) {
+        self.nodes[i].prev = NONE;
+        self.nodes[i].next = self.head;
+        if self.head != NONE {
+            self.nodes[self.head].prev = i;
+        }
+        self.head = i;
+        if self.tail == NONE {
+            self.tail = i; // Première entrée : elle est à la fois en tête et en queue
+        }
+    }
+}
+
+/// Mémoïsation pour la programmation dynamique récursive, avec des statistiques sur le cache
+/// La fonction de calcul reçoit le cache lui-même (`&mut Self`), ce qui lui permet de
+/// s'appeler récursivement via `compute` sans `RefCell` :
+/// `fn fib(n: u64, dp: &mut MemoizedDp<u64, u64>) -> u64 { dp.compute(n, |&n, dp| ...) }`
+#[derive(Debug, Clone)]
+pub struct MemoizedDp<K, V> {
+    cache: HashMap<K, V>, // Valeurs déjà calculées
+    hits: u64,            // Nombre d'appels servis par le cache
+    misses: u64,          // Nombre d'appels qui ont dû calculer la valeur
+}
+
+impl<K: Hash + Eq, V: Clone> Default for MemoizedDp<K, V> {
+    fn default() -> Self {
+        MemoizedDp {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V: Clone> MemoizedDp<K, V> {
+    /// Crée un cache vide
+    pub fn new() -> Self {
+        MemoizedDp::default()
+    }
+
+    /// Retourne la valeur associée à `key`, en la calculant avec `compute_fn` si elle n'est pas en cache
+    /// `compute_fn` peut rappeler `compute` sur le cache qu'elle reçoit pour les sous-problèmes
+    pub fn compute(&mut self, key: K, compute_fn: impl FnOnce(&K, &mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        let value = compute_fn(&key, self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    /// Remplit le cache à l'avance pour chacune des clés (celles déjà présentes ne sont pas recalculées)
+    /// Pratique pour calculer les sous-problèmes dans l'ordre et éviter une récursion trop profonde
+    pub fn precompute(&mut self, keys: impl Iterator<Item = K>, f: impl Fn(K, &mut Self) -> V)
+    where
+        K: Clone,
+    {
+        for key in keys {
+            self.compute(key, |key, dp| f(key.clone(), dp));
+        }
+    }
+
+    /// Nombre de valeurs en cache
+    pub fn cache_size(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Proportion des appels à `compute` servis par le cache (0 s'il n'y a eu aucun appel)
+    pub fn hit_rate(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            0.0
+        } else {
+            self.hits as f64 / total as f64
+        }
+    }
+}
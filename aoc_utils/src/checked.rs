@@ -0,0 +1,93 @@
+// Importation des bibliothèques nécessaires
+use crate::error::AocError; // Pour signaler un dépassement de capacité
+use std::iter::Sum; // Pour sommer des valeurs vérifiées
+use std::ops::{Add, Mul, Sub}; // Les opérations vérifiées
+
+/// Entier `i64` dont chaque opération vérifie le dépassement de capacité, même en mode release
+/// (où l'arithmétique ordinaire reboucle sans prévenir) : un dépassement rend la valeur invalide,
+/// et le reste du calcul la propage jusqu'à `get`, qui le signale au lieu de donner une réponse fausse
+/// `Checked::new(l) - r` puis `.abs()`, sommés avec `sum::<Checked>()` : une seule vérification à la fin
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checked(Option<i64>); // `None` après un dépassement
+
+impl Checked {
+    /// Crée une valeur vérifiée
+    pub fn new(value: i64) -> Self {
+        Checked(Some(value))
+    }
+
+    /// Valeur absolue (`i64::MIN` n'en a pas)
+    pub fn abs(self) -> Self {
+        Checked(self.0.and_then(i64::checked_abs))
+    }
+
+    /// Valeur du calcul, ou `None` s'il a dépassé
+    pub fn value(self) -> Option<i64> {
+        self.0
+    }
+
+    /// Valeur du calcul, ou une erreur `AocError::Overflow` qui nomme ce qui a été calculé
+    pub fn get(self, what: &str) -> Result<i64, AocError> {
+        self.0.ok_or_else(|| AocError::Overflow(what.to_string()))
+    }
+}
+
+impl Default for Checked {
+    /// Zéro, pour commencer une somme
+    fn default() -> Self {
+        Checked::new(0)
+    }
+}
+
+impl From<i64> for Checked {
+    fn from(value: i64) -> Self {
+        Checked::new(value)
+    }
+}
+
+impl From<u64> for Checked {
+    /// Un `u64` au-delà de `i64::MAX` est déjà un dépassement
+    fn from(value: u64) -> Self {
+        Checked(i64::try_from(value).ok())
+    }
+}
+
+/// Implémente une opération vérifiée entre deux `Checked`, et entre un `Checked` et un `i64`
+macro_rules! checked_op {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        impl $trait for Checked {
+            type Output = Checked;
+
+            fn $method(self, other: Checked) -> Checked {
+                match (self.0, other.0) {
+                    (Some(a), Some(b)) => Checked(a.$checked(b)),
+                    _ => Checked(None),
+                }
+            }
+        }
+
+        impl $trait<i64> for Checked {
+            type Output = Checked;
+
+            fn $method(self, other: i64) -> Checked {
+                self.$method(Checked::new(other))
+            }
+        }
+    };
+}
+
+checked_op!(Add, add, checked_add);
+checked_op!(Sub, sub, checked_sub);
+checked_op!(Mul, mul, checked_mul);
+
+impl Sum for Checked {
+    fn sum<I: Iterator<Item = Checked>>(iter: I) -> Checked {
+        iter.fold(Checked::default(), Add::add)
+    }
+}
+
+impl Sum<i64> for Checked {
+    fn sum<I: Iterator<Item = i64>>(iter: I) -> Checked {
+        iter.map(Checked::new).sum()
+    }
+}
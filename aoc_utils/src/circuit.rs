@@ -0,0 +1,176 @@
+// Importation des bibliothèques nécessaires
+use crate::graph::topological_evaluation_order; // Pour évaluer les portes dans l'ordre des dépendances
+use std::collections::HashMap; // Pour les valeurs des fils et les portes qui les produisent
+
+/// Opération logique réalisée par une porte
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GateOp {
+    And,
+    Or,
+    Xor,
+}
+
+impl GateOp {
+    /// Lit le nom d'une opération (`AND`, `OR` ou `XOR`)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "AND" => Some(GateOp::And),
+            "OR" => Some(GateOp::Or),
+            "XOR" => Some(GateOp::Xor),
+            _ => None,
+        }
+    }
+
+    /// Applique l'opération à deux bits
+    pub fn apply(self, a: u8, b: u8) -> u8 {
+        match self {
+            GateOp::And => a & b,
+            GateOp::Or => a | b,
+            GateOp::Xor => a ^ b,
+        }
+    }
+}
+
+/// Porte logique à deux entrées, comme `x00 AND y00 -> z00`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gate<'a> {
+    pub op: GateOp,                 // Opération réalisée
+    pub inputs: (&'a str, &'a str), // Fils d'entrée
+    pub output: &'a str,            // Fil de sortie
+}
+
+/// Circuit de portes logiques (jour 24)
+#[derive(Debug, Clone)]
+pub struct GateCircuit<'a> {
+    pub gates: Vec<Gate<'a>>,
+}
+
+impl<'a> GateCircuit<'a> {
+    /// Crée un circuit à partir de ses portes
+    pub fn new(gates: Vec<Gate<'a>>) -> Self {
+        GateCircuit { gates }
+    }
+
+    /// Lit l'entrée du jour 24 : les valeurs initiales (`x00: 1`), une ligne vide, puis les portes
+    /// Les lignes qui ne respectent aucun des deux formats sont ignorées
+    pub fn parse(input: &'a str) -> (HashMap<&'a str, u8>, Vec<Gate<'a>>) {
+        let mut wires = HashMap::new();
+        let mut gates = Vec::new();
+
+        for line in input.lines().map(str::trim) {
+            if let Some((wire, value)) = line.split_once(": ") {
+                // Valeur initiale d'un fil
+                if let Ok(value) = value.trim().parse() {
+                    wires.insert(wire, value);
+                }
+            } else if let [a, op, b, "->", output] = line.split_whitespace().collect::<Vec<_>>()[..]
+            {
+                // Porte : `a OP b -> sortie`
+                if let Some(op) = GateOp::from_name(op) {
+                    gates.push(Gate {
+                        op,
+                        inputs: (a, b),
+                        output,
+                    });
+                }
+            }
+        }
+
+        (wires, gates)
+    }
+
+    /// Évalue toutes les portes, chacune après les portes qui produisent ses entrées,
+    /// et ajoute la valeur de chaque fil de sortie dans `wires`
+    /// Panique si le circuit contient un cycle ou si une entrée n'est ni initialisée ni produite par une porte
+    pub fn simulate(&self, wires: &mut HashMap<&'a str, u8>) {
+        // Porte qui produit chaque fil
+        let producers: HashMap<&str, &Gate> =
+            self.gates.iter().map(|gate| (gate.output, gate)).collect();
+        let order = topological_evaluation_order(&self.gates, |gate| {
+            [gate.inputs.0, gate.inputs.1]
+                .iter()
+                .filter_map(|input| producers.get(input).copied())
+                .collect()
+        })
+        .unwrap_or_else(|gate| panic!("cycle dans le circuit autour du fil {}", gate.output));
+
+        for gate in order {
+            let read = |wire: &str| {
+                *wires
+                    .get(wire)
+                    .unwrap_or_else(|| panic!("le fil {} n'a pas de valeur", wire))
+            };
+            let value = gate.op.apply(read(gate.inputs.0), read(gate.inputs.1));
+            wires.insert(gate.output, value);
+        }
+    }
+
+    /// Fonction pour assembler la valeur d'un bus : les fils `prefix00`, `prefix01`, ... sont les bits
+    /// de poids croissant (par exemple `z` pour la sortie du jour 24)
+    pub fn read_bus(wires: &HashMap<&str, u8>, prefix: &str) -> u64 {
+        wires
+            .iter()
+            .filter_map(|(wire, &value)| {
+                // Garde seulement les fils `prefix` suivis d'un numéro
+                let bit: u32 = wire.strip_prefix(prefix)?.parse().ok()?;
+                Some(u64::from(value & 1) << bit)
+            })
+            .fold(0, |bus, bit| bus | bit)
+    }
+}
+
+/// Fonction pour repérer les fils mal branchés d'un additionneur à propagation de retenue (jour 24, partie 2)
+/// Dans un additionneur correct, chaque étage `i` calcule `z_i = x_i XOR y_i XOR retenue` et
+/// `retenue suivante = (x_i AND y_i) OR (retenue AND (x_i XOR y_i))`, ce qui impose :
+/// - chaque sortie `z` vient d'une porte `XOR`, sauf la dernière qui est la retenue finale (`OR`) ;
+/// - un `XOR` qui ne lit ni `x` ni `y` produit forcément une sortie `z` ;
+/// - un `XOR` de `x_i` et `y_i` (sauf pour le bit 0, qui produit directement `z00`) ne produit
+///   jamais une sortie `z` : il alimente le `XOR` qui produit `z_i` ;
+/// - un `AND` (sauf celui de `x00` et `y00`) alimente le `OR` qui calcule la retenue suivante
+///
+/// Retourne la liste triée des fils de sortie qui ne respectent pas cette structure
+pub fn validate_adder_circuit(gates: &[Gate]) -> Vec<String> {
+    // Dernière sortie du circuit : la retenue finale
+    let last_z = gates
+        .iter()
+        .map(|gate| gate.output)
+        .filter(|wire| wire.starts_with('z'))
+        .max();
+    let is_input = |wire: &str| wire.starts_with('x') || wire.starts_with('y');
+    // Porte du bit 0, qui n'a pas de retenue en entrée
+    let is_first_bit = |gate: &Gate| {
+        [gate.inputs.0, gate.inputs.1]
+            .iter()
+            .all(|wire| is_input(wire) && wire.ends_with("00"))
+    };
+    // Indique si le fil sert d'entrée à au moins une porte réalisant l'opération `op`
+    let feeds = |wire: &str, op: GateOp| {
+        gates
+            .iter()
+            .any(|gate| gate.op == op && (gate.inputs.0 == wire || gate.inputs.1 == wire))
+    };
+
+    let mut wrong: Vec<String> = gates
+        .iter()
+        .filter(|gate| {
+            let reads_inputs = is_input(gate.inputs.0) && is_input(gate.inputs.1);
+            match gate.op {
+                // Vérifié avant la règle des sorties `z` : un `x_i XOR y_i` branché sur `z_i` est un `XOR`
+                GateOp::Xor if reads_inputs && is_first_bit(gate) => gate.output != "z00",
+                GateOp::Xor if reads_inputs => {
+                    gate.output.starts_with('z') || !feeds(gate.output, GateOp::Xor)
+                }
+                _ if gate.output.starts_with('z') && Some(gate.output) != last_z => {
+                    gate.op != GateOp::Xor
+                }
+                GateOp::Xor => !gate.output.starts_with('z'),
+                GateOp::And => !is_first_bit(gate) && !feeds(gate.output, GateOp::Or),
+                GateOp::Or => false,
+            }
+        })
+        .map(|gate| gate.output.to_string())
+        .collect();
+    wrong.sort();
+    wrong.dedup();
+    wrong
+}
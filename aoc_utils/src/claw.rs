@@ -0,0 +1,49 @@
+// Importation des bibliothèques nécessaires
+use crate::math::solve_linear_system_2x2; // Règle de Cramer en entiers naturels
+use crate::parse::extract_numbers; // Pour lire les nombres de chaque machine
+
+/// Coût en jetons d'un appui sur le bouton A
+const COST_A: i64 = 3;
+/// Coût en jetons d'un appui sur le bouton B
+const COST_B: i64 = 1;
+
+/// Machine à pince du jour 13 : chaque bouton déplace la pince de `(ax, ay)` ou de `(bx, by)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ClawMachine {
+    pub ax: i64,
+    pub ay: i64,
+    pub bx: i64,
+    pub by: i64,
+}
+
+impl ClawMachine {
+    /// Nombre minimal de jetons (3 par appui sur A, 1 par appui sur B) pour atteindre le prix `(px, py)`,
+    /// ou `None` si le prix est inaccessible
+    /// Les boutons sont supposés non colinéaires (c'est le cas dans les entrées du jour 13) :
+    /// la solution est alors unique, et la machine est considérée comme insoluble sinon
+    pub fn solve(&self, px: i64, py: i64) -> Option<i64> {
+        // a * ax + b * bx = px et a * ay + b * by = py
+        let (a, b) = solve_linear_system_2x2(self.ax, self.bx, self.ay, self.by, px, py)?;
+        // Le calcul du coût passe aussi par `i128` pour ne pas dépasser
+        i64::try_from(COST_A as i128 * a as i128 + COST_B as i128 * b as i128).ok()
+    }
+
+    /// Comme `solve`, avec le prix décalé de `offset` sur les deux axes (jour 13, partie 2)
+    pub fn solve_with_offset(&self, px: i64, py: i64, offset: i64) -> Option<i64> {
+        self.solve(px.checked_add(offset)?, py.checked_add(offset)?)
+    }
+
+    /// Fonction pour lire toutes les machines de l'entrée, séparées par des lignes vides
+    /// Chaque machine est retournée avec la position `(px, py)` de son prix
+    /// Les blocs qui ne contiennent pas exactement six nombres sont ignorés
+    pub fn parse_all(input: &str) -> Vec<(ClawMachine, (i64, i64))> {
+        input
+            .replace("\r\n", "\n")
+            .split("\n\n")
+            .filter_map(|block| match extract_numbers::<i64>(block)[..] {
+                [ax, ay, bx, by, px, py] => Some((ClawMachine { ax, ay, bx, by }, (px, py))),
+                _ => None,
+            })
+            .collect()
+    }
+}
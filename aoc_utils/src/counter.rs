@@ -0,0 +1,94 @@
+// Importation des bibliothèques nécessaires
+use std::collections::HashMap; // Pour associer chaque élément à son nombre d'occurrences
+use std::hash::Hash; // Pour exiger des éléments hachables
+
+/// Compteur d'occurrences : le nombre de fois que chaque élément a été vu
+/// Un élément jamais vu compte 0, sans entrée dans le dictionnaire
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<T: Hash + Eq> {
+    counts: HashMap<T, usize>, // Nombre d'occurrences de chaque élément vu
+}
+
+impl<T: Hash + Eq> Default for Counter<T> {
+    fn default() -> Self {
+        Counter {
+            counts: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Hash + Eq> Counter<T> {
+    /// Crée un compteur vide
+    pub fn new() -> Self {
+        Counter::default()
+    }
+
+    /// Compte une occurrence de plus de l'élément
+    pub fn add(&mut self, item: T) {
+        self.add_n(item, 1);
+    }
+
+    /// Compte `n` occurrences de plus de l'élément
+    pub fn add_n(&mut self, item: T, n: usize) {
+        *self.counts.entry(item).or_insert(0) += n;
+    }
+
+    /// Nombre d'occurrences de l'élément (0 s'il n'a jamais été vu)
+    pub fn get(&self, item: &T) -> usize {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    /// Nombre d'éléments distincts vus
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Indique si aucun élément n'a été vu
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Nombre total d'occurrences comptées
+    pub fn total(&self) -> usize {
+        self.counts.values().sum()
+    }
+
+    /// Les éléments vus et leur nombre d'occurrences, dans un ordre quelconque
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> {
+        self.counts.iter().map(|(item, &count)| (item, count))
+    }
+
+    /// Les `n` éléments les plus fréquents, du plus fréquent au moins fréquent
+    /// L'ordre des éléments à égalité n'est pas défini
+    pub fn most_common(&self, n: usize) -> Vec<(&T, usize)> {
+        let mut items: Vec<(&T, usize)> = self.iter().collect();
+        items.sort_unstable_by_key(|&(_, count)| std::cmp::Reverse(count));
+        items.truncate(n);
+        items
+    }
+}
+
+impl<T: Hash + Eq> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: Hash + Eq> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Counter::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T: Hash + Eq> IntoIterator for Counter<T> {
+    type Item = (T, usize);
+    type IntoIter = std::collections::hash_map::IntoIter<T, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
@@ -0,0 +1,172 @@
+// Importation des bibliothèques nécessaires
+use std::collections::VecDeque; // La file à double extrémité de la bibliothèque standard
+use std::ops::{Deref, DerefMut}; // Pour exposer toutes les méthodes de `VecDeque`
+
+/// File à double extrémité : ajout et retrait en O(1) aux deux bouts
+/// Enveloppe fine autour de `VecDeque` (toutes ses méthodes restent accessibles), avec des rotations
+/// qui acceptent n'importe quel décalage au lieu de paniquer au-delà de la longueur
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Deque<T>(VecDeque<T>);
+
+impl<T> Deque<T> {
+    /// Crée une file vide
+    pub fn new() -> Self {
+        Deque(VecDeque::new())
+    }
+
+    /// Déplace les `n` premiers éléments à la fin (le décalage est pris modulo la longueur)
+    pub fn rotate_left(&mut self, n: usize) {
+        if !self.0.is_empty() {
+            let n = n % self.0.len();
+            self.0.rotate_left(n);
+        }
+    }
+
+    /// Déplace les `n` derniers éléments au début (le décalage est pris modulo la longueur)
+    pub fn rotate_right(&mut self, n: usize) {
+        if !self.0.is_empty() {
+            let n = n % self.0.len();
+            self.0.rotate_right(n);
+        }
+    }
+
+    /// Copie les éléments dans un vecteur, du début à la fin
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.0.iter().cloned().collect()
+    }
+
+    /// Consomme la file et retourne la `VecDeque` sous-jacente
+    pub fn into_inner(self) -> VecDeque<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for Deque<T> {
+    type Target = VecDeque<T>;
+
+    fn deref(&self) -> &VecDeque<T> {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Deque<T> {
+    fn deref_mut(&mut self) -> &mut VecDeque<T> {
+        &mut self.0
+    }
+}
+
+impl<T> From<Vec<T>> for Deque<T> {
+    fn from(values: Vec<T>) -> Self {
+        Deque(VecDeque::from(values))
+    }
+}
+
+impl<T> FromIterator<T> for Deque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Deque(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for Deque<T> {
+    type Item = T;
+    type IntoIter = std::collections::vec_deque::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// File à double extrémité de capacité fixe
+/// Quand elle est pleine, un ajout à un bout évince l'élément situé à l'autre bout
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CircularDeque<T> {
+    items: VecDeque<T>, // Les éléments, jamais plus de `capacity`
+    capacity: usize,    // Nombre maximal d'éléments
+}
+
+impl<T> CircularDeque<T> {
+    /// Crée une file vide pouvant contenir `capacity` éléments
+    /// Panique si la capacité est nulle
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "la capacité doit être positive");
+        CircularDeque {
+            items: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Ajoute un élément à la fin, et retourne l'élément évincé au début si la file était pleine
+    pub fn push_back(&mut self, value: T) -> Option<T> {
+        let evicted = if self.is_full() {
+            self.items.pop_front()
+        } else {
+            None
+        };
+        self.items.push_back(value);
+        evicted
+    }
+
+    /// Ajoute un élément au début, et retourne l'élément évincé à la fin si la file était pleine
+    pub fn push_front(&mut self, value: T) -> Option<T> {
+        let evicted = if self.is_full() {
+            self.items.pop_back()
+        } else {
+            None
+        };
+        self.items.push_front(value);
+        evicted
+    }
+
+    /// Retire et retourne le premier élément
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.items.pop_front()
+    }
+
+    /// Retire et retourne le dernier élément
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.items.pop_back()
+    }
+
+    /// Retourne le premier élément sans le retirer
+    pub fn front(&self) -> Option<&T> {
+        self.items.front()
+    }
+
+    /// Retourne le dernier élément sans le retirer
+    pub fn back(&self) -> Option<&T> {
+        self.items.back()
+    }
+
+    /// Retourne l'élément à la position `index` en partant du début
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+
+    /// Nombre d'éléments dans la file
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Indique si la file est vide
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Indique si la file a atteint sa capacité
+    pub fn is_full(&self) -> bool {
+        self.items.len() == self.capacity
+    }
+
+    /// Nombre maximal d'éléments de la file
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Parcourt les éléments du début à la fin
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+}
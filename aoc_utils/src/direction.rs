@@ -0,0 +1,116 @@
+// Importation des bibliothèques nécessaires
+use crate::point::{Point2D, SCREEN_EAST, SCREEN_NORTH, SCREEN_SOUTH, SCREEN_WEST}; // Pour le déplacement associé à chaque direction
+
+/// Les quatre directions cardinales d'une grille
+/// Le nord correspond à la ligne précédente (vers le haut de l'écran)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Les quatre directions, dans le sens des aiguilles d'une montre en partant du nord
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// Déplacement `(ligne, colonne)` correspondant à un pas dans cette direction
+    pub fn delta(self) -> Point2D<i64> {
+        match self {
+            Direction::North => SCREEN_NORTH,
+            Direction::East => SCREEN_EAST,
+            Direction::South => SCREEN_SOUTH,
+            Direction::West => SCREEN_WEST,
+        }
+    }
+
+    /// Direction obtenue en tournant d'un quart de tour vers la droite
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    /// Direction obtenue en tournant d'un quart de tour vers la gauche
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::North => Direction::West,
+            Direction::East => Direction::North,
+            Direction::South => Direction::East,
+            Direction::West => Direction::South,
+        }
+    }
+
+    /// Direction opposée
+    pub fn opposite(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    /// Indique si la direction est verticale (nord ou sud)
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Direction::North | Direction::South)
+    }
+
+    /// Convertit une flèche `^`, `>`, `v` ou `<` en direction
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::North),
+            '>' => Some(Direction::East),
+            'v' => Some(Direction::South),
+            '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+}
+
+/// Les huit directions d'une grille : les quatre directions cardinales et les quatre diagonales
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// Les huit directions, dans le sens des aiguilles d'une montre en partant du nord
+    pub fn all() -> [Direction8; 8] {
+        [
+            Direction8::North,
+            Direction8::NorthEast,
+            Direction8::East,
+            Direction8::SouthEast,
+            Direction8::South,
+            Direction8::SouthWest,
+            Direction8::West,
+            Direction8::NorthWest,
+        ]
+    }
+
+    /// Déplacement `(ligne, colonne)` correspondant à un pas dans cette direction
+    pub fn delta(self) -> Point2D<i64> {
+        match self {
+            Direction8::North => SCREEN_NORTH,
+            Direction8::NorthEast => SCREEN_NORTH + SCREEN_EAST,
+            Direction8::East => SCREEN_EAST,
+            Direction8::SouthEast => SCREEN_SOUTH + SCREEN_EAST,
+            Direction8::South => SCREEN_SOUTH,
+            Direction8::SouthWest => SCREEN_SOUTH + SCREEN_WEST,
+            Direction8::West => SCREEN_WEST,
+            Direction8::NorthWest => SCREEN_NORTH + SCREEN_WEST,
+        }
+    }
+}
@@ -0,0 +1,101 @@
+/// Fonction pour développer la carte dense d'un disque (jour 9) : les chiffres donnent alternativement
+/// la longueur d'un fichier et celle d'un espace libre
+/// Chaque bloc contient le numéro de son fichier, ou `None` s'il est libre
+/// Les caractères qui ne sont pas des chiffres (comme le retour à la ligne final) sont ignorés
+pub fn disk_map_expand(dense: &str) -> Vec<Option<u64>> {
+    let lengths: Vec<usize> = dense
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .map(|d| d as usize)
+        .collect();
+    let mut disk = Vec::with_capacity(lengths.iter().sum());
+    for (i, &len) in lengths.iter().enumerate() {
+        // Indices pairs : fichiers numérotés dans l'ordre ; indices impairs : espaces libres
+        let block = (i % 2 == 0).then_some((i / 2) as u64);
+        disk.extend(std::iter::repeat_n(block, len));
+    }
+    disk
+}
+
+/// Fonction pour compacter le disque bloc par bloc (jour 9, partie 1) : le dernier bloc occupé
+/// est déplacé dans le premier bloc libre, jusqu'à ce qu'il n'y ait plus de trou
+/// Deux indices se rapprochent l'un de l'autre, sans copie du disque
+pub fn compact_blocks(disk: &mut [Option<u64>]) {
+    let (mut free, mut last) = (0, disk.len());
+    loop {
+        // Premier bloc libre à partir de la gauche
+        while free < disk.len() && disk[free].is_some() {
+            free += 1;
+        }
+        // Dernier bloc occupé à partir de la droite
+        while last > 0 && disk[last - 1].is_none() {
+            last -= 1;
+        }
+        if last == 0 || free >= last - 1 {
+            break;
+        }
+        disk.swap(free, last - 1);
+    }
+}
+
+/// Fonction pour compacter le disque fichier par fichier (jour 9, partie 2) : chaque fichier,
+/// par numéro décroissant, est déplacé d'un bloc dans le premier espace libre assez grand situé
+/// à sa gauche, s'il en existe un
+pub fn compact_files(disk: &mut [Option<u64>]) {
+    // Espaces libres (début, longueur), de gauche à droite, mis à jour au fil des déplacements
+    let mut free_spans = Vec::new();
+    let mut i = 0;
+    while i < disk.len() {
+        let start = i;
+        while i < disk.len() && disk[i] == disk[start] {
+            i += 1;
+        }
+        if disk[start].is_none() {
+            free_spans.push((start, i - start));
+        }
+    }
+
+    // Parcourt les fichiers de droite à gauche ; chacun n'est traité qu'une fois,
+    // dans l'ordre décroissant des numéros
+    let mut end = disk.len();
+    let mut next_id = u64::MAX;
+    while end > 0 {
+        let Some(id) = disk[end - 1] else {
+            end -= 1;
+            continue;
+        };
+        let mut start = end - 1;
+        while start > 0 && disk[start - 1] == Some(id) {
+            start -= 1;
+        }
+        let len = end - start;
+
+        if id < next_id {
+            next_id = id;
+            // Premier espace libre assez grand, strictement à gauche du fichier
+            if let Some(span) = free_spans
+                .iter_mut()
+                .take_while(|(free_start, _)| *free_start < start)
+                .find(|(_, free_len)| *free_len >= len)
+            {
+                for offset in 0..len {
+                    disk[span.0 + offset] = Some(id);
+                    disk[start + offset] = None;
+                }
+                // L'espace libre rétrécit par la gauche
+                span.0 += len;
+                span.1 -= len;
+            }
+        }
+        end = start;
+    }
+}
+
+/// Fonction pour calculer la somme de contrôle du disque : la somme de `position * numéro du fichier`
+/// sur tous les blocs occupés
+pub fn checksum(disk: &[Option<u64>]) -> u64 {
+    disk.iter()
+        .enumerate()
+        .filter_map(|(i, block)| block.map(|id| i as u64 * id))
+        .sum()
+}
@@ -0,0 +1,75 @@
+// Importation des bibliothèques nécessaires
+use std::fmt; // Pour afficher les erreurs
+use std::io; // Pour les erreurs de lecture
+use std::path::PathBuf; // Pour le chemin d'une entrée introuvable
+
+/// Erreur commune aux utilitaires de l'Advent of Code
+#[derive(Debug)]
+pub enum AocError {
+    /// Erreur de lecture ou d'écriture
+    Io(io::Error),
+    /// Une ligne de l'entrée ne peut pas être analysée
+    Parse {
+        line: usize,     // Numéro de la ligne fautive, à partir de 1
+        text: String,    // Contenu de la ligne fautive
+        message: String, // Ce qui ne va pas dans cette ligne
+    },
+    /// Le fichier d'entrée n'existe pas ; `day` permet de suggérer la commande `fetch`
+    MissingInput { path: PathBuf, day: Option<u8> },
+    /// L'entrée ne respecte pas le format attendu (le message explique pourquoi)
+    Format(String),
+    /// Un calcul dépasse la capacité de son type (le texte nomme ce qui était calculé)
+    Overflow(String),
+}
+
+impl AocError {
+    /// Crée une erreur d'analyse pour la ligne `index` (numérotée à partir de 0)
+    pub fn parse(index: usize, text: &str, message: impl Into<String>) -> Self {
+        AocError::Parse {
+            line: index + 1,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io(error) => write!(f, "erreur d'entrée/sortie : {}", error),
+            AocError::Parse {
+                line,
+                text,
+                message,
+            } => write!(f, "ligne {} : {} dans {:?}", line, message, text),
+            AocError::MissingInput { path, day } => {
+                write!(f, "fichier d'entrée {} introuvable", path.display())?;
+                match day {
+                    Some(day) => write!(
+                        f,
+                        " : lancer `aoc2024 fetch --day {}` pour le télécharger",
+                        day
+                    ),
+                    None => write!(f, " : lancer `aoc2024 fetch` pour le télécharger"),
+                }
+            }
+            AocError::Format(message) => write!(f, "format d'entrée invalide : {}", message),
+            AocError::Overflow(what) => write!(f, "dépassement de capacité : {}", what),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(error: io::Error) -> Self {
+        AocError::Io(error)
+    }
+}
@@ -0,0 +1,17 @@
+// Importation des bibliothèques nécessaires
+use fastrand::Rng; // Générateur pseudo-aléatoire à graine fixe
+
+/// Interface des solutions qui savent générer des entrées synthétiques
+/// Les vraies entrées ne peuvent pas être partagées publiquement ; une entrée générée a la même
+/// structure (et des caractéristiques proches) et peut être versionnée comme cas de test ou de mesure
+pub trait InputGenerator: Sync {
+    /// Génère le texte d'une entrée ; `size` en fixe la taille (le nombre de lignes, par exemple)
+    /// La même graine donne toujours la même entrée
+    fn generate(&self, size: usize, seed: u64) -> String;
+}
+
+/// Fonction pour créer le générateur pseudo-aléatoire d'une graine, reproductible d'une exécution
+/// (et d'une machine) à l'autre
+pub fn seeded_rng(seed: u64) -> Rng {
+    Rng::with_seed(seed)
+}
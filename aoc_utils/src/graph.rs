@@ -0,0 +1,263 @@
+// Importation des bibliothèques nécessaires
+use std::collections::{HashMap, HashSet, VecDeque}; // Pour représenter les listes d'adjacence
+use std::hash::Hash; // Pour exiger des nœuds hachables
+
+/// Fonction pour lire une arête par ligne, comme `"ab-cd"` avec le séparateur `"-"`
+/// Les lignes vides ou sans séparateur sont ignorées
+pub fn parse_adjacency_pairs<'a>(input: &'a str, sep: &str) -> Vec<(&'a str, &'a str)> {
+    input
+        .lines()
+        .filter_map(|line| line.trim().split_once(sep)) // Coupe la ligne au niveau du séparateur
+        .map(|(a, b)| (a.trim(), b.trim()))
+        .collect()
+}
+
+/// Fonction pour construire un graphe non orienté : chaque arête est ajoutée dans les deux sens
+pub fn build_undirected<'a>(pairs: &[(&'a str, &'a str)]) -> HashMap<&'a str, HashSet<&'a str>> {
+    let mut graph: HashMap<&str, HashSet<&str>> = HashMap::new();
+    for &(a, b) in pairs {
+        graph.entry(a).or_default().insert(b);
+        graph.entry(b).or_default().insert(a);
+    }
+    graph
+}
+
+/// Fonction pour construire un graphe orienté : chaque arête ne va que de la source vers la cible
+/// Les nœuds sans successeur apparaissent quand même, avec un ensemble vide
+pub fn build_directed<'a>(pairs: &[(&'a str, &'a str)]) -> HashMap<&'a str, HashSet<&'a str>> {
+    let mut graph: HashMap<&str, HashSet<&str>> = HashMap::new();
+    for &(from, to) in pairs {
+        graph.entry(from).or_default().insert(to);
+        graph.entry(to).or_default();
+    }
+    graph
+}
+
+/// Fonction pour lire un nœud et tous ses voisins par ligne, comme `"a: b c d"`
+/// `node_sep` sépare le nœud de ses voisins, `neighbor_sep` sépare les voisins entre eux
+pub fn parse_multi_adjacency<'a>(
+    input: &'a str,
+    node_sep: &str,
+    neighbor_sep: &str,
+) -> HashMap<&'a str, Vec<&'a str>> {
+    input
+        .lines()
+        .filter_map(|line| line.trim().split_once(node_sep))
+        .map(|(node, neighbors)| {
+            // Découpe la liste des voisins en ignorant les morceaux vides
+            let neighbors = neighbors
+                .split(neighbor_sep)
+                .map(str::trim)
+                .filter(|n| !n.is_empty())
+                .collect();
+            (node.trim(), neighbors)
+        })
+        .collect()
+}
+
+/// Fonction pour trier topologiquement un sous-ensemble de nœuds (algorithme de Kahn)
+/// Seules les arêtes dont les deux extrémités sont dans `subset` sont prises en compte,
+/// ce qui évite de trier tous les nœuds quand une mise à jour du jour 5 n'en contient qu'une vingtaine
+/// En cas de cycle, retourne `Err` avec les nœuds qui n'ont pas pu être ordonnés
+/// Les nœuds de `subset` doivent être distincts
+pub fn topological_sort_subset<T: Hash + Eq + Clone>(
+    subset: &[T],
+    all_edges: &[(T, T)],
+) -> Result<Vec<T>, Vec<T>> {
+    let members: HashSet<&T> = subset.iter().collect();
+
+    // Successeurs et nombre de prédécesseurs de chaque nœud, restreints au sous-ensemble
+    let mut successors: HashMap<&T, Vec<&T>> = HashMap::new();
+    let mut in_degree: HashMap<&T, usize> = subset.iter().map(|node| (node, 0)).collect();
+    for (from, to) in all_edges {
+        if members.contains(from) && members.contains(to) {
+            successors.entry(from).or_default().push(to);
+            *in_degree.entry(to).or_default() += 1;
+        }
+    }
+
+    // Part des nœuds sans prédécesseur, dans l'ordre où ils apparaissent dans `subset`
+    let mut queue: VecDeque<&T> = subset.iter().filter(|node| in_degree[node] == 0).collect();
+    let mut order = Vec::with_capacity(subset.len());
+    while let Some(node) = queue.pop_front() {
+        order.push(node.clone());
+        for &next in successors.get(node).into_iter().flatten() {
+            let degree = in_degree.entry(next).or_default();
+            *degree -= 1;
+            if *degree == 0 {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    if order.len() == members.len() {
+        Ok(order)
+    } else {
+        // Les nœuds restants ont encore des prédécesseurs : ils font partie d'un cycle (ou en dépendent)
+        let mut seen = HashSet::new();
+        Err(subset
+            .iter()
+            .filter(|node| in_degree[node] > 0 && seen.insert(*node))
+            .cloned()
+            .collect())
+    }
+}
+
+/// Fonction pour vérifier qu'un ordre donné respecte toutes les arêtes entre ses propres nœuds
+/// Les arêtes touchant un nœud absent de `order` sont ignorées
+pub fn is_valid_subset_order<T: Hash + Eq>(order: &[T], all_edges: &[(T, T)]) -> bool {
+    // Position de chaque nœud dans l'ordre proposé
+    let position: HashMap<&T, usize> = order.iter().enumerate().map(|(i, n)| (n, i)).collect();
+    all_edges
+        .iter()
+        .all(|(from, to)| match (position.get(from), position.get(to)) {
+            (Some(a), Some(b)) => a < b,
+            _ => true, // Arête hors du sous-ensemble
+        })
+}
+
+/// Fonction pour ordonner des nœuds de sorte que chacun vienne après toutes ses dépendances
+/// (par exemple, évaluer une porte logique du jour 24 seulement quand ses fils d'entrée sont connus)
+/// `dependencies` retourne les dépendances directes d'un nœud ; elles sont ordonnées elles aussi,
+/// même si elles ne figurent pas dans `nodes`
+/// En cas de cycle, retourne `Err` avec un nœud qui en fait partie
+pub fn topological_evaluation_order<'a, T: Hash + Eq>(
+    nodes: &'a [T],
+    dependencies: impl Fn(&T) -> Vec<&'a T>,
+) -> Result<Vec<&'a T>, &'a T> {
+    // `false` : nœud en cours d'exploration, `true` : nœud déjà placé dans l'ordre
+    let mut done: HashMap<&T, bool> = HashMap::new();
+    let mut order = Vec::with_capacity(nodes.len());
+
+    for root in nodes {
+        if done.contains_key(root) {
+            continue;
+        }
+        // Parcours en profondeur itératif : chaque entrée garde les dépendances restant à visiter
+        done.insert(root, false);
+        let mut stack = vec![(root, dependencies(root).into_iter())];
+        while let Some((node, remaining)) = stack.last_mut() {
+            match remaining.next() {
+                Some(dep) => match done.get(dep) {
+                    None => {
+                        done.insert(dep, false);
+                        stack.push((dep, dependencies(dep).into_iter()));
+                    }
+                    // Dépendance encore en cours d'exploration : on est revenu sur nos pas
+                    Some(false) => return Err(dep),
+                    Some(true) => {}
+                },
+                None => {
+                    // Toutes les dépendances sont placées : le nœud peut l'être à son tour
+                    let node = *node;
+                    done.insert(node, true);
+                    order.push(node);
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    Ok(order)
+}
+
+/// Fonction pour trouver une clique de taille maximale d'un graphe non orienté (algorithme de
+/// Bron-Kerbosch avec pivot)
+/// Le graphe doit être symétrique, comme celui construit par `build_undirected`
+/// Les nœuds de la clique sont retournés triés
+pub fn maximum_clique<T: Hash + Eq + Clone + Ord>(graph: &HashMap<T, HashSet<T>>) -> Vec<T> {
+    let mut best = Vec::new();
+    let candidates: HashSet<&T> = graph.keys().collect();
+    bron_kerbosch(
+        graph,
+        &mut Vec::new(),
+        candidates,
+        HashSet::new(),
+        &mut best,
+    );
+
+    let mut clique: Vec<T> = best.into_iter().cloned().collect();
+    clique.sort();
+    clique
+}
+
+/// Étape récursive de Bron-Kerbosch : `clique` est la clique en cours de construction, `candidates`
+/// les nœuds qui peuvent encore l'étendre et `excluded` ceux déjà explorés
+fn bron_kerbosch<'a, T: Hash + Eq>(
+    graph: &'a HashMap<T, HashSet<T>>,
+    clique: &mut Vec<&'a T>,
+    mut candidates: HashSet<&'a T>,
+    mut excluded: HashSet<&'a T>,
+    best: &mut Vec<&'a T>,
+) {
+    if candidates.is_empty() {
+        if excluded.is_empty() && clique.len() > best.len() {
+            *best = clique.clone(); // Clique maximale (au sens de l'inclusion) plus grande que les précédentes
+        }
+        return;
+    }
+    // Élagage : même en ajoutant tous les candidats, on ne ferait pas mieux
+    if clique.len() + candidates.len() <= best.len() {
+        return;
+    }
+
+    let neighbors = |node: &T| graph.get(node);
+    // Pivot : le nœud qui a le plus de voisins parmi les candidats, dont les voisins n'ont pas besoin d'être essayés
+    let pivot = candidates
+        .iter()
+        .chain(excluded.iter())
+        .copied()
+        .max_by_key(|&node| {
+            neighbors(node).map_or(0, |n| n.iter().filter(|m| candidates.contains(m)).count())
+        });
+    let to_try: Vec<&T> = candidates
+        .iter()
+        .copied()
+        .filter(|&node| pivot.and_then(neighbors).is_none_or(|n| !n.contains(node)))
+        .collect();
+
+    for node in to_try {
+        let node_neighbors = neighbors(node);
+        let is_neighbor = |other: &&T| node_neighbors.is_some_and(|n| n.contains(*other));
+        clique.push(node);
+        bron_kerbosch(
+            graph,
+            clique,
+            candidates.iter().copied().filter(is_neighbor).collect(),
+            excluded.iter().copied().filter(is_neighbor).collect(),
+            best,
+        );
+        clique.pop();
+        candidates.remove(node);
+        excluded.insert(node);
+    }
+}
+
+/// Fonction pour calculer le mot de passe de la fête LAN (jour 23, partie 2) : les ordinateurs
+/// de la plus grande clique, triés par ordre alphabétique et séparés par des virgules
+pub fn maximum_clique_password(graph: &HashMap<String, HashSet<String>>) -> String {
+    maximum_clique(graph).join(",")
+}
+
+/// Fonction pour compter les triangles (trois nœuds reliés deux à deux) dont au moins un nœud
+/// commence par `prefix` (jour 23, partie 1)
+pub fn triangle_count_with_prefix(graph: &HashMap<&str, HashSet<&str>>, prefix: char) -> usize {
+    let mut count = 0;
+    for (&a, neighbors) in graph {
+        for &b in neighbors {
+            // Chaque triangle n'est compté qu'une fois, pour l'ordre a < b < c
+            if b <= a {
+                continue;
+            }
+            for &c in neighbors {
+                if c > b
+                    && graph.get(b).is_some_and(|n| n.contains(c))
+                    && [a, b, c].iter().any(|node| node.starts_with(prefix))
+                {
+                    count += 1;
+                }
+            }
+        }
+    }
+    count
+}
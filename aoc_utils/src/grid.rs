@@ -0,0 +1,739 @@
+// Importation des bibliothèques nécessaires
+use crate::direction::{Direction, Direction8}; // Pour les voisins et la lecture en ligne droite
+use crate::grid_path::GridPath; // Pour les plus courts chemins retournés avec leur coût
+use crate::point::Point2D; // Pour repérer les cases de la grille
+use crate::search::{bfs_distance_to_all, dijkstra}; // Pour les plus courts chemins dans la grille
+use colored::{Color, Colorize}; // Pour l'affichage coloré dans le terminal
+use std::collections::hash_map::Entry; // Pour ne visiter qu'une fois chaque case de la remontée
+use std::collections::{HashMap, HashSet, VecDeque}; // Pour les tailles des composantes, les cases à mettre en évidence et la remontée des chemins
+use std::fmt::Display; // Pour afficher le contenu des cases
+use std::ops::{Index, IndexMut}; // Pour l'accès direct `grid[(ligne, colonne)]`
+
+/// Les huit déplacements vers les cases voisines, diagonales comprises
+const OFFSETS8: [Point2D<i64>; 8] = [
+    Point2D::new(-1, -1),
+    Point2D::new(-1, 0),
+    Point2D::new(-1, 1),
+    Point2D::new(0, -1),
+    Point2D::new(0, 1),
+    Point2D::new(1, -1),
+    Point2D::new(1, 0),
+    Point2D::new(1, 1),
+];
+
+/// Interface commune aux différentes représentations de grilles (`Grid`, `VecGrid`)
+/// Les algorithmes écrits pour `GridLike` fonctionnent avec toutes ces représentations
+pub trait GridLike<T> {
+    /// Nombre de colonnes de la grille
+    fn width(&self) -> usize;
+
+    /// Nombre de lignes de la grille
+    fn height(&self) -> usize;
+
+    /// Retourne la case à cette position, ou `None` si elle est hors de la grille
+    fn get(&self, pos: Point2D<i64>) -> Option<&T>;
+
+    /// Remplace la case à cette position (panique si elle est hors de la grille)
+    fn set(&mut self, pos: Point2D<i64>, value: T);
+
+    /// Indique si la position se trouve à l'intérieur de la grille
+    fn in_bounds(&self, pos: Point2D<i64>) -> bool {
+        pos.x >= 0
+            && pos.y >= 0
+            && (pos.x as usize) < self.height()
+            && (pos.y as usize) < self.width()
+    }
+
+    /// Les voisins orthogonaux (nord, est, sud, ouest) situés dans la grille
+    fn neighbors4(&self, pos: Point2D<i64>) -> impl Iterator<Item = Point2D<i64>> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |dir| pos + dir.delta())
+            .filter(move |&next| self.in_bounds(next))
+    }
+
+    /// Les huit voisins (diagonales comprises) situés dans la grille, ligne par ligne
+    fn neighbors8(&self, pos: Point2D<i64>) -> impl Iterator<Item = Point2D<i64>> + '_ {
+        OFFSETS8
+            .into_iter()
+            .map(move |offset| pos + offset)
+            .filter(move |&next| self.in_bounds(next))
+    }
+
+    /// Toutes les positions dont la case vaut `value`, ligne par ligne
+    fn find_all(&self, value: &T) -> Vec<Point2D<i64>>
+    where
+        T: PartialEq,
+    {
+        (0..self.height() as i64)
+            .flat_map(|x| (0..self.width() as i64).map(move |y| Point2D::new(x, y)))
+            .filter(|&pos| self.get(pos) == Some(value))
+            .collect()
+    }
+}
+
+/// Grille rectangulaire stockée dans un vecteur plat, ligne par ligne
+/// Les positions sont des `Point2D<i64>` où `x` est la ligne et `y` la colonne
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>, // Les cases, ligne après ligne
+    width: usize,  // Nombre de colonnes
+    height: usize, // Nombre de lignes
+}
+
+impl<T> Grid<T> {
+    /// Crée une grille à partir de ses cases déjà rangées ligne par ligne
+    /// Panique si le nombre de cases ne correspond pas aux dimensions
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "le nombre de cases ne correspond pas aux dimensions de la grille"
+        );
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Analyse un texte ligne par ligne, chaque caractère devenant une case via `cell_fn`
+    /// Les lignes vides sont ignorées et toutes les lignes doivent avoir la même longueur
+    pub fn parse(input: &str, cell_fn: impl Fn(char) -> T) -> Self {
+        let mut cells = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for line in input.lines().map(str::trim_end).filter(|l| !l.is_empty()) {
+            // Convertit chaque caractère de la ligne en case
+            let row_len = line.chars().count();
+            if height == 0 {
+                width = row_len; // La première ligne fixe la largeur
+            }
+            assert_eq!(row_len, width, "la grille n'est pas rectangulaire");
+            cells.extend(line.chars().map(&cell_fn));
+            height += 1;
+        }
+
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    /// Nombre de colonnes de la grille
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Nombre de lignes de la grille
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Indique si la position se trouve à l'intérieur de la grille
+    pub fn in_bounds(&self, pos: Point2D<i64>) -> bool {
+        GridLike::in_bounds(self, pos)
+    }
+
+    /// Convertit une position en indice dans le vecteur plat, si elle est dans la grille
+    fn index(&self, pos: Point2D<i64>) -> Option<usize> {
+        if self.in_bounds(pos) {
+            Some(pos.x as usize * self.width + pos.y as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Retourne la case à cette position, ou `None` si elle est hors de la grille
+    pub fn get(&self, pos: Point2D<i64>) -> Option<&T> {
+        self.index(pos).map(|i| &self.cells[i])
+    }
+
+    /// Retourne une référence modifiable vers la case à cette position
+    pub fn get_mut(&mut self, pos: Point2D<i64>) -> Option<&mut T> {
+        self.index(pos).map(move |i| &mut self.cells[i])
+    }
+
+    /// Remplace la case à cette position
+    /// Panique si la position est hors de la grille
+    pub fn set(&mut self, pos: Point2D<i64>, value: T) {
+        let i = self
+            .index(pos)
+            .unwrap_or_else(|| panic!("position {:?} hors de la grille", pos));
+        self.cells[i] = value;
+    }
+
+    /// Les voisins orthogonaux (nord, est, sud, ouest) situés dans la grille
+    pub fn neighbors4(&self, pos: Point2D<i64>) -> impl Iterator<Item = Point2D<i64>> + '_ {
+        GridLike::neighbors4(self, pos)
+    }
+
+    /// Les huit voisins (diagonales comprises) situés dans la grille
+    pub fn neighbors8(&self, pos: Point2D<i64>) -> impl Iterator<Item = Point2D<i64>> + '_ {
+        GridLike::neighbors8(self, pos)
+    }
+
+    /// Toutes les positions dont la case vaut `value`, ligne par ligne
+    pub fn find_all(&self, value: &T) -> Vec<Point2D<i64>>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    /// La première position dont la case vaut `value`, ligne par ligne
+    pub fn find(&self, value: &T) -> Option<Point2D<i64>>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Retourne la ligne `r` sous forme de tranche
+    pub fn row_slice(&self, r: usize) -> &[T] {
+        &self.cells[r * self.width..(r + 1) * self.width]
+    }
+
+    /// Consomme la grille et retourne ses cases, ligne par ligne
+    pub fn into_cells(self) -> Vec<T> {
+        self.cells
+    }
+
+    /// Parcourt toutes les positions de la grille, ligne par ligne
+    pub fn positions(&self) -> impl Iterator<Item = Point2D<i64>> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |i| Point2D::new((i / width) as i64, (i % width) as i64))
+    }
+
+    /// Parcourt toutes les cases avec leur position, ligne par ligne
+    pub fn iter(&self) -> impl Iterator<Item = (Point2D<i64>, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Transforme chaque case de la grille, en gardant ses dimensions
+    pub fn map<U>(self, f: impl Fn(T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.into_iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Transforme chaque case en connaissant sa position
+    pub fn map_with_pos<U>(self, f: impl Fn(Point2D<i64>, T) -> U) -> Grid<U> {
+        let (width, height) = (self.width, self.height);
+        let cells = self
+            .positions()
+            .zip(self.cells)
+            .map(|(pos, cell)| f(pos, cell));
+        Grid {
+            cells: cells.collect(),
+            width,
+            height,
+        }
+    }
+
+    /// Combine deux grilles de même taille case par case
+    /// Panique si les dimensions diffèrent
+    pub fn zip_with<U, V>(self, other: Grid<U>, f: impl Fn(T, U) -> V) -> Grid<V> {
+        assert!(
+            self.width == other.width && self.height == other.height,
+            "grilles de tailles différentes : {}x{} et {}x{}",
+            self.width,
+            self.height,
+            other.width,
+            other.height
+        );
+        Grid {
+            cells: self
+                .cells
+                .into_iter()
+                .zip(other.cells)
+                .map(|(a, b)| f(a, b))
+                .collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Garde les cases pour lesquelles `f` retourne `Some`, avec leur position, ligne par ligne
+    pub fn filter_map_positions<U>(
+        self,
+        f: impl Fn(Point2D<i64>, T) -> Option<U>,
+    ) -> Vec<(Point2D<i64>, U)> {
+        self.positions()
+            .zip(self.cells)
+            .filter_map(|(pos, cell)| f(pos, cell).map(|value| (pos, value)))
+            .collect()
+    }
+
+    /// Parcourt les lignes de la grille sous forme de tranches
+    fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `max(1)` évite une division par zéro pour une grille vide
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Regroupe les cases voisines (orthogonalement) qui appartiennent à la même région
+    /// Chaque case est reliée à ses voisines de droite et du dessous si `same_region` l'accepte
+    fn union_regions(&self, same_region: impl Fn(&T, &T) -> bool) -> UnionFind {
+        let mut sets = UnionFind::new(self.cells.len());
+        for i in 0..self.cells.len() {
+            let (row, col) = (i / self.width, i % self.width);
+            if col + 1 < self.width && same_region(&self.cells[i], &self.cells[i + 1]) {
+                sets.union(i, i + 1);
+            }
+            if row + 1 < self.height && same_region(&self.cells[i], &self.cells[i + self.width]) {
+                sets.union(i, i + self.width);
+            }
+        }
+        sets
+    }
+
+    /// Nombre de composantes connexes de la grille, sans construire les régions
+    /// Deux cases voisines sont dans la même composante si `same_region` retourne `true`
+    pub fn connected_components_count(&self, same_region: impl Fn(&T, &T) -> bool) -> usize {
+        let mut sets = self.union_regions(same_region);
+        (0..self.cells.len()).filter(|&i| sets.find(i) == i).count()
+    }
+
+    /// Taille de chaque composante connexe, indexée par son représentant
+    /// (l'indice, ligne par ligne, d'une des cases de la composante)
+    pub fn component_sizes(&self, same_region: impl Fn(&T, &T) -> bool) -> HashMap<usize, usize> {
+        let mut sets = self.union_regions(same_region);
+        let mut sizes = HashMap::new();
+        for i in 0..self.cells.len() {
+            *sizes.entry(sets.find(i)).or_default() += 1;
+        }
+        sizes
+    }
+
+    /// Bords exposés d'une région : chaque case de la région (ligne par ligne) avec la direction
+    /// d'un voisin qui n'appartient pas à la région, y compris les bords de la grille
+    pub fn exposed_edges_of_region(
+        &self,
+        region: &HashSet<Point2D<i64>>,
+    ) -> Vec<(Point2D<i64>, Direction)> {
+        self.positions()
+            .filter(|pos| region.contains(pos))
+            .flat_map(|pos| {
+                Direction::ALL
+                    .into_iter()
+                    .filter(move |dir| !region.contains(&(pos + dir.delta())))
+                    .map(move |dir| (pos, dir))
+            })
+            .collect()
+    }
+
+    /// Périmètre d'une région de la grille (nombre de bords exposés)
+    pub fn perimeter_of_region(&self, region: &HashSet<Point2D<i64>>) -> usize {
+        self.exposed_edges_of_region(region).len()
+    }
+
+    /// Construit une grille `width` x `height` dont la case `(r, c)` est la case `source(r, c)` de `self`
+    fn rearranged(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|r| (0..width).map(move |c| (r, c)))
+            .map(|(r, c)| {
+                let (sr, sc) = source(r, c);
+                self.cells[sr * self.width + sc].clone()
+            })
+            .collect();
+        Grid::from_vec(width, height, cells)
+    }
+
+    /// Grille transposée : les lignes deviennent les colonnes
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |r, c| (c, r))
+    }
+
+    /// Grille tournée d'un quart de tour dans le sens des aiguilles d'une montre
+    pub fn rotate_cw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let h = self.height;
+        self.rearranged(self.height, self.width, |r, c| (h - 1 - c, r))
+    }
+
+    /// Grille tournée d'un quart de tour dans le sens inverse des aiguilles d'une montre
+    pub fn rotate_ccw(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let w = self.width;
+        self.rearranged(self.height, self.width, |r, c| (c, w - 1 - r))
+    }
+
+    /// Grille retournée de gauche à droite (chaque ligne est inversée)
+    pub fn flip_horizontal(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let w = self.width;
+        self.rearranged(self.width, self.height, |r, c| (r, w - 1 - c))
+    }
+
+    /// Grille retournée de haut en bas (l'ordre des lignes est inversé)
+    pub fn flip_vertical(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let h = self.height;
+        self.rearranged(self.width, self.height, |r, c| (h - 1 - r, c))
+    }
+
+    /// Distance (en pas orthogonaux) de `start` à chaque case atteignable,
+    /// en ne traversant que les cases pour lesquelles `passable` est vrai
+    pub fn bfs_distances(
+        &self,
+        start: Point2D<i64>,
+        passable: impl Fn(&T) -> bool,
+    ) -> HashMap<Point2D<i64>, usize> {
+        bfs_distance_to_all(start, |&pos| {
+            self.neighbors4(pos)
+                .filter(|&next| passable(&self[next]))
+                .collect::<Vec<_>>()
+        })
+    }
+
+    /// Coût minimal de `start` à chaque case atteignable (algorithme de Dijkstra)
+    /// `cost` donne le coût pour entrer dans une case, ou `None` si elle est infranchissable
+    pub fn dijkstra_distances(
+        &self,
+        start: Point2D<i64>,
+        cost: impl Fn(&T) -> Option<u64>,
+    ) -> HashMap<Point2D<i64>, u64> {
+        dijkstra(start, |&pos| {
+            self.neighbors4(pos)
+                .filter_map(|next| cost(&self[next]).map(|c| (next, c)))
+                .collect::<Vec<_>>()
+        })
+    }
+
+    /// Un plus court chemin (en pas orthogonaux) de `start` à `goal`, ou `None` si `goal` est inatteignable
+    /// Le coût du chemin est son nombre de pas
+    pub fn bfs_path(
+        &self,
+        start: Point2D<i64>,
+        goal: Point2D<i64>,
+        passable: impl Fn(&T) -> bool,
+    ) -> Option<GridPath> {
+        let distances = self.bfs_distances(start, &passable);
+        // Les prédécesseurs d'une case sont ses voisins à un pas de moins du départ
+        let positions = Self::backtrack(start, goal, |pos| {
+            let Some(&distance) = distances.get(&pos) else {
+                return Vec::new();
+            };
+            self.neighbors4(pos)
+                .filter(|next| distances.get(next).is_some_and(|d| d + 1 == distance))
+                .collect()
+        })?;
+        Some(GridPath {
+            cost: distances[&goal] as u64,
+            positions,
+        })
+    }
+
+    /// Un chemin de coût minimal de `start` à `goal` (algorithme de Dijkstra), ou `None` si `goal`
+    /// est inatteignable
+    /// `cost` donne le coût pour entrer dans une case, ou `None` si elle est infranchissable
+    /// Les coûts nuls sont acceptés : le chemin ne repasse jamais par une même case
+    pub fn dijkstra_path(
+        &self,
+        start: Point2D<i64>,
+        goal: Point2D<i64>,
+        cost: impl Fn(&T) -> Option<u64>,
+    ) -> Option<GridPath> {
+        let distances = self.dijkstra_distances(start, &cost);
+        // Les prédécesseurs d'une case sont les voisins dont la distance plus le coût d'entrée donne la sienne
+        let positions = Self::backtrack(start, goal, |pos| {
+            let (Some(&distance), Some(enter)) = (distances.get(&pos), cost(&self[pos])) else {
+                return Vec::new();
+            };
+            self.neighbors4(pos)
+                .filter(|next| distances.get(next).is_some_and(|&d| d + enter == distance))
+                .collect()
+        })?;
+        Some(GridPath {
+            cost: distances[&goal],
+            positions,
+        })
+    }
+
+    /// Remonte de `goal` à `start` en suivant `predecessors`, et retourne le chemin dans l'ordre du départ
+    /// La remontée est un parcours en largeur qui ne visite chaque case qu'une fois : avec des coûts nuls,
+    /// deux voisins à la même distance sont prédécesseurs l'un de l'autre sans faire boucler la remontée
+    fn backtrack(
+        start: Point2D<i64>,
+        goal: Point2D<i64>,
+        predecessors: impl Fn(Point2D<i64>) -> Vec<Point2D<i64>>,
+    ) -> Option<Vec<Point2D<i64>>> {
+        // Case suivante (vers `goal`) de chaque case atteinte par la remontée
+        let mut next_step = HashMap::from([(goal, goal)]);
+        let mut queue = VecDeque::from([goal]);
+        while let Some(pos) = queue.pop_front() {
+            if pos == start {
+                break;
+            }
+            for previous in predecessors(pos) {
+                if let Entry::Vacant(entry) = next_step.entry(previous) {
+                    entry.insert(pos);
+                    queue.push_back(previous);
+                }
+            }
+        }
+
+        let mut positions = vec![start];
+        let mut pos = start;
+        while pos != goal {
+            pos = *next_step.get(&pos)?;
+            positions.push(pos);
+        }
+        Some(positions)
+    }
+
+    /// Dessine la grille en remplaçant les cases mises en évidence par `highlight_char`
+    /// Pratique pour visualiser le chemin d'un garde ou une région de jardin
+    pub fn display_with_highlight(
+        &self,
+        highlight: &HashSet<Point2D<i64>>,
+        cell_fn: impl Fn(&T) -> char,
+        highlight_char: char,
+    ) -> String {
+        let mut output = String::with_capacity((self.width + 1) * self.height);
+        for (pos, cell) in self.iter() {
+            if highlight.contains(&pos) {
+                output.push(highlight_char); // Case mise en évidence
+            } else {
+                output.push(cell_fn(cell)); // Case normale
+            }
+            // Passe à la ligne suivante après la dernière colonne
+            if pos.y as usize == self.width - 1 {
+                output.push('\n');
+            }
+        }
+        output
+    }
+
+    /// Affiche la grille dans le terminal avec une couleur ANSI choisie pour chaque case
+    pub fn print_colored(&self, color_fn: impl Fn(&T) -> Color)
+    where
+        T: Display,
+    {
+        for row in self.rows() {
+            let line: String = row
+                .iter()
+                .map(|cell| cell.to_string().color(color_fn(cell)).to_string())
+                .collect();
+            println!("{}", line);
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Lit `len` caractères en partant de `start` et en avançant dans la direction `dir`
+    /// Retourne `None` si la lecture sortirait de la grille
+    pub fn chars_in_direction(
+        &self,
+        start: Point2D<i64>,
+        dir: Direction8,
+        len: usize,
+    ) -> Option<String> {
+        (0..len as i64)
+            .map(|step| self.get(start + dir.delta() * step).copied())
+            .collect()
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    /// Accès à la case `(ligne, colonne)` ; panique hors de la grille
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        assert!(
+            row < self.height && col < self.width,
+            "case ({}, {}) hors de la grille",
+            row,
+            col
+        );
+        &self.cells[row * self.width + col]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        assert!(
+            row < self.height && col < self.width,
+            "case ({}, {}) hors de la grille",
+            row,
+            col
+        );
+        &mut self.cells[row * self.width + col]
+    }
+}
+
+impl<T> Index<Point2D<i64>> for Grid<T> {
+    type Output = T;
+
+    /// Accès à la case d'une position (`x` la ligne, `y` la colonne) ; panique hors de la grille
+    fn index(&self, pos: Point2D<i64>) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {:?} hors de la grille", pos))
+    }
+}
+
+impl<T> GridLike<T> for Grid<T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    fn get(&self, pos: Point2D<i64>) -> Option<&T> {
+        Grid::get(self, pos)
+    }
+
+    fn set(&mut self, pos: Point2D<i64>, value: T) {
+        Grid::set(self, pos, value);
+    }
+}
+
+/// Union-Find (ensembles disjoints) sur les indices des cases, avec compression de chemin
+/// et union par taille
+struct UnionFind {
+    parent: Vec<usize>, // Parent de chaque élément (lui-même pour un représentant)
+    size: Vec<usize>,   // Taille de l'ensemble, valable pour les représentants
+}
+
+impl UnionFind {
+    /// Crée `len` ensembles réduits à un seul élément
+    fn new(len: usize) -> Self {
+        UnionFind {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+        }
+    }
+
+    /// Retourne le représentant de l'ensemble contenant `i`
+    fn find(&mut self, i: usize) -> usize {
+        let mut root = i;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Compression de chemin : tous les éléments parcourus pointent directement sur la racine
+        let mut current = i;
+        while self.parent[current] != root {
+            let next = self.parent[current];
+            self.parent[current] = root;
+            current = next;
+        }
+        root
+    }
+
+    /// Fusionne les ensembles contenant `a` et `b`
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        // Le plus petit ensemble est rattaché au plus grand
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+    }
+}
+
+/// Fonction pour afficher deux grilles côte à côte, avec une troisième colonne marquant d'un `!`
+/// chaque case qui diffère (`.` sinon) : `attendu  obtenu  diff`
+/// Si les dimensions diffèrent, les cases manquantes sont affichées comme des espaces et comptent comme différentes
+pub fn format_grid_diff<T: PartialEq + Display>(expected: &Grid<T>, actual: &Grid<T>) -> String {
+    let height = expected.height().max(actual.height());
+    let width = expected.width().max(actual.width());
+
+    // Texte d'une case, ou un espace si elle n'existe pas dans cette grille
+    let render = |grid: &Grid<T>, pos: Point2D<i64>| {
+        grid.get(pos)
+            .map_or_else(|| " ".to_string(), |cell| cell.to_string())
+    };
+
+    // Construit les trois colonnes ligne par ligne
+    let mut rows = Vec::with_capacity(height);
+    for row in 0..height as i64 {
+        let mut left = String::new();
+        let mut right = String::new();
+        let mut diff = String::new();
+        for col in 0..width as i64 {
+            let pos = Point2D::new(row, col);
+            left.push_str(&render(expected, pos));
+            right.push_str(&render(actual, pos));
+            let same = matches!((expected.get(pos), actual.get(pos)), (Some(e), Some(a)) if e == a);
+            diff.push(if same { '.' } else { '!' });
+        }
+        rows.push((left, right, diff));
+    }
+
+    // Largeur de chaque colonne, pour que les trois restent alignées
+    let left_width = rows
+        .iter()
+        .map(|(l, _, _)| l.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("attendu".len());
+    let right_width = rows
+        .iter()
+        .map(|(_, r, _)| r.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("obtenu".len());
+
+    let mut output = format!(
+        "{:<lw$}  {:<rw$}  diff\n",
+        "attendu",
+        "obtenu",
+        lw = left_width,
+        rw = right_width
+    );
+    for (left, right, diff) in rows {
+        output.push_str(&format!(
+            "{:<lw$}  {:<rw$}  {}\n",
+            left,
+            right,
+            diff,
+            lw = left_width,
+            rw = right_width
+        ));
+    }
+    output
+}
+
+/// Vérifie que deux grilles sont identiques, et panique avec leur différence sinon
+pub fn assert_grid_eq<T: PartialEq + Display>(expected: &Grid<T>, actual: &Grid<T>) {
+    if expected != actual {
+        panic!(
+            "les grilles diffèrent :\n{}",
+            format_grid_diff(expected, actual)
+        );
+    }
+}
@@ -0,0 +1,59 @@
+// Importation des bibliothèques nécessaires
+use crate::grid::Grid; // Pour dessiner le chemin sur une grille
+use crate::point::Point2D; // Pour les positions du chemin
+use std::collections::HashSet; // Pour l'ensemble des cases visitées
+
+/// Chemin dans une grille : la suite des positions parcourues et son coût total
+/// Le coût dépend du problème (nombre de pas, somme des poids, pénalités de rotation, ...)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct GridPath {
+    pub positions: Vec<Point2D<i64>>, // Les positions, du départ à l'arrivée
+    pub cost: u64,                    // Coût total du chemin
+}
+
+impl GridPath {
+    /// Nombre de positions du chemin (départ et arrivée compris)
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Indique si le chemin ne contient aucune position
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Ensemble des positions visitées (chaque position une seule fois)
+    pub fn visited_set(&self) -> HashSet<Point2D<i64>> {
+        self.positions.iter().copied().collect()
+    }
+
+    /// Indique si le chemin passe par cette position (parcours linéaire)
+    pub fn contains(&self, pos: &Point2D<i64>) -> bool {
+        self.positions.contains(pos)
+    }
+
+    /// Retourne une copie de la grille où chaque case du chemin vaut `overlay_val`
+    /// Les positions hors de la grille sont ignorées
+    pub fn to_grid_overlay<T: Clone>(&self, base: &Grid<T>, overlay_val: T) -> Grid<T> {
+        let mut grid = base.clone();
+        for &pos in &self.positions {
+            if let Some(cell) = grid.get_mut(pos) {
+                *cell = overlay_val.clone();
+            }
+        }
+        grid
+    }
+
+    /// Dessine le chemin sur une grille de caractères, chaque case du chemin devenant `path_char`
+    pub fn display_on(&self, base: &Grid<char>, path_char: char) -> String {
+        base.display_with_highlight(&self.visited_set(), |&c| c, path_char)
+    }
+}
+
+impl From<Vec<Point2D<i64>>> for GridPath {
+    /// Chemin dont chaque pas coûte 1 (par exemple un résultat de `bfs_all_paths`)
+    fn from(positions: Vec<Point2D<i64>>) -> Self {
+        let cost = positions.len().saturating_sub(1) as u64;
+        GridPath { positions, cost }
+    }
+}
@@ -0,0 +1,46 @@
+/// Fonction de hachage HASH du jour 15 : pour chaque caractère, `courant = (courant + ASCII) * 17 % 256`
+/// Par exemple `aoc_hash("HASH") == 52`
+pub fn aoc_hash(s: &str) -> u8 {
+    s.bytes()
+        .fold(0u32, |current, byte| (current + byte as u32) * 17 % 256) as u8
+}
+
+/// Fonction pour calculer `base^exp mod modulus` par exponentiation rapide
+fn pow_mod(base: u64, exp: usize, modulus: u64) -> u64 {
+    let mut result = 1 % modulus as u128;
+    let mut square = base as u128 % modulus as u128;
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * square % modulus as u128;
+        }
+        square = square * square % modulus as u128;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Hachage polynomial glissant (Rabin-Karp) : `c0 * BASE^(n-1) + c1 * BASE^(n-2) + ... + c(n-1)` modulo `MOD`
+pub fn rolling_hash<const BASE: u64, const MOD: u64>(s: &str) -> u64 {
+    s.chars().fold(0u64, |hash, c| {
+        ((hash as u128 * BASE as u128 + c as u128) % MOD as u128) as u64
+    })
+}
+
+/// Fonction pour faire glisser d'un caractère une fenêtre de longueur `len` en O(1) :
+/// retire `remove_char` (le plus ancien) et ajoute `add_char` à la fin
+/// Le résultat est identique à `rolling_hash` appliqué à la nouvelle fenêtre
+pub fn rolling_hash_update<const BASE: u64, const MOD: u64>(
+    old_hash: u64,
+    remove_char: char,
+    add_char: char,
+    len: usize,
+) -> u64 {
+    let modulus = MOD as u128;
+    // Poids du caractère retiré dans l'ancienne fenêtre : `BASE^(len-1)`
+    let weight = pow_mod(BASE, len.saturating_sub(1), MOD) as u128;
+    let removed = remove_char as u128 % modulus * weight % modulus;
+    // Retire le plus ancien caractère (en restant positif), décale et ajoute le nouveau
+    let without_first = (old_hash as u128 + modulus - removed) % modulus;
+    ((without_first * BASE as u128 + add_char as u128) % modulus) as u64
+}
@@ -0,0 +1,64 @@
+// Importation des bibliothèques nécessaires
+use crate::point::Point2D; // Pour repérer les cases de la grille
+use std::collections::HashMap; // Pour ne stocker que les cases réellement modifiées
+
+/// Grille conceptuellement infinie : toute case jamais modifiée vaut `T::default()`
+/// Pratique pour les automates cellulaires dont la zone active grandit sans limite connue
+#[derive(Debug, Clone, Default)]
+pub struct InfiniteGrid<T> {
+    cells: HashMap<Point2D<i64>, T>, // Les cases modifiées
+    default: T,                      // La valeur de toutes les autres cases
+}
+
+impl<T: Default + Clone> InfiniteGrid<T> {
+    /// Crée une grille dont toutes les cases valent la valeur par défaut
+    pub fn new() -> Self {
+        InfiniteGrid {
+            cells: HashMap::new(),
+            default: T::default(),
+        }
+    }
+
+    /// Retourne la case à cette position, ou la valeur par défaut si elle n'a jamais été modifiée
+    pub fn get(&self, pos: &Point2D<i64>) -> &T {
+        self.cells.get(pos).unwrap_or(&self.default)
+    }
+
+    /// Retourne une copie de la case à cette position (valeur par défaut si absente)
+    pub fn get_or_default(&self, pos: &Point2D<i64>) -> T {
+        self.get(pos).clone()
+    }
+
+    /// Modifie la case à cette position
+    pub fn set(&mut self, pos: Point2D<i64>, value: T) {
+        self.cells.insert(pos, value);
+    }
+
+    /// Parcourt toutes les cases du rectangle (bornes incluses), ligne par ligne,
+    /// y compris celles qui n'ont jamais été modifiées
+    pub fn iter_region(
+        &self,
+        top_left: Point2D<i64>,
+        bot_right: Point2D<i64>,
+    ) -> impl Iterator<Item = (Point2D<i64>, &T)> {
+        (top_left.x..=bot_right.x).flat_map(move |x| {
+            (top_left.y..=bot_right.y).map(move |y| {
+                let pos = Point2D::new(x, y);
+                (pos, self.get(&pos))
+            })
+        })
+    }
+
+    /// Retourne le plus petit rectangle `(coin haut-gauche, coin bas-droit)` contenant
+    /// toutes les cases modifiées, ou `None` si aucune case ne l'a été
+    pub fn active_bounding_box(&self) -> Option<(Point2D<i64>, Point2D<i64>)> {
+        let mut positions = self.cells.keys();
+        let first = *positions.next()?;
+        Some(positions.fold((first, first), |(min, max), pos| {
+            (
+                Point2D::new(min.x.min(pos.x), min.y.min(pos.y)),
+                Point2D::new(max.x.max(pos.x), max.y.max(pos.y)),
+            )
+        }))
+    }
+}
@@ -0,0 +1,161 @@
+// Importation des bibliothèques nécessaires
+use crate::error::AocError; // Pour les erreurs de lecture
+use crate::parse::read_input; // Pour lire un fichier d'entrée
+use std::convert::Infallible; // Toute chaîne désigne une source valide
+use std::env; // Pour le répertoire des entrées choisi par variable d'environnement
+use std::fmt; // Pour afficher la source
+use std::fs::File; // Pour lire un fichier par morceaux
+use std::io::{self, BufRead, BufReader, Read}; // Pour lire l'entrée standard et les entrées volumineuses
+use std::path::{Path, PathBuf}; // Pour le chemin d'un fichier
+use std::str::FromStr; // Pour lire une source depuis la ligne de commande
+
+/// Variable d'environnement choisissant le répertoire des entrées
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// Répertoire des entrées par défaut, relatif au répertoire courant
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Fonction pour choisir le répertoire des entrées : `dir` s'il est donné (option `--input-dir`),
+/// sinon `$AOC_INPUT_DIR`, sinon `inputs`
+/// Avec un profil (par exemple l'entrée d'un ami), c'est le sous-répertoire `<profil>` qui est utilisé
+pub fn input_dir(dir: Option<&Path>, profile: Option<&str>) -> PathBuf {
+    let base = dir
+        .map(Path::to_path_buf)
+        .or_else(|| env::var_os(INPUT_DIR_ENV).map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR));
+    match profile {
+        Some(profile) => base.join(profile),
+        None => base,
+    }
+}
+
+/// Fonction pour calculer le répertoire des entrées d'une année, comme `inputs/2024`
+pub fn year_dir(dir: &Path, year: u16) -> PathBuf {
+    dir.join(year.to_string())
+}
+
+/// Fonction pour calculer le chemin de l'entrée d'un jour dans le répertoire d'une année,
+/// comme `inputs/2024/day01.txt`
+pub fn day_input_path(year_dir: &Path, day: u8) -> PathBuf {
+    year_dir.join(format!("day{:02}.txt", day))
+}
+
+/// Provenance du texte d'entrée d'un puzzle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// Un fichier sur le disque
+    File(PathBuf),
+    /// L'entrée standard (`-` sur la ligne de commande), par exemple `pbpaste | aoc2024 run --day 1`
+    Stdin,
+    /// Un texte fourni directement, comme l'exemple d'un énoncé
+    Inline(String),
+}
+
+impl InputSource {
+    /// Fonction pour lire tout le texte de la source
+    /// `day` permet de suggérer `aoc2024 fetch` si le fichier est absent
+    /// L'entrée standard ne peut être lue qu'une fois : lire le texte une seule fois pour les deux parties
+    pub fn read(&self, day: Option<u8>) -> Result<String, AocError> {
+        match self {
+            InputSource::File(path) => read_input(path, day),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            InputSource::Inline(text) => Ok(text.clone()),
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    /// `-` désigne l'entrée standard, toute autre valeur un chemin de fichier
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        })
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<entrée standard>"),
+            InputSource::Inline(_) => write!(f, "<texte>"),
+        }
+    }
+}
+
+/// Taille du tampon de lecture des entrées volumineuses
+const STREAM_BUFFER_SIZE: usize = 1 << 16;
+
+/// Fonction pour lire un fichier de paires d'entiers (comme l'entrée du jour 1) sans le charger en entier
+/// Même résultat et mêmes erreurs que `parse_columns::<i64>(input, 2)`, mais le fichier est lu par
+/// morceaux dans un tampon réutilisé et aucune chaîne n'est allouée par ligne : adapté aux entrées
+/// générées de plusieurs centaines de Mo
+pub fn read_pairs_streaming(
+    path: impl AsRef<Path>,
+    day: Option<u8>,
+) -> Result<(Vec<i64>, Vec<i64>), AocError> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => AocError::MissingInput {
+            path: path.to_path_buf(),
+            day,
+        },
+        _ => AocError::Io(e),
+    })?;
+    read_pairs_from(BufReader::with_capacity(STREAM_BUFFER_SIZE, file))
+}
+
+/// Fonction pour lire des paires d'entiers depuis n'importe quel lecteur (fichier, entrée standard...)
+/// Chaque ligne non vide doit contenir exactement deux entiers séparés par des espaces
+pub fn read_pairs_from(mut reader: impl BufRead) -> Result<(Vec<i64>, Vec<i64>), AocError> {
+    let (mut left, mut right) = (Vec::new(), Vec::new());
+    let mut line = Vec::new(); // Réutilisé d'une ligne à l'autre
+    for index in 0.. {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            break; // Fin de l'entrée
+        }
+        let mut values = line
+            .split(u8::is_ascii_whitespace)
+            .filter(|value| !value.is_empty());
+        let Some(first) = values.next() else {
+            continue; // Ligne vide
+        };
+        let (second, extra) = (values.next(), values.count());
+        let second = match second {
+            Some(second) if extra == 0 => second,
+            _ => {
+                let count = 1 + usize::from(second.is_some()) + extra;
+                let message = format!("{} valeurs au lieu de 2", count);
+                return Err(line_error(index, &line, message));
+            }
+        };
+        left.push(parse_value(index, &line, first)?);
+        right.push(parse_value(index, &line, second)?);
+    }
+    Ok((left, right))
+}
+
+/// Fonction pour lire un entier d'une ligne, sans allocation
+fn parse_value(index: usize, line: &[u8], value: &[u8]) -> Result<i64, AocError> {
+    std::str::from_utf8(value)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| {
+            let value = String::from_utf8_lossy(value);
+            line_error(index, line, format!("valeur invalide {:?}", value))
+        })
+}
+
+/// Fonction pour créer l'erreur d'une ligne fautive (le texte n'est converti qu'en cas d'erreur)
+fn line_error(index: usize, line: &[u8], message: String) -> AocError {
+    let text = String::from_utf8_lossy(line);
+    AocError::parse(index, text.trim_end_matches(['\r', '\n']), message)
+}
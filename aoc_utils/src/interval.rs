@@ -0,0 +1,131 @@
+/// Intervalle d'entiers dont les deux bornes sont incluses : `start..=end`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    /// Crée un intervalle `start..=end`
+    /// Panique si `start > end`
+    pub fn new(start: i64, end: i64) -> Self {
+        assert!(start <= end, "intervalle vide : {}..={}", start, end);
+        Interval { start, end }
+    }
+
+    /// Nombre d'entiers contenus dans l'intervalle
+    pub fn length(&self) -> i64 {
+        self.end - self.start + 1
+    }
+
+    /// Indique si le point appartient à l'intervalle
+    pub fn contains(&self, point: i64) -> bool {
+        self.start <= point && point <= self.end
+    }
+
+    /// Indique si l'autre intervalle est entièrement inclus dans celui-ci
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Indique si les deux intervalles ont au moins un point commun
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// Indique si les deux intervalles se chevauchent ou se touchent (comme `1..=3` et `4..=6`)
+    /// La borne est retirée au début plutôt qu'ajoutée à la fin, pour ne pas dépasser `i64::MAX`
+    fn touches(&self, other: &Interval) -> bool {
+        self.start.saturating_sub(1) <= other.end && other.start.saturating_sub(1) <= self.end
+    }
+
+    /// Retourne la partie commune aux deux intervalles, ou `None` s'ils sont disjoints
+    pub fn intersect(&self, other: &Interval) -> Option<Interval> {
+        if self.overlaps(other) {
+            Some(Interval::new(
+                self.start.max(other.start),
+                self.end.min(other.end),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Retourne l'union des deux intervalles si elle forme un seul intervalle
+    /// (intervalles qui se chevauchent ou adjacents), `None` sinon
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        if self.touches(other) {
+            Some(Interval::new(
+                self.start.min(other.start),
+                self.end.max(other.end),
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Nombre d'entiers situés strictement entre les deux intervalles (0 s'ils sont adjacents),
+    /// ou `None` s'ils se chevauchent
+    pub fn gap_between(&self, other: &Interval) -> Option<i64> {
+        if self.overlaps(other) {
+            None
+        } else if self.end < other.start {
+            Some(other.start - self.end - 1)
+        } else {
+            Some(self.start - other.end - 1)
+        }
+    }
+}
+
+/// Ensemble d'intervalles toujours maintenu minimal : triés, sans chevauchement ni adjacence
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MergeableIntervals {
+    intervals: Vec<Interval>, // Triés par début croissant
+}
+
+impl MergeableIntervals {
+    /// Crée un ensemble vide
+    pub fn new() -> Self {
+        MergeableIntervals::default()
+    }
+
+    /// Ajoute un intervalle en le fusionnant avec tous ceux qu'il chevauche ou touche
+    pub fn push_and_merge(&mut self, interval: Interval) {
+        // Premier intervalle qui peut toucher le nouveau (les précédents finissent trop tôt)
+        let first = self
+            .intervals
+            .partition_point(|existing| existing.end < interval.start.saturating_sub(1));
+        // Fusionne tous les intervalles suivants qui touchent le nouveau
+        let mut merged = interval;
+        let mut last = first;
+        while last < self.intervals.len() {
+            match merged.union(&self.intervals[last]) {
+                Some(union) => merged = union,
+                None => break,
+            }
+            last += 1;
+        }
+        // Remplace les intervalles fusionnés par leur union
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Les intervalles de l'ensemble, triés
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    /// Indique si le point appartient à l'un des intervalles
+    pub fn contains(&self, point: i64) -> bool {
+        let i = self
+            .intervals
+            .partition_point(|existing| existing.end < point);
+        self.intervals
+            .get(i)
+            .is_some_and(|existing| existing.contains(point))
+    }
+
+    /// Nombre total d'entiers couverts par l'ensemble
+    pub fn total_length(&self) -> i64 {
+        self.intervals.iter().map(Interval::length).sum()
+    }
+}
@@ -0,0 +1,57 @@
+/// Adaptateur d'itérateur qui produit tous les éléments sauf celui d'indice `skip_index`
+/// Aucun vecteur n'est alloué : seule la position courante est suivie
+#[derive(Debug, Clone)]
+pub struct SkipOne<I> {
+    iter: I,           // L'itérateur d'origine
+    skip_index: usize, // Indice de l'élément à ignorer
+    position: usize,   // Indice du prochain élément de `iter`
+}
+
+impl<I: Iterator> Iterator for SkipOne<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Saute l'élément à ignorer quand on arrive dessus
+        if self.position == self.skip_index {
+            self.iter.next()?;
+            self.position += 1;
+        }
+        let item = self.iter.next()?;
+        self.position += 1;
+        Some(item)
+    }
+}
+
+/// Fonction pour parcourir un itérateur en ignorant l'élément d'indice `skip_index`
+pub fn skip_one<I: Iterator>(iter: I, skip_index: usize) -> SkipOne<I> {
+    SkipOne {
+        iter,
+        skip_index,
+        position: 0,
+    }
+}
+
+/// Fonction pour savoir si retirer un seul élément peut rendre la liste valide (le "Problem Dampener" du jour 2)
+/// Un seul tampon est alloué : il contient la liste privée d'un élément, et passer d'un indice retiré
+/// au suivant ne demande de modifier qu'une case
+pub fn is_safe_after_any_removal(slice: &[i32], is_safe: impl Fn(&[i32]) -> bool) -> bool {
+    if slice.is_empty() {
+        return false; // Impossible de retirer un élément d'une liste vide
+    }
+
+    // Commence avec la liste privée de son premier élément
+    let mut window = slice[1..].to_vec();
+    if is_safe(&window) {
+        return true;
+    }
+
+    // Remettre `slice[i]` à la place `i` revient à retirer l'élément `i + 1` à la place
+    for i in 0..window.len() {
+        window[i] = slice[i];
+        if is_safe(&window) {
+            return true;
+        }
+    }
+
+    false
+}
@@ -0,0 +1,119 @@
+// Importation des bibliothèques nécessaires
+use std::collections::HashMap; // Pour les séquences entre boutons et la mémoïsation
+
+/// Graphe d'un clavier : pour chaque bouton de départ et chaque bouton d'arrivée, toutes les plus
+/// courtes séquences de flèches qui y mènent, terminées par l'appui `A`
+pub type KeypadGraph = HashMap<char, HashMap<char, Vec<String>>>;
+
+/// Fonction pour précalculer toutes les plus courtes séquences entre deux boutons d'un clavier (jour 21)
+/// `layout` donne les lignes du clavier, de haut en bas ; un espace marque le trou au-dessus
+/// duquel le bras du robot ne doit jamais passer
+/// Par exemple `["789", "456", "123", " 0A"]` pour le pavé numérique et `[" ^A", "<v>"]` pour le pavé directionnel
+pub fn build_keypad_graph(layout: &[&str]) -> KeypadGraph {
+    // Position (ligne, colonne) de chaque bouton
+    let buttons: HashMap<char, (i64, i64)> = layout
+        .iter()
+        .enumerate()
+        .flat_map(|(row, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, c)| c != ' ')
+                .map(move |(col, c)| (c, (row as i64, col as i64)))
+        })
+        .collect();
+    let gap = layout.iter().enumerate().find_map(|(row, line)| {
+        line.chars()
+            .position(|c| c == ' ')
+            .map(|col| (row as i64, col as i64))
+    });
+
+    let mut graph = KeypadGraph::new();
+    for (&from, &start) in &buttons {
+        for (&to, &end) in &buttons {
+            let mut sequences = Vec::new();
+            collect_moves(start, end, gap, &mut String::new(), &mut sequences);
+            graph.entry(from).or_default().insert(to, sequences);
+        }
+    }
+    graph
+}
+
+/// Énumère récursivement les chemins les plus courts (qui ne s'éloignent jamais de la cible)
+/// de `pos` à `end` en évitant le trou, et ajoute chacun d'eux suivi de `A`
+fn collect_moves(
+    pos: (i64, i64),
+    end: (i64, i64),
+    gap: Option<(i64, i64)>,
+    path: &mut String,
+    out: &mut Vec<String>,
+) {
+    if Some(pos) == gap {
+        return; // Le bras passerait au-dessus du trou
+    }
+    if pos == end {
+        out.push(format!("{}A", path));
+        return;
+    }
+
+    // Seuls les déplacements qui rapprochent de la cible donnent des chemins les plus courts
+    let (row, col) = pos;
+    let moves = [
+        (end.0 < row, '^', (row - 1, col)),
+        (end.0 > row, 'v', (row + 1, col)),
+        (end.1 < col, '<', (row, col - 1)),
+        (end.1 > col, '>', (row, col + 1)),
+    ];
+    for (useful, arrow, next) in moves {
+        if useful {
+            path.push(arrow);
+            collect_moves(next, end, gap, path, out);
+            path.pop();
+        }
+    }
+}
+
+/// Fonction pour calculer le nombre minimal d'appuis humains pour que le premier robot tape `sequence`
+/// sur le clavier `graphs[0]`
+/// `depth` est le nombre de robots intermédiaires, chacun devant un pavé directionnel ; les couches
+/// successives utilisent `graphs[1]`, `graphs[2]`, ... et la dernière est réutilisée pour les suivantes
+/// (pour le jour 21 : `[numérique, directionnel]`, avec `depth = 2` puis `depth = 25`)
+/// Le cache `memo` est indexé par `(séquence, profondeur)` : il ne doit servir qu'avec les mêmes `graphs`
+pub fn min_presses_at_depth(
+    sequence: &str,
+    depth: u32,
+    graphs: &[KeypadGraph],
+    memo: &mut HashMap<(String, u32), u64>,
+) -> u64 {
+    if let Some(&presses) = memo.get(&(sequence.to_string(), depth)) {
+        return presses;
+    }
+
+    let graph = &graphs[0];
+    let next_graphs = if graphs.len() > 1 {
+        &graphs[1..]
+    } else {
+        graphs
+    };
+
+    // Chaque robot commence sur `A` et y revient après chaque appui
+    let mut presses = 0;
+    let mut current = 'A';
+    for target in sequence.chars() {
+        let candidates = &graph[&current][&target];
+        presses += candidates
+            .iter()
+            .map(|moves| {
+                if depth == 0 {
+                    moves.len() as u64 // L'humain tape directement cette séquence
+                } else {
+                    min_presses_at_depth(moves, depth - 1, next_graphs, memo)
+                }
+            })
+            .min()
+            .unwrap_or(0);
+        current = target;
+    }
+
+    memo.insert((sequence.to_string(), depth), presses);
+    presses
+}
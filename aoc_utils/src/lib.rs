@@ -0,0 +1,46 @@
+//! Utilitaires partagés entre les différents jours de l'Advent of Code 2024
+
+pub mod algo; // Algorithmes génériques (détection de cycles, mémoïsation, ...)
+pub mod answer; // Réponses des puzzles (nombre, texte ou grille de lettres)
+pub mod bits; // Manipulation de bits
+pub mod cache; // Caches bornés pour la mémoïsation
+pub mod checked; // Arithmétique qui signale les dépassements de capacité
+pub mod circuit; // Circuits de portes logiques (jour 24)
+pub mod claw; // Machines à pince (jour 13)
+pub mod counter; // Comptage des occurrences
+pub mod deque; // Files à double extrémité
+pub mod direction; // Directions cardinales
+pub mod disk; // Carte du disque et défragmentation (jour 9)
+pub mod error; // Type d'erreur commun
+pub mod generate; // Génération d'entrées synthétiques
+pub mod graph; // Lecture et construction de graphes
+pub mod grid; // Grille rectangulaire générique
+pub mod grid_path; // Chemins dans une grille avec leur coût
+pub mod hash; // Fonctions de hachage des puzzles
+pub mod infinite_grid; // Grille infinie avec valeur par défaut
+pub mod input; // Provenance des entrées (fichier, entrée standard ou texte)
+pub mod interval; // Intervalles d'entiers
+pub mod iter; // Adaptateurs d'itérateurs
+pub mod keypad; // Claviers pilotés par des robots en cascade (jour 21)
+pub mod locks; // Serrures et clés (jour 25)
+pub mod lookup; // Tables de correspondance indexées par de petits entiers
+pub mod math; // Arithmétique modulaire et algèbre linéaire
+pub mod optimize; // Optimisation approchée ou exacte
+pub mod parse; // Fonctions d'analyse des entrées des puzzles
+pub mod point; // Points et vecteurs en deux dimensions
+#[cfg(feature = "progress")]
+pub mod progress; // Barres de progression pour les itérations longues
+pub mod region; // Régions de grille et clôtures
+pub mod robot_grid; // Robot qui avance et tourne sur une grille (jours 6 et 15)
+pub mod robots; // Robots se déplaçant sur une grille torique (jour 14)
+pub mod search; // Algorithmes de recherche dans un espace d'états
+pub mod secrets; // Nombres secrets et prix des bananes (jour 22)
+pub mod select; // Sélection du k-ième élément
+pub mod solution; // Interface commune aux solutions de chaque jour
+pub mod tiling; // Pavage d'une chaîne par des motifs (jour 19)
+pub mod trails; // Sentiers de randonnée sur une carte topographique (jour 10)
+pub mod vec_grid; // Grille stockée ligne par ligne
+#[cfg(feature = "viz")]
+pub mod visualize; // Animations des solutions dans le terminal
+pub mod warehouse; // Poussée de caisses dans l'entrepôt (jour 15)
+pub mod word_search; // Recherche de mots dans une grille de lettres (jour 4)
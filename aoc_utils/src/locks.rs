@@ -0,0 +1,71 @@
+// Importation des bibliothèques nécessaires
+use crate::error::AocError; // Pour signaler un schéma mal formé
+use crate::grid::Grid; // Le schéma d'une serrure ou d'une clé
+use crate::point::Point2D; // Pour parcourir les colonnes du schéma
+
+/// Nombre de colonnes d'un schéma
+const COLUMNS: usize = 5;
+/// Nombre de lignes d'un schéma (la ligne pleine, cinq lignes utiles et la ligne vide)
+const ROWS: usize = 7;
+/// Hauteur disponible dans une colonne, une fois retirées la ligne pleine et la ligne vide
+const MAX_HEIGHT: u8 = 5;
+
+/// Fonction pour compter, dans chaque colonne, les `#` contigus partant du haut (`from_top`) ou du bas,
+/// sans compter la ligne pleine qui sert de base
+pub fn column_heights(grid: &Grid<char>, from_top: bool) -> [u8; 5] {
+    let mut heights = [0; COLUMNS];
+    for (col, height) in heights.iter_mut().enumerate() {
+        // Lignes parcourues depuis la base de la serrure (en haut) ou de la clé (en bas)
+        let rows: Vec<usize> = if from_top {
+            (0..grid.height()).collect()
+        } else {
+            (0..grid.height()).rev().collect()
+        };
+        let filled = rows
+            .into_iter()
+            .take_while(|&row| grid.get(Point2D::new(row as i64, col as i64)) == Some(&'#'))
+            .count();
+        *height = filled.saturating_sub(1) as u8;
+    }
+    heights
+}
+
+/// Fonction pour lire le schéma d'une serrure ou d'une clé (jour 25)
+/// Retourne la hauteur de chaque colonne et `true` s'il s'agit d'une serrure (ligne du haut pleine),
+/// `false` pour une clé (ligne du bas pleine)
+pub fn parse_lock_or_key(schematic: &str) -> Result<([u8; 5], bool), AocError> {
+    let lines: Vec<&str> = schematic
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect();
+    if lines.len() != ROWS || lines.iter().any(|line| line.chars().count() != COLUMNS) {
+        return Err(AocError::Format(format!(
+            "un schéma doit faire {} lignes de {} caractères",
+            ROWS, COLUMNS
+        )));
+    }
+
+    let grid = Grid::parse(&lines.join("\n"), |c| c);
+    let is_lock = lines[0] == "#####";
+    if !is_lock && lines[ROWS - 1] != "#####" {
+        return Err(AocError::Format(
+            "ni la première ni la dernière ligne du schéma n'est pleine".to_string(),
+        ));
+    }
+    Ok((column_heights(&grid, is_lock), is_lock))
+}
+
+/// Fonction pour savoir si une clé entre dans une serrure : dans aucune colonne, les deux
+/// hauteurs réunies ne dépassent l'espace disponible
+pub fn fits(lock: &[u8; 5], key: &[u8; 5]) -> bool {
+    lock.iter().zip(key).all(|(l, k)| l + k <= MAX_HEIGHT)
+}
+
+/// Fonction pour compter les couples (serrure, clé) compatibles (jour 25)
+pub fn batch_fit_count(locks: &[[u8; 5]], keys: &[[u8; 5]]) -> usize {
+    locks
+        .iter()
+        .map(|lock| keys.iter().filter(|key| fits(lock, key)).count())
+        .sum()
+}
@@ -0,0 +1,67 @@
+/// Table de correspondance indexée par de petits entiers, stockée dans un simple vecteur
+/// Remplace avantageusement une `HashMap` quand les clés sont des codes ASCII ou de petits identifiants
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LookupTable<V> {
+    values: Vec<V>, // Une case par clé possible
+}
+
+impl<V: Clone> LookupTable<V> {
+    /// Crée une table de `size` cases, toutes initialisées à `default`
+    pub fn new(size: usize, default: V) -> Self {
+        LookupTable {
+            values: vec![default; size],
+        }
+    }
+
+    /// Nombre de clés possibles
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Indique si la table ne peut contenir aucune clé
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Retourne la valeur associée à la clé
+    /// Panique si la clé dépasse la taille de la table
+    pub fn get(&self, key: impl Into<usize>) -> &V {
+        &self.values[key.into()]
+    }
+
+    /// Associe une valeur à la clé
+    /// Panique si la clé dépasse la taille de la table
+    pub fn set(&mut self, key: impl Into<usize>, val: V) {
+        self.values[key.into()] = val;
+    }
+}
+
+/// Table de correspondance couvrant tous les caractères ASCII (`0u8..=127u8`)
+/// Pratique pour les comparaisons fréquentes, comme les règles d'ordre du jour 5
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharTable<V> {
+    table: LookupTable<V>, // 128 cases, une par caractère ASCII
+}
+
+impl<V: Clone> CharTable<V> {
+    /// Crée une table dont tous les caractères sont associés à `default`
+    pub fn new(default: V) -> Self {
+        CharTable {
+            table: LookupTable::new(128, default),
+        }
+    }
+
+    /// Retourne la valeur associée au caractère ASCII
+    /// Panique si l'octet n'est pas un caractère ASCII
+    pub fn get(&self, c: u8) -> &V {
+        assert!(c.is_ascii(), "caractère non ASCII : {}", c);
+        self.table.get(c)
+    }
+
+    /// Associe une valeur au caractère ASCII
+    /// Panique si l'octet n'est pas un caractère ASCII
+    pub fn set(&mut self, c: u8, val: V) {
+        assert!(c.is_ascii(), "caractère non ASCII : {}", c);
+        self.table.set(c, val);
+    }
+}
@@ -0,0 +1,230 @@
+/// Fonction pour construire la matrice identité de taille `size`
+pub fn mat_identity(size: usize) -> Vec<Vec<u64>> {
+    (0..size)
+        .map(|i| (0..size).map(|j| u64::from(i == j)).collect())
+        .collect()
+}
+
+/// Fonction pour multiplier deux matrices modulo `m`
+/// Les coefficients sont d'abord réduits modulo `m`, et chaque produit est calculé en `u128`
+/// pour éviter tout dépassement de capacité, quelles que soient les valeurs des coefficients
+pub fn mat_mul_mod(a: &[Vec<u64>], b: &[Vec<u64>], m: u64) -> Vec<Vec<u64>> {
+    let inner = b.len(); // Nombre de lignes de `b`, qui doit valoir le nombre de colonnes de `a`
+    let cols = b.first().map_or(0, Vec::len);
+
+    a.iter()
+        .map(|row| {
+            assert_eq!(row.len(), inner, "dimensions de matrices incompatibles");
+            (0..cols)
+                .map(|j| {
+                    // Somme des produits ligne × colonne, réduite modulo `m` à chaque étape
+                    (0..inner).fold(0u128, |acc, k| {
+                        let product = (row[k] % m) as u128 * (b[k][j] % m) as u128;
+                        (acc + product) % m as u128
+                    }) as u64
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Fonction pour élever une matrice carrée à la puissance `exp` modulo `m`
+/// Utilise l'exponentiation rapide (carrés successifs) : O(taille³ × log exp)
+pub fn mat_pow_mod(base: &[Vec<u64>], exp: u64, m: u64) -> Vec<Vec<u64>> {
+    let mut result = mat_identity(base.len());
+    let mut square = base.to_vec();
+    let mut exp = exp;
+
+    while exp > 0 {
+        // Si le bit courant de l'exposant est à 1, on multiplie le résultat par le carré courant
+        if exp & 1 == 1 {
+            result = mat_mul_mod(&result, &square, m);
+        }
+        square = mat_mul_mod(&square, &square, m);
+        exp >>= 1;
+    }
+
+    result
+}
+
+/// Fonction pour calculer le terme `n` d'une récurrence linéaire modulo `modulus`
+/// La récurrence est `a(n) = coefficients[0] * a(n-1) + coefficients[1] * a(n-2) + ...`
+/// et `initial` contient les premiers termes `a(0), a(1), ...`
+pub fn linear_recurrence_term(coefficients: &[u64], initial: &[u64], n: u64, modulus: u64) -> u64 {
+    let k = coefficients.len();
+    assert_eq!(
+        initial.len(),
+        k,
+        "il faut autant de termes initiaux que de coefficients"
+    );
+
+    // Les premiers termes sont donnés directement
+    if n < k as u64 {
+        return initial[n as usize] % modulus;
+    }
+
+    // Matrice compagnon : la première ligne applique la récurrence, les autres décalent l'état
+    let mut companion = vec![vec![0; k]; k];
+    companion[0] = coefficients.iter().map(|c| c % modulus).collect();
+    for i in 1..k {
+        companion[i][i - 1] = 1;
+    }
+
+    // L'état initial est `[a(k-1), ..., a(1), a(0)]` et `companion^n` le fait avancer de `n` termes
+    let power = mat_pow_mod(&companion, n, modulus);
+    // `a(n)` est la dernière composante de l'état obtenu
+    power[k - 1]
+        .iter()
+        .zip(initial.iter().rev())
+        .fold(0u128, |acc, (&p, &a)| {
+            (acc + p as u128 * (a % modulus) as u128) % modulus as u128
+        }) as u64
+}
+
+/// Fonction pour calculer le `n`-ième nombre de Fibonacci modulo `modulus`
+pub fn fibonacci_mod(n: u64, modulus: u64) -> u64 {
+    linear_recurrence_term(&[1, 1], &[0, 1], n, modulus)
+}
+
+/// Nombre rationnel exact sous forme `(numérateur, dénominateur)`, le dénominateur étant toujours positif
+pub type Rational = (i64, i64);
+
+/// Fonction pour calculer le plus grand commun diviseur de deux entiers
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Fonction pour réduire une fraction et la convertir en `Rational`
+/// Retourne `None` si le résultat ne tient pas dans un `i64`
+fn reduce(numerator: i128, denominator: i128) -> Option<Rational> {
+    let g = gcd(numerator, denominator);
+    // Le signe est porté par le numérateur
+    let sign = if denominator < 0 { -1 } else { 1 };
+    let n = i64::try_from(sign * numerator / g).ok()?;
+    let d = i64::try_from(sign * denominator / g).ok()?;
+    Some((n, d))
+}
+
+/// Fonction pour résoudre `a*x + b*y = p` et `c*x + d*y = q` en entiers naturels (cœur du jour 13)
+/// Utilise la règle de Cramer avec des calculs intermédiaires en `i128` pour éviter les dépassements
+/// Retourne `None` si le déterminant est nul ou si la solution n'est pas faite d'entiers positifs ou nuls
+pub fn solve_linear_system_2x2(
+    a: i64,
+    b: i64,
+    c: i64,
+    d: i64,
+    p: i64,
+    q: i64,
+) -> Option<(i64, i64)> {
+    let (a, b, c, d, p, q) = (
+        a as i128, b as i128, c as i128, d as i128, p as i128, q as i128,
+    );
+
+    let det = a * d - b * c;
+    if det == 0 {
+        return None; // Système dégénéré : aucune solution unique
+    }
+
+    // Numérateurs de Cramer
+    let x_num = p * d - b * q;
+    let y_num = a * q - p * c;
+    // La solution doit être entière
+    if x_num % det != 0 || y_num % det != 0 {
+        return None;
+    }
+
+    let (x, y) = (x_num / det, y_num / det);
+    if x < 0 || y < 0 {
+        return None; // On ne peut pas appuyer un nombre négatif de fois sur un bouton
+    }
+    Some((i64::try_from(x).ok()?, i64::try_from(y).ok()?))
+}
+
+/// Fonction pour résoudre le même système en nombres rationnels exacts
+/// Retourne `None` uniquement si le déterminant est nul (ou si le résultat dépasse un `i64`)
+pub fn solve_linear_system_2x2_rational(
+    a: i64,
+    b: i64,
+    c: i64,
+    d: i64,
+    p: i64,
+    q: i64,
+) -> Option<(Rational, Rational)> {
+    let (a, b, c, d, p, q) = (
+        a as i128, b as i128, c as i128, d as i128, p as i128, q as i128,
+    );
+
+    let det = a * d - b * c;
+    if det == 0 {
+        return None;
+    }
+
+    Some((reduce(p * d - b * q, det)?, reduce(a * q - p * c, det)?))
+}
+
+/// Fonction pour compter les sous-ensembles de `values` dont la somme vaut `target`
+/// Programmation dynamique classique : `ways[s]` est le nombre de sous-ensembles de somme `s`
+/// Les éléments égaux sont distingués par leur position (`[1, 1]` a deux sous-ensembles de somme 1)
+/// Le sous-ensemble vide compte pour `target == 0`
+/// Un nombre de sous-ensembles qui dépasse `u64::MAX` est ramené à `u64::MAX` ; le résultat n'est
+/// exact qu'en dessous, sinon il faut utiliser `count_subsets_summing_to_mod`
+pub fn count_subsets_summing_to(values: &[u64], target: u64) -> u64 {
+    let target = target as usize;
+    let mut ways = vec![0u64; target + 1];
+    ways[0] = 1;
+    for &value in values {
+        let value = value as usize;
+        // Parcours à l'envers pour n'utiliser chaque élément qu'une fois
+        for sum in (value..=target).rev() {
+            ways[sum] = ways[sum].saturating_add(ways[sum - value]);
+        }
+    }
+    ways[target]
+}
+
+/// Variante de `count_subsets_summing_to` qui calcule le nombre de sous-ensembles modulo `modulus`,
+/// pour quand ce nombre dépasse la capacité d'un `u64`
+pub fn count_subsets_summing_to_mod(values: &[u64], target: u64, modulus: u64) -> u64 {
+    let target = target as usize;
+    let mut ways = vec![0u64; target + 1];
+    ways[0] = 1 % modulus;
+    for &value in values {
+        let value = value as usize;
+        for sum in (value..=target).rev() {
+            ways[sum] = ((ways[sum] as u128 + ways[sum - value] as u128) % modulus as u128) as u64;
+        }
+    }
+    ways[target]
+}
+
+/// Fonction pour savoir si un sous-ensemble de `values` a pour somme `target`
+/// Les sommes atteignables sont stockées dans un ensemble de bits : ajouter un élément revient
+/// à faire un `OU` de l'ensemble avec lui-même décalé de la valeur de l'élément
+pub fn can_subset_sum(values: &[u64], target: u64) -> bool {
+    let target = target as usize;
+    let words = target / 64 + 1;
+    let mut reachable = vec![0u64; words];
+    reachable[0] = 1; // La somme 0 est toujours atteignable
+
+    for &value in values {
+        let value = value as usize;
+        if value > target {
+            continue; // Ne peut faire partie d'aucune solution
+        }
+        let (word_shift, bit_shift) = (value / 64, value % 64);
+        // Décalage de tout l'ensemble de `value` bits, en partant des mots de poids fort
+        for i in (word_shift..words).rev() {
+            let mut shifted = reachable[i - word_shift] << bit_shift;
+            if bit_shift > 0 && i > word_shift {
+                shifted |= reachable[i - word_shift - 1] >> (64 - bit_shift);
+            }
+            reachable[i] |= shifted;
+        }
+    }
+
+    reachable[target / 64] >> (target % 64) & 1 == 1
+}
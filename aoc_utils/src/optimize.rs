@@ -0,0 +1,121 @@
+/// Recuit simulé (simulated annealing) pour minimiser approximativement une fonction d'énergie
+/// `neighbor` reçoit l'état courant et la température, pour pouvoir perturber davantage quand il fait chaud
+/// La température décroît géométriquement de `initial_temp` à `final_temp` sur `max_iterations` itérations
+/// Retourne le meilleur état rencontré (l'énergie la plus basse)
+pub fn simulated_annealing<S, FN, EN>(
+    initial: S,
+    neighbor: FN,
+    energy: EN,
+    max_iterations: u64,
+    initial_temp: f64,
+    final_temp: f64,
+) -> S
+where
+    S: Clone,
+    FN: Fn(&S, f64) -> S, // Génère un état voisin à la température donnée
+    EN: Fn(&S) -> f64,    // Énergie à minimiser
+{
+    let mut rng = fastrand::Rng::new();
+
+    // Facteur de refroidissement appliqué à chaque itération
+    let cooling = if max_iterations > 0 {
+        (final_temp / initial_temp).powf(1.0 / max_iterations as f64)
+    } else {
+        1.0
+    };
+
+    let mut current_energy = energy(&initial);
+    let mut current = initial;
+    let mut best = current.clone();
+    let mut best_energy = current_energy;
+    let mut temperature = initial_temp;
+
+    for _ in 0..max_iterations {
+        let candidate = neighbor(&current, temperature);
+        let candidate_energy = energy(&candidate);
+        let delta = candidate_energy - current_energy;
+
+        // Critère de Metropolis : on accepte toujours une amélioration,
+        // et une dégradation avec une probabilité `e^(-delta / T)`
+        if delta <= 0.0 || rng.f64() < (-delta / temperature).exp() {
+            current = candidate;
+            current_energy = candidate_energy;
+            // Mémorise le meilleur état rencontré jusqu'ici
+            if current_energy < best_energy {
+                best = current.clone();
+                best_energy = current_energy;
+            }
+        }
+
+        temperature *= cooling;
+    }
+
+    best
+}
+
+/// Séparation et évaluation (branch and bound) pour minimiser un coût de façon exacte
+/// - `branch` génère les enfants d'un état
+/// - `bound` donne une borne inférieure du coût de tous les états terminaux du sous-arbre ;
+///   pour un état terminal, c'est son coût exact
+/// - `is_terminal` reconnaît les feuilles (solutions complètes)
+///
+/// Le parcours est en profondeur et élague tout sous-arbre dont la borne ne peut pas
+/// améliorer la meilleure solution trouvée jusqu'ici
+/// Pour maximiser un gain, il suffit de minimiser son opposé
+pub struct BranchAndBound<S, FN, BN, EN> {
+    initial: S,             // État de départ (la racine de l'arbre de recherche)
+    branch: FN,             // Génère les enfants d'un état
+    bound: BN,              // Borne inférieure du coût d'un sous-arbre
+    is_terminal: EN,        // Reconnaît les solutions complètes
+    best: Option<(S, i64)>, // Meilleure solution trouvée jusqu'ici, avec son coût
+}
+
+impl<S, FN, BN, EN> BranchAndBound<S, FN, BN, EN>
+where
+    S: Clone,
+    FN: Fn(&S) -> Vec<S>,
+    BN: Fn(&S) -> i64,
+    EN: Fn(&S) -> bool,
+{
+    /// Prépare le solveur à partir de l'état initial et des trois fonctions du problème
+    pub fn new(initial: S, branch: FN, bound: BN, is_terminal: EN) -> Self {
+        BranchAndBound {
+            initial,
+            branch,
+            bound,
+            is_terminal,
+            best: None,
+        }
+    }
+
+    /// Cherche l'état terminal de coût minimal
+    /// Retourne cet état et son coût, ou `None` si aucun état terminal n'est atteignable
+    pub fn solve(&mut self) -> Option<(S, i64)> {
+        self.best = None;
+        let mut stack = vec![self.initial.clone()];
+
+        while let Some(state) = stack.pop() {
+            let bound = (self.bound)(&state);
+            // Élagage : ce sous-arbre ne peut pas faire mieux que la meilleure solution connue
+            if self.best.as_ref().is_some_and(|(_, best)| bound >= *best) {
+                continue;
+            }
+
+            if (self.is_terminal)(&state) {
+                self.best = Some((state, bound)); // Meilleure solution jusqu'ici
+                continue;
+            }
+
+            // Explore d'abord les enfants les plus prometteurs (borne la plus basse),
+            // pour trouver vite une bonne solution et élaguer davantage ensuite
+            let mut children: Vec<(i64, S)> = (self.branch)(&state)
+                .into_iter()
+                .map(|child| ((self.bound)(&child), child))
+                .collect();
+            children.sort_by_key(|(bound, _)| std::cmp::Reverse(*bound));
+            stack.extend(children.into_iter().map(|(_, child)| child));
+        }
+
+        self.best.clone()
+    }
+}
@@ -0,0 +1,367 @@
+// Importation des bibliothèques nécessaires
+use crate::direction::Direction; // Pour convertir les flèches en directions
+use crate::error::AocError; // Pour signaler une entrée mal formée
+use crate::grid::Grid; // Pour la section grille des entrées en deux parties
+use std::collections::HashMap; // Pour compter les éléments de l'état initial
+use std::fs; // Pour lire les fichiers d'entrée
+use std::hash::Hash; // Pour exiger des clés hachables
+use std::io; // Pour reconnaître un fichier absent
+use std::path::Path; // Pour le chemin du fichier d'entrée
+use std::rc::Rc; // Pour partager la fonction de conversion entre les lignes d'une grille
+use std::str::FromStr; // Pour convertir un morceau de texte en nombre de n'importe quel type
+
+/// Fonction pour découper une chaîne en suites de chiffres
+/// Chaque suite est précédée de son signe `-` s'il est présent juste avant le premier chiffre
+fn number_runs(s: &str) -> impl Iterator<Item = &str> {
+    let bytes = s.as_bytes();
+    let mut pos = 0;
+
+    std::iter::from_fn(move || {
+        // Avance jusqu'au prochain chiffre
+        while pos < bytes.len() && !bytes[pos].is_ascii_digit() {
+            pos += 1;
+        }
+        if pos == bytes.len() {
+            return None; // Plus aucun chiffre dans la chaîne
+        }
+
+        // Inclut le signe `-` s'il précède immédiatement le premier chiffre
+        let start = if pos > 0 && bytes[pos - 1] == b'-' {
+            pos - 1
+        } else {
+            pos
+        };
+        // Avance jusqu'à la fin de la suite de chiffres
+        while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+            pos += 1;
+        }
+
+        Some(&s[start..pos])
+    })
+}
+
+/// Fonction pour convertir une suite de chiffres (éventuellement signée) en nombre
+/// Pour un type non signé, le signe `-` est ignoré ; un dépassement de capacité donne `None`
+fn parse_run<T: FromStr>(run: &str) -> Option<T> {
+    run.parse::<T>().ok().or_else(|| {
+        run.strip_prefix('-')
+            .and_then(|digits| digits.parse::<T>().ok())
+    })
+}
+
+/// Fonction pour extraire tous les nombres d'une ligne comme `"Button A: X+94, Y+34"`
+/// Les nombres trop grands pour le type `T` sont simplement ignorés au lieu de provoquer une panique
+pub fn extract_numbers<T: FromStr>(s: &str) -> Vec<T> {
+    number_runs(s).filter_map(parse_run).collect()
+}
+
+/// Fonction pour extraire uniquement le premier nombre d'une ligne, sans rien collecter
+pub fn extract_first_number<T: FromStr>(s: &str) -> Option<T> {
+    number_runs(s).find_map(parse_run)
+}
+
+/// Fonction pour découper une ligne de structure fixe à l'aide d'un modèle contenant des `{}`
+/// Le texte littéral du modèle doit correspondre exactement, et chaque `{}` capture le texte à sa place
+/// Par exemple `parse_template("Button A: X+94, Y+34", "Button A: X+{}, Y+{}") == Some(vec!["94", "34"])`
+/// Retourne `None` si les parties littérales ne correspondent pas
+pub fn parse_template<'a>(line: &'a str, template: &str) -> Option<Vec<&'a str>> {
+    // Les morceaux littéraux entre les `{}` : il y en a un de plus que de `{}`
+    let literals: Vec<&str> = template.split("{}").collect();
+    let (first, rest_literals) = literals.split_first()?;
+
+    // Le début de la ligne doit correspondre au premier littéral
+    let mut rest = line.strip_prefix(first)?;
+    let mut captures = Vec::with_capacity(rest_literals.len());
+
+    for (i, literal) in rest_literals.iter().enumerate() {
+        if i == rest_literals.len() - 1 {
+            // Dernier `{}` : la capture s'étend jusqu'au littéral final, qui doit terminer la ligne
+            captures.push(rest.strip_suffix(literal)?);
+            rest = "";
+        } else {
+            // `{}` intermédiaire : la capture s'arrête à la première occurrence du littéral suivant
+            let end = rest.find(literal)?;
+            captures.push(&rest[..end]);
+            rest = &rest[end + literal.len()..];
+        }
+    }
+
+    // Sans `{}`, la ligne doit être exactement égale au modèle
+    if rest_literals.is_empty() && !rest.is_empty() {
+        return None;
+    }
+
+    Some(captures)
+}
+
+/// Fonction pour parcourir une grille de caractères sans la construire, case par case
+/// Produit les triplets `(ligne, colonne, valeur)` ligne par ligne, de façon paresseuse
+/// Les lignes vides sont ignorées (comme dans `Grid::parse`)
+pub fn parse_grid_enumerate<'a, T>(
+    input: &'a str,
+    cell_fn: impl Fn(char) -> T + 'a,
+) -> impl Iterator<Item = (usize, usize, T)> + 'a {
+    parse_grid_filter_map(input, move |c| Some(cell_fn(c)))
+}
+
+/// Fonction pour parcourir une grille en ne gardant que les cases pour lesquelles `cell_fn` retourne `Some`
+/// Pratique pour ne récupérer que certaines cases (départs, arrivées, ...) sans construire la grille
+pub fn parse_grid_filter_map<'a, T>(
+    input: &'a str,
+    cell_fn: impl Fn(char) -> Option<T> + 'a,
+) -> impl Iterator<Item = (usize, usize, T)> + 'a {
+    // Partagée entre les itérateurs de chaque ligne
+    let cell_fn = Rc::new(cell_fn);
+    input
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+        .enumerate()
+        .flat_map(move |(row, line)| {
+            let cell_fn = Rc::clone(&cell_fn);
+            line.chars()
+                .enumerate()
+                .filter_map(move |(col, c)| cell_fn(c).map(|value| (row, col, value)))
+        })
+}
+
+/// Fonction pour lire une entrée en deux sections séparées par une ligne vide :
+/// une grille de caractères, puis une suite de commandes (éventuellement sur plusieurs lignes)
+/// Les retours à la ligne de la section des commandes sont ignorés
+pub fn read_grid_and_commands(input: &str) -> Result<(Grid<char>, Vec<char>), AocError> {
+    let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+
+    // La première ligne vide après la grille sépare les deux sections
+    let first = lines
+        .iter()
+        .position(|line| !line.is_empty())
+        .unwrap_or(lines.len());
+    let separator = lines[first..]
+        .iter()
+        .position(|line| line.is_empty())
+        .map(|i| first + i)
+        .ok_or_else(|| {
+            AocError::Format("ligne vide séparant la grille des commandes introuvable".to_string())
+        })?;
+
+    // Vérifie que la grille est rectangulaire avant de la construire
+    let grid_lines = &lines[first..separator];
+    let width = grid_lines[0].chars().count();
+    if let Some(row) = grid_lines
+        .iter()
+        .position(|line| line.chars().count() != width)
+    {
+        return Err(AocError::parse(
+            first + row,
+            grid_lines[row],
+            format!(
+                "ligne de grille de {} caractères au lieu de {}",
+                grid_lines[row].chars().count(),
+                width
+            ),
+        ));
+    }
+    let grid = Grid::parse(&grid_lines.join("\n"), |c| c);
+
+    // Recolle toutes les lignes de commandes en une seule suite
+    let commands = lines[separator + 1..]
+        .iter()
+        .flat_map(|line| line.chars())
+        .filter(|c| !c.is_whitespace())
+        .collect();
+
+    Ok((grid, commands))
+}
+
+/// Fonction pour lire une grille suivie d'une suite de flèches `^`, `>`, `v`, `<` (jour 15)
+/// Retourne une erreur si un caractère de la section des commandes n'est pas une flèche
+pub fn read_grid_and_directions(input: &str) -> Result<(Grid<char>, Vec<Direction>), AocError> {
+    let (grid, commands) = read_grid_and_commands(input)?;
+    let directions = commands
+        .into_iter()
+        .map(|c| {
+            Direction::from_arrow(c)
+                .ok_or_else(|| AocError::Format(format!("direction inconnue : {:?}", c)))
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((grid, directions))
+}
+
+/// Fonction pour lire une entrée composée d'un état initial suivi de règles, une par ligne
+/// L'état initial est la première section (jusqu'à la première ligne vide, ou la première ligne
+/// s'il n'y a pas de ligne vide) ; chaque ligne non vide restante est une règle
+/// C'est à l'appelant de fournir la façon de lire l'état et les règles
+pub fn parse_initial_and_rules<S, R>(
+    input: &str,
+    state_parser: impl Fn(&str) -> S,
+    rule_parser: impl Fn(&str) -> R,
+) -> Result<(S, Vec<R>), AocError> {
+    let input = input.trim_start_matches(['\n', '\r']);
+    if input.trim().is_empty() {
+        return Err(AocError::Format(
+            "entrée vide : état initial manquant".to_string(),
+        ));
+    }
+
+    // Coupe au niveau de la première ligne vide, sinon après la première ligne
+    let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+    let state_len = lines.iter().position(|line| line.is_empty()).unwrap_or(1);
+    let state = state_parser(&lines[..state_len].join("\n"));
+
+    let rules = lines[state_len..]
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| rule_parser(line))
+        .collect();
+
+    Ok((state, rules))
+}
+
+/// Fonction pour lire la première ligne comme une liste de clés séparées par des espaces,
+/// chacune comptée une fois (une clé répétée est comptée autant de fois qu'elle apparaît)
+/// Pratique pour le jour 11, où seul le nombre de pierres portant chaque numéro importe
+pub fn parse_initial_counts<K: FromStr + Hash + Eq>(
+    input: &str,
+) -> Result<HashMap<K, u64>, AocError> {
+    let (index, line) = input
+        .lines()
+        .enumerate()
+        .find(|(_, line)| !line.trim().is_empty())
+        .ok_or_else(|| AocError::Format("entrée vide : aucune clé initiale".to_string()))?;
+
+    let mut counts = HashMap::new();
+    for word in line.split_whitespace() {
+        let key = word.parse().map_err(|_| {
+            AocError::parse(index, line, format!("clé initiale invalide {:?}", word))
+        })?;
+        *counts.entry(key).or_default() += 1;
+    }
+    Ok(counts)
+}
+
+/// Fonction pour lire des colonnes de nombres : chaque ligne non vide doit contenir exactement
+/// `n` valeurs séparées par des espaces, et la i-ème valeur de chaque ligne va dans la i-ème colonne
+/// Par exemple, l'entrée du jour 1 donne deux colonnes avec `parse_columns::<i64>(input, 2)`
+/// Retourne une erreur indiquant la ligne fautive au lieu d'ignorer les lignes mal formées
+pub fn parse_columns<T: FromStr>(input: &str, n: usize) -> Result<Vec<Vec<T>>, AocError> {
+    let mut columns: Vec<Vec<T>> = (0..n).map(|_| Vec::new()).collect();
+    for (index, line) in input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+    {
+        let values: Vec<&str> = line.split_whitespace().collect();
+        if values.len() != n {
+            return Err(AocError::parse(
+                index,
+                line,
+                format!("{} valeurs au lieu de {}", values.len(), n),
+            ));
+        }
+        for (column, value) in columns.iter_mut().zip(values) {
+            let parsed = value.parse().map_err(|_| {
+                AocError::parse(index, line, format!("valeur invalide {:?}", value))
+            })?;
+            column.push(parsed);
+        }
+    }
+    Ok(columns)
+}
+
+/// Fonction pour lire une grille dont chaque caractère est converti avec `FromStr`
+/// (par exemple `Grid<u8>` pour une carte de chiffres, ou `Grid<char>`)
+/// Les lignes vides sont ignorées ; une erreur est retournée pour un caractère invalide
+/// ou une grille qui n'est pas rectangulaire
+pub fn parse_grid<T: FromStr>(input: &str) -> Result<Grid<T>, AocError> {
+    let mut cells = Vec::new();
+    let mut width = None;
+    let mut height = 0;
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line.trim_end();
+        if line.is_empty() {
+            continue;
+        }
+        // La première ligne fixe la largeur
+        let row_len = line.chars().count();
+        if *width.get_or_insert(row_len) != row_len {
+            return Err(AocError::parse(
+                index,
+                line,
+                format!(
+                    "la grille n'est pas rectangulaire ({} caractères au lieu de {})",
+                    row_len,
+                    width.unwrap_or_default()
+                ),
+            ));
+        }
+        for (col, c) in line.chars().enumerate() {
+            let cell = c.to_string().parse().map_err(|_| {
+                AocError::parse(
+                    index,
+                    line,
+                    format!("colonne {} : caractère invalide {:?}", col + 1, c),
+                )
+            })?;
+            cells.push(cell);
+        }
+        height += 1;
+    }
+
+    Ok(Grid::from_vec(width.unwrap_or(0), height, cells))
+}
+
+/// Fonction pour découper l'entrée en sections séparées par une ou plusieurs lignes vides
+/// Les sections ne contiennent ni la ligne vide ni le retour à la ligne final
+pub fn parse_blocks(input: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for line in input.lines().map(str::trim_end) {
+        if line.is_empty() {
+            // Fin d'une section (les lignes vides consécutives ne créent pas de section vide)
+            if !current.is_empty() {
+                blocks.push(current.join("\n"));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current.join("\n"));
+    }
+    blocks
+}
+
+/// Fonction pour lire tous les entiers signés de l'entrée, séparés par des espaces, des virgules
+/// ou des retours à la ligne
+/// Contrairement à `extract_numbers`, tout autre texte est une erreur (avec le numéro de ligne)
+pub fn parse_signed_ints(input: &str) -> Result<Vec<i64>, AocError> {
+    let mut numbers = Vec::new();
+    for (index, line) in input.lines().enumerate() {
+        let tokens = line
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|token| !token.is_empty());
+        for token in tokens {
+            let number = token.parse().map_err(|_| {
+                AocError::parse(index, line, format!("entier invalide {:?}", token))
+            })?;
+            numbers.push(number);
+        }
+    }
+    Ok(numbers)
+}
+
+/// Fonction pour lire un fichier d'entrée en entier
+/// Un fichier absent donne `AocError::MissingInput` (avec `day`, pour suggérer `aoc2024 fetch`),
+/// toute autre erreur de lecture donne `AocError::Io`
+pub fn read_input(path: impl AsRef<Path>, day: Option<u8>) -> Result<String, AocError> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => AocError::MissingInput {
+            path: path.to_path_buf(),
+            day,
+        },
+        _ => AocError::Io(e),
+    })
+}
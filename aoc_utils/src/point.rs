@@ -0,0 +1,81 @@
+// Importation des bibliothèques nécessaires
+use std::ops::{Add, Mul, Sub}; // Pour les opérations arithmétiques sur les points
+
+/// Point (ou vecteur) en deux dimensions
+/// Dans une grille, `x` désigne la ligne et `y` la colonne
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2D<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2D<T> {
+    /// Crée un nouveau point à partir de ses deux coordonnées
+    pub const fn new(x: T, y: T) -> Self {
+        Point2D { x, y }
+    }
+}
+
+impl Point2D<i64> {
+    /// Ramène le point dans le rectangle `[0, width[ x [0, height[` en enroulant chaque coordonnée
+    /// (`x` modulo `width`, `y` modulo `height`), y compris pour des coordonnées négatives
+    pub fn rem_euclid(self, width: i64, height: i64) -> Self {
+        Point2D::new(self.x.rem_euclid(width), self.y.rem_euclid(height))
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point2D<T> {
+    type Output = Self;
+
+    /// Additionne les deux points coordonnée par coordonnée
+    fn add(self, other: Self) -> Self {
+        Point2D::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point2D<T> {
+    type Output = Self;
+
+    /// Soustrait les deux points coordonnée par coordonnée
+    fn sub(self, other: Self) -> Self {
+        Point2D::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point2D<T> {
+    type Output = Self;
+
+    /// Multiplie les deux coordonnées par un même scalaire
+    fn mul(self, factor: T) -> Self {
+        Point2D::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// Coordonnées cartésiennes de l'énoncé : `x` est la colonne (vers la droite) et `y` la ligne (vers le bas)
+/// Convention des jours où l'énoncé donne des positions `X=..., Y=...` ou `p=x,y` (jours 13 et 14, ainsi que
+/// les octets du jour 18)
+pub type XY = Point2D<i64>;
+
+/// Coordonnées d'écran d'une grille : `x` est la ligne (vers le bas) et `y` la colonne (vers la droite)
+/// Convention de `Grid` et de tous les jours qui lisent une carte caractère par caractère
+/// (jours 4, 6, 8, 10, 12, 15, 16, 20, ...)
+pub type RowCol = Point2D<i64>;
+
+/// Un pas vers le haut de l'écran (ligne précédente)
+pub const SCREEN_NORTH: RowCol = RowCol { x: -1, y: 0 };
+/// Un pas vers le bas de l'écran (ligne suivante)
+pub const SCREEN_SOUTH: RowCol = RowCol { x: 1, y: 0 };
+/// Un pas vers la droite de l'écran (colonne suivante)
+pub const SCREEN_EAST: RowCol = RowCol { x: 0, y: 1 };
+/// Un pas vers la gauche de l'écran (colonne précédente)
+pub const SCREEN_WEST: RowCol = RowCol { x: 0, y: -1 };
+
+/// Fonction pour convertir des coordonnées de l'énoncé en coordonnées de grille (échange des deux axes)
+pub fn xy_to_rowcol(p: XY) -> RowCol {
+    RowCol { x: p.y, y: p.x }
+}
+
+/// Fonction pour convertir des coordonnées de grille en coordonnées de l'énoncé (échange des deux axes)
+pub fn rowcol_to_xy(p: RowCol) -> XY {
+    XY { x: p.y, y: p.x }
+}
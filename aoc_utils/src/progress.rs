@@ -0,0 +1,75 @@
+// Importation des bibliothèques nécessaires
+use indicatif::{ProgressBar, ProgressStyle}; // Pour dessiner la barre de progression sur stderr
+
+/// Modèle d'affichage d'une barre de longueur connue : pourcentage, ETA et éléments par seconde
+const BAR_TEMPLATE: &str = "{bar:40} {percent:>3}% {pos}/{len} [ETA {eta}] {per_sec}";
+/// Modèle d'affichage d'un indicateur de longueur inconnue
+const SPINNER_TEMPLATE: &str = "{spinner} {pos} éléments [{elapsed}] {per_sec}";
+
+/// Itérateur qui se comporte exactement comme l'itérateur enveloppé,
+/// tout en faisant avancer une barre de progression à chaque élément consommé
+pub struct ProgressIter<I: Iterator> {
+    iter: I,          // L'itérateur d'origine
+    bar: ProgressBar, // La barre de progression associée
+}
+
+impl<I: Iterator> ProgressIter<I> {
+    /// Crée une barre de progression pour un itérateur dont on connaît le nombre d'éléments
+    pub fn new(iter: I, len: u64) -> Self {
+        let bar = ProgressBar::new(len);
+        // Le modèle est une constante valide, l'erreur ne peut donc pas se produire
+        if let Ok(style) = ProgressStyle::with_template(BAR_TEMPLATE) {
+            bar.set_style(style);
+        }
+        ProgressIter { iter, bar }
+    }
+
+    /// Crée un indicateur animé pour un itérateur dont la longueur est inconnue
+    pub fn new_spinner(iter: I) -> Self {
+        let bar = ProgressBar::new_spinner();
+        if let Ok(style) = ProgressStyle::with_template(SPINNER_TEMPLATE) {
+            bar.set_style(style);
+        }
+        ProgressIter { iter, bar }
+    }
+}
+
+impl<I: Iterator> Iterator for ProgressIter<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        // On n'avance la barre que si un élément a réellement été produit
+        if item.is_some() {
+            self.bar.inc(1);
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint() // La barre ne change pas le nombre d'éléments
+    }
+}
+
+impl<I: Iterator> Drop for ProgressIter<I> {
+    /// Efface la barre du terminal, même si l'itérateur n'a pas été entièrement consommé
+    fn drop(&mut self) {
+        self.bar.finish_and_clear();
+    }
+}
+
+/// Extension permettant d'écrire `candidates.into_iter().progress(n)`
+pub trait ProgressIteratorExt: Iterator + Sized {
+    /// Enveloppe l'itérateur dans une barre de progression de longueur `len`
+    fn progress(self, len: u64) -> ProgressIter<Self> {
+        ProgressIter::new(self, len)
+    }
+
+    /// Enveloppe l'itérateur dans un indicateur animé (longueur inconnue)
+    fn progress_spinner(self) -> ProgressIter<Self> {
+        ProgressIter::new_spinner(self)
+    }
+}
+
+// Tous les itérateurs bénéficient automatiquement de l'extension
+impl<I: Iterator> ProgressIteratorExt for I {}
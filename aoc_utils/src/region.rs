@@ -0,0 +1,120 @@
+// Importation des bibliothèques nécessaires
+use crate::grid::Grid; // Pour découper une carte en régions
+use crate::point::Point2D; // Pour repérer les cases d'une région
+use std::collections::{HashMap, HashSet}; // Pour regrouper les bords de clôture
+
+/// Les quatre déplacements orthogonaux (ligne, colonne) : haut, bas, gauche, droite
+const OFFSETS: [Point2D<i64>; 4] = [
+    Point2D::new(-1, 0),
+    Point2D::new(1, 0),
+    Point2D::new(0, -1),
+    Point2D::new(0, 1),
+];
+
+/// Fonction pour compter les suites d'entiers consécutifs dans une liste triée
+/// Par exemple `[1, 2, 3, 5, 6]` contient deux suites : `1..=3` et `5..=6`
+pub fn count_runs(sorted_values: &[i64]) -> usize {
+    if sorted_values.is_empty() {
+        return 0;
+    }
+
+    // Une nouvelle suite commence à chaque "trou" entre deux valeurs voisines
+    1 + sorted_values
+        .windows(2)
+        .filter(|pair| pair[1] != pair[0] + 1)
+        .count()
+}
+
+/// Fonction pour compter les côtés de la clôture d'une région (jour 12, partie 2)
+/// Les bords exposés sont regroupés par orientation et par ligne (ou colonne) fixe,
+/// puis chaque suite de bords contigus forme un seul côté
+pub fn count_fence_sides(region: &HashSet<Point2D<i64>>) -> usize {
+    // Clé : (direction vers l'extérieur, coordonnée fixe) ; valeur : coordonnées variables
+    let mut edges: HashMap<(Point2D<i64>, i64), Vec<i64>> = HashMap::new();
+
+    for &cell in region {
+        for offset in OFFSETS {
+            // Un bord est exposé si la case voisine n'appartient pas à la région
+            if region.contains(&(cell + offset)) {
+                continue;
+            }
+            if offset.x != 0 {
+                // Bord horizontal (haut ou bas) : la ligne est fixe, la colonne varie
+                edges.entry((offset, cell.x)).or_default().push(cell.y);
+            } else {
+                // Bord vertical (gauche ou droite) : la colonne est fixe, la ligne varie
+                edges.entry((offset, cell.y)).or_default().push(cell.x);
+            }
+        }
+    }
+
+    // Chaque suite contiguë de bords sur une même ligne forme un côté
+    edges
+        .into_values()
+        .map(|mut values| {
+            values.sort_unstable();
+            count_runs(&values)
+        })
+        .sum()
+}
+
+/// Fonction pour calculer le périmètre d'une région (jour 12, partie 1) :
+/// le nombre de bords de ses cases qui ne sont pas partagés avec une autre case de la région
+pub fn perimeter(region: &HashSet<Point2D<i64>>) -> usize {
+    region
+        .iter()
+        .map(|&cell| {
+            OFFSETS
+                .iter()
+                .filter(|&&offset| !region.contains(&(cell + offset)))
+                .count()
+        })
+        .sum()
+}
+
+/// Fonction pour découper une grille en régions : des cases voisines (orthogonalement) de même valeur
+/// Remplissage par diffusion depuis chaque case pas encore attribuée ; les régions sont
+/// retournées dans l'ordre de leur première case, ligne par ligne
+pub fn all_regions<T: PartialEq>(grid: &Grid<T>) -> Vec<HashSet<Point2D<i64>>> {
+    let mut assigned = HashSet::new();
+    let mut regions = Vec::new();
+
+    for (start, value) in grid.iter() {
+        if !assigned.insert(start) {
+            continue; // Déjà dans une région trouvée plus tôt
+        }
+        let mut region = HashSet::from([start]);
+        let mut stack = vec![start];
+        while let Some(pos) = stack.pop() {
+            for next in grid.neighbors4(pos) {
+                if grid.get(next) == Some(value) && assigned.insert(next) {
+                    region.insert(next);
+                    stack.push(next);
+                }
+            }
+        }
+        regions.push(region);
+    }
+
+    regions
+}
+
+/// Fonction pour calculer le prix total des clôtures (jour 12) avec une fonction de coût par région
+fn total_price(grid: &Grid<char>, fence_cost: impl Fn(&HashSet<Point2D<i64>>) -> usize) -> u64 {
+    all_regions(grid)
+        .iter()
+        .map(|region| (region.len() * fence_cost(region)) as u64)
+        .sum()
+}
+
+/// Fonction pour calculer le prix des clôtures au périmètre (jour 12, partie 1) : la somme de
+/// `aire * périmètre` sur toutes les régions
+pub fn total_perimeter_price(grid: &Grid<char>) -> u64 {
+    total_price(grid, perimeter)
+}
+
+/// Fonction pour calculer le prix des clôtures au nombre de côtés (jour 12, partie 2) : la somme de
+/// `aire * nombre de côtés` sur toutes les régions
+pub fn total_side_price(grid: &Grid<char>) -> u64 {
+    total_price(grid, count_fence_sides)
+}
@@ -0,0 +1,82 @@
+// Importation des bibliothèques nécessaires
+use crate::direction::Direction; // Orientation du robot
+use crate::grid::Grid; // La carte sur laquelle le robot se déplace
+use crate::point::Point2D; // Position du robot
+
+/// Robot (ou garde) qui se déplace sur une grille en regardant dans une direction (jours 6 et 15)
+/// `is_blocked` indique quelles cases arrêtent le robot (les obstacles `#` du jour 6, par exemple)
+/// Le robot peut sortir de la grille : il ne bouge alors plus et `is_out_of_bounds` devient vrai
+pub struct RobotGrid<T> {
+    pub grid: Grid<T>,              // La carte
+    pub pos: Point2D<i64>,          // Position actuelle du robot
+    pub dir: Direction,             // Direction dans laquelle le robot regarde
+    pub is_blocked: fn(&T) -> bool, // Reconnaît les cases infranchissables
+}
+
+impl<T> RobotGrid<T> {
+    /// Place un robot sur la grille, à cette position et dans cette direction
+    pub fn new(
+        grid: Grid<T>,
+        pos: Point2D<i64>,
+        dir: Direction,
+        is_blocked: fn(&T) -> bool,
+    ) -> Self {
+        RobotGrid {
+            grid,
+            pos,
+            dir,
+            is_blocked,
+        }
+    }
+
+    /// Retourne la case située juste devant le robot, ou `None` si elle est hors de la grille
+    pub fn look_ahead(&self) -> Option<&T> {
+        self.grid.get(self.pos + self.dir.delta())
+    }
+
+    /// Avance d'une case si rien ne bloque le robot
+    /// Retourne `true` si le robot a atteint une nouvelle case de la grille ; `false` s'il est
+    /// bloqué (il ne bouge pas) ou s'il vient de sortir de la grille (ou en était déjà sorti)
+    pub fn move_forward(&mut self) -> bool {
+        if self.is_out_of_bounds() {
+            return false;
+        }
+        match self.look_ahead() {
+            Some(cell) if (self.is_blocked)(cell) => false, // Obstacle devant le robot
+            Some(_) => {
+                self.pos = self.pos + self.dir.delta();
+                true
+            }
+            None => {
+                // Le robot quitte la grille
+                self.pos = self.pos + self.dir.delta();
+                false
+            }
+        }
+    }
+
+    /// Tourne le robot d'un quart de tour vers la droite
+    pub fn turn_right(&mut self) {
+        self.dir = self.dir.turn_right();
+    }
+
+    /// Tourne le robot d'un quart de tour vers la gauche
+    pub fn turn_left(&mut self) {
+        self.dir = self.dir.turn_left();
+    }
+
+    /// Indique si le robot est sorti de la grille
+    pub fn is_out_of_bounds(&self) -> bool {
+        !self.grid.in_bounds(self.pos)
+    }
+
+    /// Avance tout droit jusqu'à un obstacle ou jusqu'à sortir de la grille
+    /// Retourne le nombre de cases atteintes dans la grille
+    pub fn step_until_blocked(&mut self) -> usize {
+        let mut steps = 0;
+        while self.move_forward() {
+            steps += 1;
+        }
+        steps
+    }
+}
@@ -0,0 +1,52 @@
+// Importation des bibliothèques nécessaires
+use crate::point::XY; // Positions et vitesses `x` (colonne), `y` (ligne), comme dans l'énoncé du jour 14
+
+/// Fonction pour calculer la position d'un robot après `steps` secondes sur une grille torique
+/// Le robot avance de `vel` à chaque seconde et réapparaît de l'autre côté quand il sort de la grille
+pub fn simulate_robot(pos: XY, vel: XY, width: i64, height: i64, steps: i64) -> XY {
+    // Le déplacement total peut être calculé d'un coup, puis enroulé une seule fois
+    (pos + vel * steps).rem_euclid(width, height)
+}
+
+/// Fonction pour calculer la position de chaque robot `(position, vitesse)` après `steps` secondes
+pub fn simulate_robots(robots: &[(XY, XY)], width: i64, height: i64, steps: i64) -> Vec<XY> {
+    robots
+        .iter()
+        .map(|&(pos, vel)| simulate_robot(pos, vel, width, height, steps))
+        .collect()
+}
+
+/// Fonction pour calculer le facteur de sécurité (jour 14) : le produit du nombre de robots dans
+/// chacun des quatre quadrants de la grille
+/// Les robots situés sur la ligne ou la colonne du milieu n'appartiennent à aucun quadrant
+pub fn safety_factor(positions: &[XY], width: i64, height: i64) -> u64 {
+    let (mid_x, mid_y) = (width / 2, height / 2);
+    let mut quadrants = [0u64; 4];
+    for pos in positions {
+        if (width % 2 == 1 && pos.x == mid_x) || (height % 2 == 1 && pos.y == mid_y) {
+            continue; // Sur une ligne de séparation
+        }
+        // Indice du quadrant : bit 0 pour la moitié droite, bit 1 pour la moitié basse
+        let index = usize::from(pos.x >= mid_x) + 2 * usize::from(pos.y >= mid_y);
+        quadrants[index] += 1;
+    }
+    quadrants.iter().product()
+}
+
+/// Fonction pour trouver la seconde (parmi `0..max_steps`) où le facteur de sécurité est minimal
+/// Quand les robots dessinent le sapin de Noël (jour 14, partie 2), ils sont regroupés dans une
+/// même zone, ce qui donne justement le facteur de sécurité le plus faible
+/// En cas d'égalité, la première seconde est retenue
+pub fn find_minimum_safety_step(
+    initial_robots: &[(XY, XY)],
+    width: i64,
+    height: i64,
+    max_steps: u64,
+) -> u64 {
+    (0..max_steps)
+        .min_by_key(|&step| {
+            let positions = simulate_robots(initial_robots, width, height, step as i64);
+            safety_factor(&positions, width, height)
+        })
+        .unwrap_or(0)
+}
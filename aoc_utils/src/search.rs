@@ -0,0 +1,366 @@
+// Importation des bibliothèques nécessaires
+use std::cmp::Reverse; // Pour transformer le tas max en tas min
+use std::collections::BTreeSet; // Pour mémoriser les états déjà rencontrés (seul `Ord` est requis)
+use std::collections::BinaryHeap; // File de priorité de Dijkstra
+use std::collections::HashMap; // Pour les distances et prédécesseurs des états
+use std::collections::VecDeque; // Pour les seaux de l'algorithme de Dial
+use std::hash::Hash; // Pour exiger des états hachables
+
+/// Résultat d'une recherche en faisceau
+/// Permet de savoir si le faisceau a dû élaguer des états (résultat approché)
+/// ou s'il a exploré tous les états comme un BFS classique (résultat exact)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BeamResult<S> {
+    /// Aucun état n'a été élagué : le but trouvé est celui qu'aurait trouvé un BFS
+    Exact(S),
+    /// Au moins un niveau a été tronqué : le but trouvé peut être sous-optimal
+    Approximate(S),
+}
+
+impl<S> BeamResult<S> {
+    /// Retourne une référence vers l'état but, quelle que soit la variante
+    pub fn state(&self) -> &S {
+        match self {
+            BeamResult::Exact(state) | BeamResult::Approximate(state) => state,
+        }
+    }
+
+    /// Consomme le résultat et retourne l'état but
+    pub fn into_state(self) -> S {
+        match self {
+            BeamResult::Exact(state) | BeamResult::Approximate(state) => state,
+        }
+    }
+
+    /// Indique si le résultat est garanti identique à celui d'un BFS
+    pub fn is_exact(&self) -> bool {
+        matches!(self, BeamResult::Exact(_))
+    }
+}
+
+/// Recherche en faisceau (beam search) à mémoire bornée
+/// À chaque niveau, seuls les `beam_width` meilleurs états (selon `Ord`, le plus grand étant le meilleur) sont conservés
+/// Les doublons ne sont retirés qu'à l'intérieur d'un même niveau : la mémoire utilisée reste
+/// proportionnelle à `beam_width` (fois le nombre de successeurs d'un état), pas à l'espace exploré
+/// Un état peut donc être revisité à un niveau ultérieur : sur un espace d'états cyclique sans but
+/// atteignable, le faisceau ne s'épuise jamais (voir `beam_search_unique`)
+/// Retourne le premier état but rencontré, ou `None` si le faisceau s'épuise sans en trouver
+pub fn beam_search<S, FN, IT>(
+    start: S,
+    beam_width: usize,
+    successors: FN,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<BeamResult<S>>
+where
+    S: Clone + Ord, // Les états doivent être comparables pour être classés dans le faisceau
+    FN: Fn(&S) -> IT, // Fonction qui génère les successeurs d'un état
+    IT: IntoIterator<Item = S>, // Les successeurs peuvent être n'importe quelle collection itérable
+{
+    beam_search_levels(start, beam_width, successors, is_goal, None)
+}
+
+/// Variante de `beam_search` qui ne revisite jamais un état déjà rencontré, à n'importe quel niveau
+/// Le faisceau finit toujours par s'épuiser sur un espace d'états fini, mais l'ensemble des états
+/// rencontrés grandit avec tout l'espace exploré : la mémoire n'est plus bornée par `beam_width`
+pub fn beam_search_unique<S, FN, IT>(
+    start: S,
+    beam_width: usize,
+    successors: FN,
+    is_goal: impl Fn(&S) -> bool,
+) -> Option<BeamResult<S>>
+where
+    S: Clone + Ord,
+    FN: Fn(&S) -> IT,
+    IT: IntoIterator<Item = S>,
+{
+    let mut seen = BTreeSet::from([start.clone()]);
+    beam_search_levels(start, beam_width, successors, is_goal, Some(&mut seen))
+}
+
+/// Boucle commune des recherches en faisceau, niveau par niveau
+/// `seen` contient les états déjà rencontrés quand la déduplication porte sur toute la recherche
+fn beam_search_levels<S, FN, IT>(
+    start: S,
+    beam_width: usize,
+    successors: FN,
+    is_goal: impl Fn(&S) -> bool,
+    mut seen: Option<&mut BTreeSet<S>>,
+) -> Option<BeamResult<S>>
+where
+    S: Clone + Ord,
+    FN: Fn(&S) -> IT,
+    IT: IntoIterator<Item = S>,
+{
+    // Le faisceau courant, trié du meilleur au moins bon
+    let mut beam = vec![start];
+    // Devient vrai dès qu'un niveau a été tronqué
+    let mut pruned = false;
+
+    while !beam.is_empty() {
+        // Le faisceau étant trié, le premier but trouvé est le meilleur du niveau
+        if let Some(goal) = beam.iter().find(|state| is_goal(state)) {
+            let goal = goal.clone();
+            return Some(if pruned {
+                BeamResult::Approximate(goal)
+            } else {
+                BeamResult::Exact(goal)
+            });
+        }
+
+        // Génère tous les successeurs du niveau courant (jamais vus, si la déduplication est globale)
+        let mut next: Vec<S> = beam
+            .iter()
+            .flat_map(&successors)
+            .filter(|state| seen.as_mut().is_none_or(|seen| seen.insert(state.clone())))
+            .collect();
+
+        // Trie par ordre décroissant pour garder les meilleurs états en tête, sans doublon
+        next.sort_unstable_by(|a, b| b.cmp(a));
+        next.dedup();
+        // Ne conserve que les `beam_width` meilleurs états
+        if next.len() > beam_width {
+            next.truncate(beam_width);
+            pruned = true;
+        }
+
+        beam = next;
+    }
+
+    None // Le faisceau est vide : aucun but atteignable avec cette largeur
+}
+
+/// Parcours en largeur qui retourne tous les chemins de longueur minimale de `start` à `goal`
+/// Chaque état garde la liste de tous ses prédécesseurs au niveau précédent, puis les chemins
+/// sont reconstruits en remontant depuis le but
+/// Attention : le nombre de plus courts chemins peut être exponentiel, c'est à l'appelant
+/// de restreindre l'espace de recherche (par exemple via `successors`)
+pub fn bfs_all_paths<S, FN, IT>(start: S, goal: &S, successors: FN) -> Vec<Vec<S>>
+where
+    S: Hash + Eq + Clone,
+    FN: Fn(&S) -> IT,
+    IT: IntoIterator<Item = S>,
+{
+    // Distance de chaque état découvert depuis le départ
+    let mut distances = HashMap::new();
+    distances.insert(start.clone(), 0);
+    // Tous les prédécesseurs de chaque état sur un plus court chemin
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+
+    let mut frontier = vec![start.clone()];
+    let mut depth = 0;
+
+    // On s'arrête dès que le niveau contenant le but a été entièrement traité :
+    // aucun chemin plus long n'est exploré
+    while !frontier.is_empty() && !distances.contains_key(goal) {
+        let mut next_frontier = Vec::new();
+        for state in &frontier {
+            for next in successors(state) {
+                match distances.get(&next) {
+                    // Nouvel état : il est à la distance `depth + 1`
+                    None => {
+                        distances.insert(next.clone(), depth + 1);
+                        predecessors.insert(next.clone(), vec![state.clone()]);
+                        next_frontier.push(next);
+                    }
+                    // État déjà atteint au même niveau par un autre chemin : autre prédécesseur
+                    Some(&d) if d == depth + 1 => {
+                        if let Some(preds) = predecessors.get_mut(&next) {
+                            preds.push(state.clone());
+                        }
+                    }
+                    // État atteint plus tôt : ce chemin n'est pas le plus court
+                    Some(_) => {}
+                }
+            }
+        }
+        frontier = next_frontier;
+        depth += 1;
+    }
+
+    if !distances.contains_key(goal) {
+        return Vec::new(); // But inaccessible
+    }
+
+    // Remonte depuis le but en suivant tous les prédécesseurs possibles
+    let mut paths = Vec::new();
+    let mut stack = vec![vec![goal.clone()]];
+    while let Some(reversed_path) = stack.pop() {
+        let last = &reversed_path[reversed_path.len() - 1];
+        if *last == start {
+            // Chemin complet : on le remet dans l'ordre départ -> but
+            paths.push(reversed_path.into_iter().rev().collect());
+            continue;
+        }
+        for pred in &predecessors[last] {
+            let mut extended = reversed_path.clone();
+            extended.push(pred.clone());
+            stack.push(extended);
+        }
+    }
+
+    paths
+}
+
+/// Recherche à coût uniforme pour des poids entiers bornés (algorithme de Dial)
+/// Les états en attente sont rangés dans `max_edge_cost + 1` seaux utilisés de façon circulaire,
+/// ce qui remplace le tas de Dijkstra : O(V + E + coût maximal) en temps et O(max_edge_cost + V) en mémoire
+/// `successors` retourne les couples `(état voisin, coût de l'arête)`, chaque coût devant être au plus `max_edge_cost`
+/// Retourne la distance minimale de `start` à chaque état atteignable
+pub fn weighted_bfs<S, FN, IT>(start: S, max_edge_cost: usize, successors: FN) -> HashMap<S, usize>
+where
+    S: Hash + Eq + Clone,
+    FN: Fn(&S) -> IT,
+    IT: IntoIterator<Item = (S, usize)>,
+{
+    let bucket_count = max_edge_cost + 1;
+    let mut buckets: Vec<VecDeque<S>> = vec![VecDeque::new(); bucket_count];
+    let mut distances = HashMap::new();
+
+    distances.insert(start.clone(), 0);
+    buckets[0].push_back(start);
+    let mut pending = 1; // Nombre d'états en attente dans l'ensemble des seaux
+    let mut current = 0; // Distance correspondant au seau en cours de traitement
+
+    while pending > 0 {
+        let slot = current % bucket_count;
+        // Traite tous les états du seau courant (y compris ceux ajoutés par des arêtes de coût nul)
+        while let Some(state) = buckets[slot].pop_front() {
+            pending -= 1;
+            // Ignore les entrées périmées : l'état a été atteint plus tard par un chemin plus court
+            if distances[&state] != current {
+                continue;
+            }
+            for (next, cost) in successors(&state) {
+                assert!(
+                    cost <= max_edge_cost,
+                    "coût d'arête supérieur à max_edge_cost"
+                );
+                let candidate = current + cost;
+                let improved = distances.get(&next).is_none_or(|&d| candidate < d);
+                if improved {
+                    distances.insert(next.clone(), candidate);
+                    buckets[candidate % bucket_count].push_back(next);
+                    pending += 1;
+                }
+            }
+        }
+        current += 1; // Passe au seau de la distance suivante
+    }
+
+    distances
+}
+
+/// Algorithme de Dijkstra : distance minimale de `start` à chaque état atteignable
+/// `successors` retourne les couples `(état voisin, coût de l'arête)` ; les coûts ne sont pas bornés
+/// (contrairement à `weighted_bfs`), mais les états doivent être ordonnables pour le tas
+pub fn dijkstra<S, FN, IT>(start: S, successors: FN) -> HashMap<S, u64>
+where
+    S: Hash + Eq + Clone + Ord,
+    FN: Fn(&S) -> IT,
+    IT: IntoIterator<Item = (S, u64)>,
+{
+    let mut distances = HashMap::new();
+    distances.insert(start.clone(), 0);
+    let mut heap = BinaryHeap::from([Reverse((0, start))]);
+
+    while let Some(Reverse((distance, state))) = heap.pop() {
+        // Ignore les entrées périmées : l'état a déjà été atteint par un chemin plus court
+        if distances[&state] < distance {
+            continue;
+        }
+        for (next, cost) in successors(&state) {
+            let candidate = distance + cost;
+            if distances.get(&next).is_none_or(|&d| candidate < d) {
+                distances.insert(next.clone(), candidate);
+                heap.push(Reverse((candidate, next)));
+            }
+        }
+    }
+
+    distances
+}
+
+/// Parcours en largeur qui calcule la distance de `start` à chaque état atteignable, en un seul parcours
+pub fn bfs_distance_to_all<S, FN, IT>(start: S, successors: FN) -> HashMap<S, usize>
+where
+    S: Hash + Eq + Clone,
+    FN: Fn(&S) -> IT,
+    IT: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::new();
+    distances.insert(start.clone(), 0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        let next_distance = distances[&state] + 1;
+        for next in successors(&state) {
+            // Le premier passage sur un état est toujours le plus court en BFS
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), next_distance);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Fonction pour calculer les distances entre toutes les paires de sources (une BFS par source)
+/// La clé `(i, j)` donne la distance de `sources[i]` à `sources[j]` ; les paires inaccessibles sont absentes
+/// Permet de précalculer une matrice de distances pour des problèmes de type voyageur de commerce
+pub fn bfs_distance_between_all_pairs<S, FN, IT>(
+    sources: &[S],
+    successors: FN,
+) -> HashMap<(usize, usize), usize>
+where
+    S: Hash + Eq + Clone,
+    FN: Fn(&S) -> IT,
+    IT: IntoIterator<Item = S>,
+{
+    let mut pairs = HashMap::new();
+    for (i, source) in sources.iter().enumerate() {
+        let distances = bfs_distance_to_all(source.clone(), &successors);
+        // Ne garde que les distances vers les autres sources
+        for (j, target) in sources.iter().enumerate() {
+            if let Some(&d) = distances.get(target) {
+                pairs.insert((i, j), d);
+            }
+        }
+    }
+    pairs
+}
+
+/// Recherche dichotomique du plus petit indice de `[lo, hi[` pour lequel `pred` est vrai
+/// Le prédicat doit être monotone : faux jusqu'à un certain indice, puis vrai ensuite
+/// (par exemple « la sortie est bloquée après les `n` premiers octets » au jour 18)
+/// Retourne `None` si `pred` est faux partout
+pub fn binary_search_first_true(
+    lo: usize,
+    hi: usize,
+    pred: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    let (mut lo, mut hi_bound) = (lo, hi);
+    // Invariant : `pred` est faux avant `lo` et vrai à partir de `hi_bound` (s'il est dans l'intervalle)
+    while lo < hi_bound {
+        let mid = lo + (hi_bound - lo) / 2;
+        if pred(mid) {
+            hi_bound = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    (lo < hi).then_some(lo)
+}
+
+/// Recherche dichotomique du plus grand indice de `[lo, hi[` pour lequel `pred` est faux
+/// Même hypothèse de monotonie que `binary_search_first_true`
+/// Retourne `None` si `pred` est vrai partout
+pub fn binary_search_last_false(
+    lo: usize,
+    hi: usize,
+    pred: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    // Le dernier faux est juste avant le premier vrai (ou à la fin s'il n'y a aucun vrai)
+    let first_true = binary_search_first_true(lo, hi, pred).unwrap_or(hi.max(lo));
+    (first_true > lo).then(|| first_true - 1)
+}
@@ -0,0 +1,90 @@
+// Importation des bibliothèques nécessaires
+use std::collections::HashMap; // Pour le prix associé à chaque suite de variations
+
+/// Module des nombres secrets : 2^24
+const PRUNE: u64 = 16_777_216;
+
+/// Fonction pour calculer le nombre secret suivant (jour 22)
+/// Les trois étapes doivent être appliquées dans cet ordre, chacune étant un « mélange » (XOR avec
+/// le nombre courant) suivi d'un « élagage » (modulo 2^24) :
+/// multiplier par 64, puis diviser par 32, puis multiplier par 2048
+/// Comme le module est une puissance de deux, les multiplications sont des décalages et l'élagage
+/// un masque : les bits perdus par le décalage auraient de toute façon été élagués, ce qui évite
+/// tout dépassement quel que soit `n`
+pub fn mine_prng_next(n: u64) -> u64 {
+    let n = ((n << 6) ^ n) & (PRUNE - 1);
+    let n = ((n >> 5) ^ n) & (PRUNE - 1);
+    ((n << 11) ^ n) & (PRUNE - 1)
+}
+
+/// Fonction pour calculer le `n`-ième nombre secret après `start` (`start` lui-même pour `n = 0`)
+pub fn mine_prng_nth(start: u64, n: u32) -> u64 {
+    (0..n).fold(start, |secret, _| mine_prng_next(secret))
+}
+
+/// Fonction pour calculer les `len` nombres secrets qui suivent `start` (sans `start` lui-même)
+pub fn mine_prng_sequence(start: u64, len: usize) -> Vec<u64> {
+    std::iter::successors(Some(mine_prng_next(start)), |&n| Some(mine_prng_next(n)))
+        .take(len)
+        .collect()
+}
+
+/// Fonction pour générer les nombres secrets d'un acheteur : `start` suivi des `count` secrets suivants
+pub fn generate_secrets(start: u64, count: usize) -> Vec<u64> {
+    let mut secrets = Vec::with_capacity(count + 1);
+    secrets.push(start);
+    secrets.extend(mine_prng_sequence(start, count));
+    secrets
+}
+
+/// Fonction pour extraire les prix (dernier chiffre de chaque secret) et leurs variations
+/// Le i-ème couple contient le prix du secret `i + 1` et sa différence avec le prix précédent
+pub fn prices_and_changes(secrets: &[u64]) -> Vec<(u8, i8)> {
+    secrets
+        .windows(2)
+        .map(|pair| {
+            let (before, after) = ((pair[0] % 10) as i8, (pair[1] % 10) as i8);
+            (after as u8, after - before)
+        })
+        .collect()
+}
+
+/// Fonction pour associer chaque suite de quatre variations consécutives au prix obtenu
+/// la première fois qu'elle apparaît (le singe vend dès qu'il reconnaît la suite)
+pub fn first_occurrence_map(pairs: &[(u8, i8)]) -> HashMap<[i8; 4], u8> {
+    let mut first = HashMap::new();
+    for window in pairs.windows(4) {
+        let changes = [window[0].1, window[1].1, window[2].1, window[3].1];
+        first.entry(changes).or_insert(window[3].0);
+    }
+    first
+}
+
+/// Fonction pour calculer le nombre total de bananes obtenues avec cette suite de variations,
+/// en additionnant les ventes de tous les acheteurs (0 pour ceux chez qui elle n'apparaît pas)
+pub fn total_bananas_for_sequence(
+    all_first_occurrences: &[HashMap<[i8; 4], u8>],
+    seq: [i8; 4],
+) -> u64 {
+    all_first_occurrences
+        .iter()
+        .filter_map(|first| first.get(&seq))
+        .map(|&price| u64::from(price))
+        .sum()
+}
+
+/// Fonction pour trouver la suite de variations qui rapporte le plus de bananes (jour 22, partie 2)
+/// Retourne la suite et le total de bananes, ou `None` si aucune suite n'apparaît
+/// En cas d'égalité, la plus petite suite (ordre lexicographique) est retenue
+pub fn best_sequence(all_first_occurrences: &[HashMap<[i8; 4], u8>]) -> Option<([i8; 4], u64)> {
+    // Additionne les prix de toutes les suites en un seul passage sur chaque acheteur
+    let mut totals: HashMap<[i8; 4], u64> = HashMap::new();
+    for first in all_first_occurrences {
+        for (&seq, &price) in first {
+            *totals.entry(seq).or_default() += u64::from(price);
+        }
+    }
+    totals
+        .into_iter()
+        .max_by_key(|&(seq, total)| (total, std::cmp::Reverse(seq)))
+}
@@ -0,0 +1,22 @@
+/// Fonction pour trouver le `k`-ième plus petit élément (en partant de 0) sans trier toute la liste
+/// S'appuie sur la sélection rapide de la bibliothèque standard (`select_nth_unstable`, O(n) en moyenne)
+/// La tranche est partiellement réordonnée sur place
+/// Panique si `k` est en dehors de la tranche
+pub fn kth_smallest<T: Ord + Clone>(data: &mut [T], k: usize) -> T {
+    assert!(k < data.len(), "indice {} hors de la liste", k);
+    let (_, kth, _) = data.select_nth_unstable(k);
+    kth.clone()
+}
+
+/// Fonction pour trouver le `k`-ième plus grand élément (en partant de 0)
+pub fn kth_largest<T: Ord + Clone>(data: &mut [T], k: usize) -> T {
+    assert!(k < data.len(), "indice {} hors de la liste", k);
+    kth_smallest(data, data.len() - 1 - k)
+}
+
+/// Fonction pour trouver la médiane d'une liste non vide
+/// Pour une longueur paire, c'est la médiane inférieure qui est retournée
+pub fn median<T: Ord + Clone>(data: &mut [T]) -> T {
+    assert!(!data.is_empty(), "la médiane d'une liste vide n'existe pas");
+    kth_smallest(data, (data.len() - 1) / 2)
+}
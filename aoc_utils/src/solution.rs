@@ -0,0 +1,34 @@
+// Importation des bibliothèques nécessaires
+use crate::answer::Answer; // La réponse d'une partie
+use crate::error::AocError; // Pour signaler une entrée mal formée
+
+/// Implémentation alternative d'une partie, comparée aux autres par `aoc2024 run --compare`
+/// Garder la version naïve comme variante permet de s'en servir d'oracle pour les versions optimisées
+#[derive(Debug, Clone, Copy)]
+pub struct Variant {
+    pub name: &'static str, // Nom affiché, comme `tri` ou `histogramme`
+    pub run: fn(&str) -> Result<Answer, AocError>, // La partie résolue par cette variante
+}
+
+/// Interface commune aux solutions de chaque jour
+/// Chaque partie reçoit le contenu complet du fichier d'entrée et retourne sa réponse (sans
+/// l'afficher), ou une erreur si l'entrée ne peut pas être analysée
+pub trait Solution: Sync {
+    /// Résout la première partie du puzzle
+    fn part1(&self, input: &str) -> Result<Answer, AocError>;
+
+    /// Résout la deuxième partie du puzzle
+    fn part2(&self, input: &str) -> Result<Answer, AocError>;
+
+    /// Notes sur l'approche et les algorithmes utilisés, reprises dans le rapport d'`aoc2024 report`
+    /// (en Markdown), vides par défaut
+    fn description(&self) -> &'static str {
+        ""
+    }
+
+    /// Toutes les implémentations d'une partie (1 ou 2), qui doivent donner la même réponse
+    /// Par défaut, aucune variante n'est déclarée
+    fn variants(&self, _part: u8) -> &'static [Variant] {
+        &[]
+    }
+}
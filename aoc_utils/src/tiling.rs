@@ -0,0 +1,95 @@
+// Importation des bibliothèques nécessaires
+use std::collections::HashMap; // Pour les enfants des nœuds du trie
+
+/// Nœud d'un trie d'octets
+#[derive(Default)]
+struct TrieNode {
+    children: HashMap<u8, usize>, // Indice du nœud enfant pour chaque octet
+    is_tile: bool,                // Vrai si un motif se termine ici
+}
+
+/// Trie des motifs, stocké dans un vecteur (la racine est le nœud 0)
+struct Trie {
+    nodes: Vec<TrieNode>,
+}
+
+impl Trie {
+    /// Construit le trie de tous les motifs (les motifs vides sont ignorés)
+    fn new(tiles: &[&str]) -> Self {
+        let mut nodes = vec![TrieNode::default()];
+        for tile in tiles.iter().filter(|tile| !tile.is_empty()) {
+            let mut node = 0;
+            for &byte in tile.as_bytes() {
+                node = match nodes[node].children.get(&byte) {
+                    Some(&child) => child,
+                    None => {
+                        // Nouveau nœud pour cet octet
+                        nodes.push(TrieNode::default());
+                        let child = nodes.len() - 1;
+                        nodes[node].children.insert(byte, child);
+                        child
+                    }
+                };
+            }
+            nodes[node].is_tile = true;
+        }
+        Trie { nodes }
+    }
+
+    /// Parcourt les longueurs de tous les motifs qui sont des préfixes de `text`
+    fn prefix_lengths<'a>(&'a self, text: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+        let mut node = Some(0);
+        text.iter()
+            .enumerate()
+            .map_while(move |(i, byte)| {
+                // Descend d'un niveau dans le trie, ou s'arrête si aucun motif ne continue ainsi
+                let child = *self.nodes[node?].children.get(byte)?;
+                node = Some(child);
+                Some((i + 1, self.nodes[child].is_tile))
+            })
+            .filter_map(|(len, is_tile)| is_tile.then_some(len))
+    }
+}
+
+/// Fonction pour compter les façons d'écrire `target` en mettant bout à bout des motifs de `tiles`
+/// (jour 19, partie 2) ; un même motif peut être utilisé plusieurs fois
+/// Programmation dynamique : `ways[i]` est le nombre de façons d'obtenir `target[..i]`,
+/// et le trie donne en un seul parcours tous les motifs qui commencent à la position `i`
+/// La cible vide s'obtient d'une seule façon (aucun motif)
+/// Un nombre de façons qui dépasse `u64::MAX` est ramené à `u64::MAX` : le résultat n'est exact
+/// qu'en dessous de cette limite
+pub fn count_tilings<'a>(target: &'a str, tiles: &[&'a str]) -> u64 {
+    let trie = Trie::new(tiles);
+    let bytes = target.as_bytes();
+    let mut ways = vec![0u64; bytes.len() + 1];
+    ways[0] = 1;
+
+    for start in 0..bytes.len() {
+        if ways[start] == 0 {
+            continue; // Ce préfixe n'est pas réalisable
+        }
+        for len in trie.prefix_lengths(&bytes[start..]) {
+            ways[start + len] = ways[start + len].saturating_add(ways[start]);
+        }
+    }
+
+    ways[bytes.len()]
+}
+
+/// Fonction pour savoir si `target` peut s'écrire avec des motifs de `tiles` (jour 19, partie 1)
+pub fn can_tile(target: &str, tiles: &[&str]) -> bool {
+    let trie = Trie::new(tiles);
+    let bytes = target.as_bytes();
+    let mut reachable = vec![false; bytes.len() + 1];
+    reachable[0] = true;
+
+    for start in 0..bytes.len() {
+        if reachable[start] {
+            for len in trie.prefix_lengths(&bytes[start..]) {
+                reachable[start + len] = true;
+            }
+        }
+    }
+
+    reachable[bytes.len()]
+}
@@ -0,0 +1,68 @@
+// Importation des bibliothèques nécessaires
+use crate::grid::Grid; // La carte topographique
+use crate::point::Point2D; // Pour repérer les cases de la carte
+use std::collections::{HashSet, VecDeque}; // Pour le parcours en largeur
+
+/// Hauteur d'un sommet, où se termine chaque sentier
+const SUMMIT: u8 = 9;
+
+/// Les voisins d'une case qui sont exactement un cran plus haut : un sentier monte d'un seul cran à chaque pas
+fn uphill_neighbors(grid: &Grid<u8>, pos: Point2D<i64>) -> impl Iterator<Item = Point2D<i64>> + '_ {
+    let target = grid.get(pos).and_then(|h| h.checked_add(1));
+    grid.neighbors4(pos)
+        .filter(move |&next| target.is_some() && grid.get(next) == target.as_ref())
+}
+
+/// Fonction pour calculer le score d'un départ de sentier (jour 10, partie 1) : le nombre de
+/// sommets différents accessibles en montant d'un cran à chaque pas
+/// Parcours en largeur avec un ensemble de cases visitées, pour ne compter chaque sommet qu'une fois
+pub fn trail_score(grid: &Grid<u8>, start: Point2D<i64>) -> usize {
+    let mut visited = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    let mut summits = 0;
+
+    while let Some(pos) = queue.pop_front() {
+        if grid.get(pos) == Some(&SUMMIT) {
+            summits += 1;
+            continue;
+        }
+        for next in uphill_neighbors(grid, pos) {
+            if visited.insert(next) {
+                queue.push_back(next);
+            }
+        }
+    }
+
+    summits
+}
+
+/// Fonction pour calculer la note d'un départ de sentier (jour 10, partie 2) : le nombre de sentiers
+/// distincts qui mènent à un sommet
+/// Parcours en profondeur sans ensemble de cases visitées : chaque chemin est compté séparément
+pub fn trail_rating(grid: &Grid<u8>, start: Point2D<i64>) -> usize {
+    let mut stack = vec![start];
+    let mut trails = 0;
+
+    while let Some(pos) = stack.pop() {
+        if grid.get(pos) == Some(&SUMMIT) {
+            trails += 1;
+        } else {
+            stack.extend(uphill_neighbors(grid, pos));
+        }
+    }
+
+    trails
+}
+
+/// Fonction pour calculer, en un seul passage sur la carte, la somme des scores et la somme des
+/// notes de tous les départs de sentier (cases de hauteur 0)
+pub fn all_trailhead_metrics(grid: &Grid<u8>) -> (usize, usize) {
+    grid.iter()
+        .filter(|&(_, &height)| height == 0)
+        .fold((0, 0), |(score, rating), (start, _)| {
+            (
+                score + trail_score(grid, start),
+                rating + trail_rating(grid, start),
+            )
+        })
+}
@@ -0,0 +1,128 @@
+// Importation des bibliothèques nécessaires
+use crate::grid::{Grid, GridLike}; // La grille plate et l'interface commune
+use crate::point::Point2D; // Pour repérer les cases de la grille
+
+/// Grille rectangulaire stockée ligne par ligne dans un `Vec<Vec<T>>`
+/// Même interface que `Grid<T>` (via `GridLike`), avec un accès direct à chaque ligne
+/// Les positions sont des `Point2D<i64>` où `x` est la ligne et `y` la colonne
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VecGrid<T> {
+    rows: Vec<Vec<T>>, // Les lignes, toutes de la même longueur
+    width: usize,      // Nombre de colonnes
+}
+
+impl<T> VecGrid<T> {
+    /// Crée une grille à partir de ses lignes
+    /// Panique si les lignes n'ont pas toutes la même longueur
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "la grille n'est pas rectangulaire"
+        );
+        VecGrid { rows, width }
+    }
+
+    /// Analyse un texte ligne par ligne, chaque caractère devenant une case via `cell_fn`
+    /// Les lignes vides sont ignorées et toutes les lignes doivent avoir la même longueur
+    pub fn parse(input: &str, cell_fn: impl Fn(char) -> T) -> Self {
+        let rows = input
+            .lines()
+            .map(str::trim_end)
+            .filter(|l| !l.is_empty())
+            .map(|line| line.chars().map(&cell_fn).collect())
+            .collect();
+        VecGrid::from_rows(rows)
+    }
+
+    /// Nombre de colonnes de la grille
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Nombre de lignes de la grille
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Retourne la case à cette position, ou `None` si elle est hors de la grille
+    pub fn get(&self, pos: Point2D<i64>) -> Option<&T> {
+        if GridLike::in_bounds(self, pos) {
+            Some(&self.rows[pos.x as usize][pos.y as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Remplace la case à cette position
+    /// Panique si la position est hors de la grille
+    pub fn set(&mut self, pos: Point2D<i64>, value: T) {
+        assert!(
+            GridLike::in_bounds(self, pos),
+            "position {:?} hors de la grille",
+            pos
+        );
+        self.rows[pos.x as usize][pos.y as usize] = value;
+    }
+
+    /// Les voisins orthogonaux (nord, est, sud, ouest) situés dans la grille
+    pub fn neighbors4(&self, pos: Point2D<i64>) -> impl Iterator<Item = Point2D<i64>> + '_ {
+        GridLike::neighbors4(self, pos)
+    }
+
+    /// Les huit voisins (diagonales comprises) situés dans la grille
+    pub fn neighbors8(&self, pos: Point2D<i64>) -> impl Iterator<Item = Point2D<i64>> + '_ {
+        GridLike::neighbors8(self, pos)
+    }
+
+    /// Toutes les positions dont la case vaut `value`, ligne par ligne
+    pub fn find_all(&self, value: &T) -> Vec<Point2D<i64>>
+    where
+        T: PartialEq,
+    {
+        GridLike::find_all(self, value)
+    }
+
+    /// Retourne la ligne `r` sous forme de tranche
+    pub fn row_slice(&self, r: usize) -> &[T] {
+        &self.rows[r]
+    }
+}
+
+impl<T> GridLike<T> for VecGrid<T> {
+    fn width(&self) -> usize {
+        self.width
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn get(&self, pos: Point2D<i64>) -> Option<&T> {
+        VecGrid::get(self, pos)
+    }
+
+    fn set(&mut self, pos: Point2D<i64>, value: T) {
+        VecGrid::set(self, pos, value);
+    }
+}
+
+impl<T> From<Grid<T>> for VecGrid<T> {
+    /// Découpe le vecteur plat en lignes
+    fn from(grid: Grid<T>) -> Self {
+        let (width, height) = (grid.width(), grid.height());
+        let mut cells = grid.into_cells().into_iter();
+        let rows = (0..height)
+            .map(|_| cells.by_ref().take(width).collect())
+            .collect();
+        VecGrid { rows, width }
+    }
+}
+
+impl<T> From<VecGrid<T>> for Grid<T> {
+    /// Recolle les lignes dans un vecteur plat
+    fn from(grid: VecGrid<T>) -> Self {
+        let (width, height) = (grid.width, grid.rows.len());
+        Grid::from_vec(width, height, grid.rows.into_iter().flatten().collect())
+    }
+}
@@ -0,0 +1,92 @@
+// Importation des bibliothèques nécessaires
+use crate::error::AocError; // Pour signaler une entrée mal formée
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind}; // Pour les touches du clavier
+use ratatui::style::{Modifier, Style}; // Pour mettre en valeur la ligne courante
+use ratatui::text::Line; // Une ligne de texte d'une image
+use ratatui::widgets::{Block, Paragraph}; // Le cadre et le texte affichés
+use ratatui::DefaultTerminal; // Le terminal en plein écran
+use std::io; // Pour les erreurs du terminal
+use std::time::Duration; // Pour la durée de chaque image
+
+/// Une image d'animation : un titre et des lignes de texte, dont une peut être mise en valeur
+/// Les images ne dépendent pas du terminal, ce qui permet de les construire et de les vérifier à part
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Frame {
+    pub title: String, // Affiché dans le cadre, par exemple l'étape et le total courant
+    pub lines: Vec<String>, // Le contenu de l'image, ligne par ligne
+    pub highlight: Option<usize>, // L'indice de la ligne mise en valeur, s'il y en a une
+}
+
+/// Interface des solutions qui savent montrer leur déroulement, image par image
+pub trait Visualize: Sync {
+    /// Construit les images de l'animation d'une partie (1 ou 2) sur le contenu de l'entrée
+    fn frames(&self, input: &str, part: u8) -> Result<Vec<Frame>, AocError>;
+}
+
+/// Fonction pour jouer une animation en plein écran à `fps` images par seconde
+/// Espace met en pause, `q` ou Échap quitte ; l'animation reste sur la dernière image jusqu'à une touche
+/// Le terminal est rétabli même si l'affichage échoue
+pub fn play(frames: &[Frame], fps: u32) -> io::Result<()> {
+    let mut terminal = ratatui::init();
+    let result = play_in(&mut terminal, frames, fps);
+    ratatui::restore();
+    result
+}
+
+/// Joue l'animation dans un terminal déjà passé en plein écran
+fn play_in(terminal: &mut DefaultTerminal, frames: &[Frame], fps: u32) -> io::Result<()> {
+    let delay = Duration::from_secs(1) / fps.max(1);
+    let mut index = 0;
+    let mut paused = false;
+    loop {
+        let Some(frame) = frames.get(index) else {
+            return Ok(()); // Aucune image à afficher
+        };
+        terminal.draw(|f| f.render_widget(paragraph(frame), f.area()))?;
+
+        // Attend la fin de l'image (ou indéfiniment sur la dernière image et en pause)
+        let last = index + 1 == frames.len();
+        let key = if last || paused || event::poll(delay)? {
+            Some(next_key()?)
+        } else {
+            None
+        };
+        match key {
+            Some(KeyCode::Char('q')) | Some(KeyCode::Esc) => return Ok(()),
+            Some(KeyCode::Char(' ')) => paused = !paused,
+            _ if last => return Ok(()),
+            _ if !paused => index += 1,
+            _ => {}
+        }
+    }
+}
+
+/// Fonction pour attendre la prochaine touche pressée
+fn next_key() -> io::Result<KeyCode> {
+    loop {
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press {
+                return Ok(key.code);
+            }
+        }
+    }
+}
+
+/// Fonction pour transformer une image en texte encadré, la ligne mise en valeur étant inversée
+fn paragraph(frame: &Frame) -> Paragraph<'_> {
+    let lines: Vec<Line> = frame
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            let line = Line::raw(line.as_str());
+            match frame.highlight {
+                Some(highlight) if highlight == index => {
+                    line.style(Style::new().add_modifier(Modifier::REVERSED))
+                }
+                _ => line,
+            }
+        })
+        .collect();
+    Paragraph::new(lines).block(Block::bordered().title(frame.title.as_str()))
+}
@@ -0,0 +1,119 @@
+// Importation des bibliothèques nécessaires
+use crate::direction::Direction; // Pour la direction de poussée
+use crate::grid::Grid; // La carte de l'entrepôt
+use crate::point::Point2D; // Pour repérer le robot et les caisses
+use std::collections::{HashSet, VecDeque}; // Pour le parcours des caisses à pousser
+
+/// Fonction pour élargir la carte de l'entrepôt (jour 15, partie 2) :
+/// `#` devient `##`, `O` devient `[]`, `.` devient `..` et `@` devient `@.`
+pub fn widen_warehouse(grid: &Grid<char>) -> Grid<char> {
+    let cells = grid
+        .iter()
+        .flat_map(|(_, &c)| match c {
+            '#' => ['#', '#'],
+            'O' => ['[', ']'],
+            '@' => ['@', '.'],
+            _ => ['.', '.'],
+        })
+        .collect();
+    Grid::from_vec(grid.width() * 2, grid.height(), cells)
+}
+
+/// Fonction pour rassembler toutes les cases de caisses déplacées si le robot pousse dans la direction `dir`
+/// Le parcours en largeur suit les caisses en éventail : une grande caisse `[]` poussée verticalement
+/// entraîne ses deux moitiés, et deux caisses peuvent converger vers une même troisième caisse
+/// Rien n'est modifié ici : retourne `None` si une caisse touche un mur, l'ensemble complet sinon
+pub fn collect_push_set(
+    grid: &Grid<char>,
+    robot: Point2D<i64>,
+    dir: Direction,
+) -> Option<HashSet<Point2D<i64>>> {
+    let step = dir.delta();
+    let mut push_set = HashSet::new();
+    let mut queue = VecDeque::from([robot + step]);
+
+    while let Some(pos) = queue.pop_front() {
+        match grid.get(pos) {
+            // Un mur (ou le bord de la carte) bloque toute la poussée
+            Some('#') | None => return None,
+            // Une caisse : on la déplace, et on regarde ce qu'il y a devant elle
+            Some(&c @ ('O' | '[' | ']')) => {
+                if !push_set.insert(pos) {
+                    continue; // Caisse déjà atteinte par une autre branche de l'éventail
+                }
+                queue.push_back(pos + step);
+                // Verticalement, la moitié jumelle d'une grande caisse bouge aussi
+                if dir.is_vertical() {
+                    match c {
+                        '[' => queue.push_back(pos + Direction::East.delta()),
+                        ']' => queue.push_back(pos + Direction::West.delta()),
+                        _ => {}
+                    }
+                }
+            }
+            // Une case libre : rien à pousser au-delà
+            Some(_) => {}
+        }
+    }
+
+    Some(push_set)
+}
+
+/// Fonction pour déplacer d'un coup toutes les cases de l'ensemble dans la direction `dir`
+/// Toutes les cases sont lues avant d'écrire quoi que ce soit, pour que la grille reste cohérente
+pub fn commit_push(grid: &mut Grid<char>, push_set: &HashSet<Point2D<i64>>, dir: Direction) {
+    let step = dir.delta();
+
+    // Mémorise le contenu de chaque case avant de la vider
+    let moved: Vec<(Point2D<i64>, char)> = push_set
+        .iter()
+        .filter_map(|&pos| grid.get(pos).map(|&c| (pos, c)))
+        .collect();
+    for &(pos, _) in &moved {
+        grid.set(pos, '.');
+    }
+    // Réécrit chaque case à sa nouvelle position
+    for (pos, c) in moved {
+        grid.set(pos + step, c);
+    }
+}
+
+/// Fonction pour faire avancer le robot `@` d'un pas en poussant les caisses si possible
+/// Retourne la nouvelle position du robot (inchangée si la poussée est bloquée)
+pub fn move_robot(grid: &mut Grid<char>, robot: Point2D<i64>, dir: Direction) -> Point2D<i64> {
+    match collect_push_set(grid, robot, dir) {
+        Some(push_set) => {
+            commit_push(grid, &push_set, dir);
+            // Le robot avance dans la case libérée
+            let next = robot + dir.delta();
+            grid.set(robot, '.');
+            grid.set(next, '@');
+            next
+        }
+        None => robot, // Bloqué par un mur
+    }
+}
+
+/// Fonction pour calculer la somme des coordonnées GPS : `ligne * row_factor + colonne * col_factor`
+/// Pour le jour 15, on utilise `gps_sum(caisses, 100, 1)`
+pub fn gps_sum(
+    positions: impl IntoIterator<Item = Point2D<i64>>,
+    row_factor: i64,
+    col_factor: i64,
+) -> i64 {
+    positions
+        .into_iter()
+        .map(|pos| pos.x * row_factor + pos.y * col_factor)
+        .sum()
+}
+
+/// Fonction pour calculer la somme GPS de toutes les cases de la grille égales à `target`
+/// (par exemple `'O'`, ou `'['` pour l'entrepôt élargi)
+pub fn gps_sum_from_grid<T: PartialEq>(
+    grid: &Grid<T>,
+    target: &T,
+    row_factor: i64,
+    col_factor: i64,
+) -> i64 {
+    gps_sum(grid.find_all(target), row_factor, col_factor)
+}
@@ -0,0 +1,55 @@
+// Importation des bibliothèques nécessaires
+use crate::direction::Direction8; // Les huit directions de lecture
+use crate::grid::Grid; // La grille de lettres
+use crate::point::Point2D; // Position de départ d'un mot
+
+/// Fonction pour trouver toutes les occurrences d'un mot dans une grille de lettres (jour 4, partie 1)
+/// Le mot peut être écrit dans chacune des huit directions ; chaque occurrence est donnée par
+/// la position de sa première lettre et sa direction de lecture
+/// Les lectures qui sortiraient de la grille ne comptent pas
+pub fn word_search(grid: &Grid<char>, word: &str) -> Vec<(Point2D<i64>, Direction8)> {
+    let len = word.chars().count();
+    let first = match word.chars().next() {
+        Some(c) => c,
+        None => return Vec::new(), // Le mot vide n'a pas d'occurrence
+    };
+
+    grid.find_all(&first)
+        .into_iter()
+        .flat_map(|start| Direction8::all().into_iter().map(move |dir| (start, dir)))
+        .filter(|&(start, dir)| grid.chars_in_direction(start, dir, len).as_deref() == Some(word))
+        .collect()
+}
+
+/// Fonction pour compter les occurrences d'un mot dans les huit directions
+pub fn word_search_count(grid: &Grid<char>, word: &str) -> usize {
+    word_search(grid, word).len()
+}
+
+/// Fonction pour compter les croix formées par deux occurrences diagonales de `arm` (jour 4, partie 2)
+/// Une croix est centrée sur une case dont les deux diagonales (nord-ouest vers sud-est et
+/// nord-est vers sud-ouest) se lisent `arm` dans un sens ou dans l'autre
+/// `arm` doit avoir une longueur impaire : son centre est à `len / 2` de chaque extrémité
+pub fn find_x_pattern(grid: &Grid<char>, arm: &str) -> usize {
+    let len = arm.chars().count();
+    assert!(
+        len % 2 == 1,
+        "la branche d'une croix doit avoir une longueur impaire"
+    );
+    let reversed: String = arm.chars().rev().collect();
+    let reach = (len / 2) as i64;
+
+    // Une diagonale convient si on y lit la branche dans un sens ou dans l'autre
+    let diagonal_matches = |center: Point2D<i64>, dir: Direction8| {
+        let start = center - dir.delta() * reach;
+        grid.chars_in_direction(start, dir, len)
+            .is_some_and(|text| text == arm || text == reversed)
+    };
+
+    grid.positions()
+        .filter(|&center| {
+            diagonal_matches(center, Direction8::SouthEast)
+                && diagonal_matches(center, Direction8::SouthWest)
+        })
+        .count()
+}
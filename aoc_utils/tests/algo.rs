@@ -0,0 +1,200 @@
+//! Tests des utilitaires partagés : mémoïsation, détection de cycles, comptage et recherche de quine
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::algo::{
+    find_cycle, find_cycle_and_jump, find_cycle_floyd, memoize, quine_search, Memo,
+}; // Les algorithmes testés
+use aoc_utils::counter::Counter; // Le compteur testé
+
+/// Suite `x -> x² + 1 mod 255` à partir de 3 : 3, 10, 101, 2, 5, 26, 167, 95, 101, ...
+/// Le cycle commence à l'indice 2 (101) et compte 6 états
+fn next(x: &u64) -> u64 {
+    (x * x + 1) % 255
+}
+
+#[test]
+fn memoize_computes_deep_recursion() {
+    let fib = memoize(
+        |recur, n: u64| {
+            if n < 2 {
+                n
+            } else {
+                recur(n - 1) + recur(n - 2)
+            }
+        },
+        90,
+    );
+    assert_eq!(fib, 2_880_067_194_370_816_120);
+}
+
+#[test]
+fn memo_keeps_its_cache_between_calls() {
+    // Nombre de chemins monotones dans une grille, qui partagent leurs sous-problèmes
+    let mut paths = Memo::new(|recur, (x, y): (u32, u32)| -> u64 {
+        if x == 0 || y == 0 {
+            1
+        } else {
+            recur((x - 1, y)) + recur((x, y - 1))
+        }
+    });
+    assert!(paths.is_empty());
+    assert_eq!(paths.get((2, 2)), 6);
+    let cached = paths.len();
+    assert_eq!(paths.get((1, 1)), 2);
+    assert_eq!(paths.len(), cached, "(1, 1) était déjà en cache");
+    assert_eq!(paths.get((16, 16)), 601_080_390);
+}
+
+#[test]
+fn brent_and_floyd_find_the_same_cycle() {
+    assert_eq!(find_cycle(&3, next), (2, 6));
+    assert_eq!(find_cycle_floyd(&3, next), (2, 6));
+    // Une suite qui boucle dès le départ
+    assert_eq!(find_cycle(&0, |&x| (x + 1) % 7), (0, 7));
+    assert_eq!(find_cycle_floyd(&0, |&x| (x + 1) % 7), (0, 7));
+}
+
+#[test]
+fn jump_matches_simulation() {
+    let mut state = 3;
+    for step in 0..50 {
+        assert_eq!(find_cycle_and_jump(3, next, step), state, "étape {}", step);
+        state = next(&state);
+    }
+    // Un milliard d'étapes : 2 avant le cycle, puis 999 999 998 % 6 = 2 dans le cycle
+    assert_eq!(find_cycle_and_jump(3, next, 1_000_000_000), 5);
+}
+
+#[test]
+fn jump_on_a_modular_counter() {
+    // Un compteur modulo 7 boucle dès le départ
+    let step = |&x: &u64| (x + 1) % 7;
+    assert_eq!(find_cycle_and_jump(0, step, 0), 0);
+    assert_eq!(find_cycle_and_jump(0, step, 6), 6);
+    assert_eq!(
+        find_cycle_and_jump(0, step, 1_000_000_000_000),
+        1_000_000_000_000 % 7
+    );
+}
+
+#[test]
+fn jump_inside_the_tail() {
+    // 100 états avant un cycle de longueur 3 : 100, 101, 102, 100, ...
+    let step = |&x: &u64| if x < 100 { x + 1 } else { 100 + (x - 99) % 3 };
+    assert_eq!(find_cycle(&0, step), (100, 3));
+    assert_eq!(find_cycle_and_jump(0, step, 42), 42);
+    assert_eq!(find_cycle_and_jump(0, step, 99), 99);
+    assert_eq!(find_cycle_and_jump(0, step, 100), 100);
+    assert_eq!(find_cycle_and_jump(0, step, 104), 101);
+    assert_eq!(find_cycle_and_jump(0, step, 1_000_000_000), 100);
+}
+
+/// Une position ou une vitesse `(x, y)` de robot
+type XY = (i64, i64);
+
+/// Fait avancer des robots `(position, vitesse)` d'une seconde sur une grille torique
+fn step_robots(robots: &[(XY, XY)], width: i64, height: i64) -> Vec<XY> {
+    robots
+        .iter()
+        .map(|&((x, y), (vx, vy))| ((x + vx).rem_euclid(width), (y + vy).rem_euclid(height)))
+        .collect()
+}
+
+#[test]
+fn jump_on_a_robot_grid() {
+    // Trois robots sur la grille 11×7 de l'exemple du jour 14 : l'état revient après 77 secondes
+    let robots = [((0, 4), (3, -3)), ((6, 3), (-1, -3)), ((2, 0), (2, -1))];
+    let start: Vec<XY> = robots.iter().map(|&(pos, _)| pos).collect();
+    let step = |positions: &Vec<XY>| {
+        let moving: Vec<_> = positions
+            .iter()
+            .zip(&robots)
+            .map(|(&pos, &(_, vel))| (pos, vel))
+            .collect();
+        step_robots(&moving, 11, 7)
+    };
+
+    assert_eq!(find_cycle(&start, step), (0, 77));
+    let mut expected = start.clone();
+    for target in 0..=200 {
+        assert_eq!(
+            find_cycle_and_jump(start.clone(), step, target),
+            expected,
+            "étape {}",
+            target
+        );
+        expected = step(&expected);
+    }
+    assert_eq!(
+        find_cycle_and_jump(start.clone(), step, 1_000_000 * 77),
+        start
+    );
+}
+
+#[test]
+fn counter_counts_occurrences() {
+    let mut counter: Counter<char> = "abracadabra".chars().collect();
+    assert_eq!(counter.get(&'a'), 5);
+    assert_eq!(counter.get(&'z'), 0);
+    assert_eq!(counter.len(), 5);
+    assert_eq!(counter.total(), 11);
+    // `b` et `r` sont à égalité derrière `a`
+    let common = counter.most_common(2);
+    assert_eq!(common[0], (&'a', 5));
+    assert_eq!(common[1].1, 2);
+
+    counter.add('z');
+    counter.add_n('a', 2);
+    counter.extend("zz".chars());
+    assert_eq!(counter.get(&'a'), 7);
+    assert_eq!(counter.get(&'z'), 3);
+    assert_eq!(counter.most_common(1), vec![(&'a', 7)]);
+    assert!(Counter::<u8>::new().is_empty());
+}
+
+/// Ordinateur 3 bits du jour 17 : exécute le programme avec le registre A initialisé à `a`
+/// et retourne tout ce qu'il affiche
+fn run_computer(program: &[u8], a: u64) -> Vec<u8> {
+    let (mut a, mut b, mut c) = (a, 0u64, 0u64);
+    let mut output = Vec::new();
+    let mut ip = 0;
+    while ip + 1 < program.len() {
+        let (opcode, literal) = (program[ip], u64::from(program[ip + 1]));
+        let combo = match literal {
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => literal,
+        };
+        ip += 2;
+        match opcode {
+            0 => a >>= combo,
+            1 => b ^= literal,
+            2 => b = combo % 8,
+            3 if a != 0 => ip = literal as usize,
+            3 => {}
+            4 => b ^= c,
+            5 => output.push((combo % 8) as u8),
+            6 => b = a >> combo,
+            _ => c = a >> combo,
+        }
+    }
+    output
+}
+
+#[test]
+fn quine_search_finds_the_day_17_example() {
+    let program = [0, 3, 5, 4, 3, 0];
+    let a = quine_search(&program, |a| run_computer(&program, a));
+    assert_eq!(a, Some(117440));
+    // La valeur trouvée fait bien afficher le programme lui-même
+    assert_eq!(run_computer(&program, a.unwrap()), program);
+}
+
+#[test]
+fn quine_search_gives_up_without_solution() {
+    // Un ordinateur qui n'affiche jamais rien ne peut pas reproduire le programme
+    assert_eq!(quine_search(&[0, 3, 5, 4, 3, 0], |_| Vec::new()), None);
+    // Le programme vide est reproduit par la valeur 0
+    assert_eq!(quine_search(&[], |_| Vec::new()), Some(0));
+}
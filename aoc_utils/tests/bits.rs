@@ -0,0 +1,48 @@
+//! Tests des manipulations de bits
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::bits::{bits_to_u64, extract_bits, format_binary, set_bit, u64_to_bits}; // Les fonctions testées
+
+#[test]
+fn bits_round_trip() {
+    let mut rng = fastrand::Rng::with_seed(24);
+    let samples = [0, 1, u64::MAX, 1 << 63, 0xdead_beef].into_iter();
+    for x in samples.chain((0..1000).map(|_| rng.u64(..))) {
+        assert_eq!(bits_to_u64(&u64_to_bits(x, 64)), x, "{:#x}", x);
+    }
+}
+
+#[test]
+fn bits_start_with_the_least_significant() {
+    assert_eq!(u64_to_bits(0b110, 4), [false, true, true, false]);
+    assert_eq!(bits_to_u64(&[true, false, true]), 5);
+    assert_eq!(bits_to_u64(&[]), 0);
+    // Les bits au-delà du 64e sont nuls
+    assert_eq!(u64_to_bits(u64::MAX, 66)[63..], [true, false, false]);
+}
+
+#[test]
+fn extracts_a_bit_field() {
+    assert_eq!(extract_bits(0b11010, 1, 3), 0b101);
+    assert_eq!(extract_bits(u64::MAX, 0, 64), u64::MAX);
+    assert_eq!(extract_bits(u64::MAX, 60, 10), 0b1111);
+    assert_eq!(extract_bits(u64::MAX, 64, 3), 0);
+    assert_eq!(extract_bits(0b1010, 0, 0), 0);
+}
+
+#[test]
+fn sets_a_single_bit() {
+    let n = 0b1000;
+    assert_eq!(set_bit(n, 0, true), 0b1001);
+    assert_eq!(set_bit(n, 3, false), 0);
+    assert_eq!(set_bit(n, 3, true), n);
+    assert_eq!(set_bit(0, 63, true), 1 << 63);
+}
+
+#[test]
+fn formats_with_zero_padding() {
+    assert_eq!(format_binary(5, 8), "00000101");
+    assert_eq!(format_binary(0, 3), "000");
+    // Un nombre plus large que `width` n'est pas tronqué
+    assert_eq!(format_binary(0b10110, 2), "10110");
+}
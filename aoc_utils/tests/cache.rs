@@ -0,0 +1,141 @@
+//! Tests des caches de mémoïsation
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::cache::{LruCache, MemoizedDp}; // Les caches testés
+
+#[test]
+fn lru_evicts_the_least_recently_used_entry() {
+    let mut cache = LruCache::new(2);
+    cache.insert("a", 1);
+    cache.insert("b", 2);
+    cache.insert("c", 3);
+    // `a` était la plus ancienne entrée
+    assert_eq!(cache.get(&"a"), None);
+    assert_eq!(cache.get(&"b"), Some(&2));
+    assert_eq!(cache.get(&"c"), Some(&3));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.capacity(), 2);
+}
+
+#[test]
+fn lru_get_marks_the_entry_as_most_recent() {
+    let mut cache = LruCache::new(2);
+    cache.insert(1, "un");
+    cache.insert(2, "deux");
+    // La lecture de 1 en fait l'entrée la plus récente : c'est 2 qui est évincée
+    assert_eq!(cache.get(&1), Some(&"un"));
+    cache.insert(3, "trois");
+    assert_eq!(cache.get(&2), None);
+    assert_eq!(cache.get(&1), Some(&"un"));
+    assert_eq!(cache.get(&3), Some(&"trois"));
+}
+
+#[test]
+fn lru_get_or_insert_does_not_compute_on_a_hit() {
+    let mut cache = LruCache::new(4);
+    let mut calls = 0;
+    for _ in 0..3 {
+        let value = *cache.get_or_insert(7, || {
+            calls += 1;
+            49
+        });
+        assert_eq!(value, 49);
+    }
+    assert_eq!(calls, 1);
+    // Un remplacement ne change pas le nombre d'entrées
+    cache.insert(7, 50);
+    assert_eq!(cache.get(&7), Some(&50));
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn lru_with_capacity_one_keeps_only_the_last_key() {
+    let mut cache = LruCache::new(1);
+    for key in 0..5 {
+        cache.insert(key, key * 10);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get(&key), Some(&(key * 10)));
+    }
+    assert!((0..4).all(|key| cache.get(&key).is_none()));
+    assert!(!cache.is_empty());
+}
+
+fn fib(n: u64, dp: &mut MemoizedDp<u64, u64>) -> u64 {
+    dp.compute(n, |&n, dp| {
+        if n < 2 {
+            n
+        } else {
+            fib(n - 1, dp) + fib(n - 2, dp)
+        }
+    })
+}
+
+/// Nombre de pierres obtenues à partir d'une pierre après `blinks` clignements (jour 11)
+fn stones(stone: u64, blinks: u32, dp: &mut MemoizedDp<(u64, u32), u64>) -> u64 {
+    dp.compute((stone, blinks), |&(stone, blinks), dp| {
+        if blinks == 0 {
+            return 1;
+        }
+        let digits = stone.checked_ilog10().map_or(1, |d| d + 1);
+        if stone == 0 {
+            stones(1, blinks - 1, dp)
+        } else if digits % 2 == 0 {
+            let half = 10u64.pow(digits / 2);
+            stones(stone / half, blinks - 1, dp) + stones(stone % half, blinks - 1, dp)
+        } else {
+            stones(stone * 2024, blinks - 1, dp)
+        }
+    })
+}
+
+#[test]
+fn recursive_fibonacci() {
+    let mut dp = MemoizedDp::new();
+    assert_eq!(fib(10, &mut dp), 55);
+    assert_eq!(fib(90, &mut dp), 2_880_067_194_370_816_120);
+    // Chaque valeur de 0 à 90 n'est calculée qu'une fois
+    assert_eq!(dp.cache_size(), 91);
+}
+
+#[test]
+fn day_11_stone_blinking() {
+    let mut dp = MemoizedDp::new();
+    let count = |blinks, dp: &mut _| {
+        [125, 17]
+            .iter()
+            .map(|&s| stones(s, blinks, dp))
+            .sum::<u64>()
+    };
+    assert_eq!(count(6, &mut dp), 22);
+    assert_eq!(count(25, &mut dp), 55312);
+    assert!(dp.hit_rate() > 0.0, "les pierres se répètent");
+}
+
+#[test]
+fn hit_rate_counts_cache_hits() {
+    let mut dp: MemoizedDp<u64, u64> = MemoizedDp::new();
+    assert_eq!(dp.hit_rate(), 0.0);
+    dp.compute(1, |&k, _| k * 10);
+    dp.compute(1, |_, _| unreachable!("la valeur est en cache"));
+    dp.compute(2, |&k, _| k * 10);
+    dp.compute(1, |_, _| unreachable!("la valeur est en cache"));
+    assert_eq!(dp.cache_size(), 2);
+    assert_eq!(dp.hit_rate(), 0.5);
+}
+
+#[test]
+fn precompute_warms_the_cache() {
+    let mut dp = MemoizedDp::new();
+    // Calculé dans l'ordre, chaque terme ne fait qu'appeler deux valeurs déjà en cache
+    dp.precompute(0..=1000u64, |n, dp| {
+        if n < 2 {
+            n
+        } else {
+            let a = dp.compute(n - 1, |_, _| unreachable!());
+            let b = dp.compute(n - 2, |_, _| unreachable!());
+            (a + b) % 1_000_000_007
+        }
+    });
+    assert_eq!(dp.cache_size(), 1001);
+    assert_eq!(dp.compute(1000, |_, _| unreachable!()), 517691607);
+}
@@ -0,0 +1,171 @@
+//! Tests du circuit de portes logiques du jour 24
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::circuit::{validate_adder_circuit, GateCircuit}; // Les fonctions testées
+use std::collections::HashMap; // Pour les valeurs des fils
+
+const SMALL: &str = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02";
+
+const LARGER: &str = "\
+x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj";
+
+fn simulate(input: &str) -> HashMap<&str, u8> {
+    let (mut wires, gates) = GateCircuit::parse(input);
+    GateCircuit::new(gates).simulate(&mut wires);
+    wires
+}
+
+#[test]
+fn small_example_outputs() {
+    let wires = simulate(SMALL);
+    assert_eq!(wires["z00"], 0);
+    assert_eq!(wires["z01"], 0);
+    assert_eq!(wires["z02"], 1);
+    assert_eq!(GateCircuit::read_bus(&wires, "z"), 4);
+    assert_eq!(GateCircuit::read_bus(&wires, "x"), 7);
+}
+
+#[test]
+fn larger_example_wire_values() {
+    let wires = simulate(LARGER);
+    let expected = "bfw:1 bqk:1 djm:1 ffh:0 fgs:1 frj:1 fst:1 gnj:1 hwm:1 kjc:0 kpj:1 kwq:0 \
+        mjb:1 nrd:1 ntg:0 pbm:1 psh:1 qhw:1 rvg:0 tgd:0 tnw:1 vdt:1 wpb:0 z00:0 z01:0 z02:0 \
+        z03:1 z04:0 z05:1 z06:1 z07:1 z08:1 z09:1 z10:1 z11:0 z12:0";
+    for pair in expected.split_whitespace() {
+        let (wire, value) = pair.split_once(':').unwrap();
+        assert_eq!(wires[wire].to_string(), value, "fil {}", wire);
+    }
+    assert_eq!(GateCircuit::read_bus(&wires, "z"), 2024);
+}
+
+/// Construit un additionneur à propagation de retenue sur `bits` bits, en échangeant
+/// éventuellement les fils de sortie de deux portes
+fn ripple_carry_adder(bits: usize, swap: Option<(&str, &str)>) -> String {
+    let mut gates = vec![
+        "x00 XOR y00 -> z00".to_string(),
+        "x00 AND y00 -> c00".to_string(),
+    ];
+    for i in 1..bits {
+        let carry_out = if i == bits - 1 {
+            format!("z{:02}", bits)
+        } else {
+            format!("c{:02}", i)
+        };
+        gates.push(format!("x{i:02} XOR y{i:02} -> s{i:02}"));
+        gates.push(format!("s{i:02} XOR c{:02} -> z{i:02}", i - 1));
+        gates.push(format!("x{i:02} AND y{i:02} -> a{i:02}"));
+        gates.push(format!("s{i:02} AND c{:02} -> b{i:02}", i - 1));
+        gates.push(format!("a{i:02} OR b{i:02} -> {}", carry_out));
+    }
+
+    let swap_output = |gate: String| {
+        let (left, output) = gate.split_once(" -> ").unwrap();
+        match swap {
+            Some((a, b)) if output == a => format!("{} -> {}", left, b),
+            Some((a, b)) if output == b => format!("{} -> {}", left, a),
+            _ => gate,
+        }
+    };
+    gates
+        .into_iter()
+        .map(swap_output)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Calcule `x + y` avec le circuit
+fn add(circuit: &str, bits: usize, x: u64, y: u64) -> u64 {
+    let (_, gates) = GateCircuit::parse(circuit);
+    let names: Vec<(String, String)> = (0..bits)
+        .map(|i| (format!("x{:02}", i), format!("y{:02}", i)))
+        .collect();
+    let mut wires = HashMap::new();
+    for (i, (x_name, y_name)) in names.iter().enumerate() {
+        wires.insert(x_name.as_str(), ((x >> i) & 1) as u8);
+        wires.insert(y_name.as_str(), ((y >> i) & 1) as u8);
+    }
+    GateCircuit::new(gates).simulate(&mut wires);
+    GateCircuit::read_bus(&wires, "z")
+}
+
+#[test]
+fn correct_adder_has_no_wrong_wire() {
+    let circuit = ripple_carry_adder(4, None);
+    for (x, y) in [(0, 0), (5, 9), (15, 15), (7, 8)] {
+        assert_eq!(add(&circuit, 4, x, y), x + y);
+    }
+    let (_, gates) = GateCircuit::parse(&circuit);
+    assert!(validate_adder_circuit(&gates).is_empty());
+}
+
+#[test]
+fn swapped_outputs_are_flagged() {
+    let circuit = ripple_carry_adder(4, Some(("z02", "a02")));
+    assert_ne!(add(&circuit, 4, 4, 4), 8, "l'échange fausse l'addition");
+    let (_, gates) = GateCircuit::parse(&circuit);
+    assert_eq!(validate_adder_circuit(&gates), ["a02", "z02"]);
+}
+
+#[test]
+fn swapped_sum_bit_is_flagged() {
+    // `x02 XOR y02` produit directement `z02`, et le `XOR` de la retenue produit `s02`
+    let circuit = ripple_carry_adder(4, Some(("z02", "s02")));
+    let (_, gates) = GateCircuit::parse(&circuit);
+    assert_eq!(validate_adder_circuit(&gates), ["s02", "z02"]);
+}
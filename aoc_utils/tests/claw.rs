@@ -0,0 +1,74 @@
+//! Tests des machines à pince du jour 13
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::claw::ClawMachine; // La machine testée
+
+const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+/// Décalage des prix de la partie 2
+const OFFSET: i64 = 10_000_000_000_000;
+
+#[test]
+fn parses_all_machines() {
+    let machines = ClawMachine::parse_all(EXAMPLE);
+    assert_eq!(machines.len(), 4);
+    assert_eq!(
+        machines[0],
+        (
+            ClawMachine {
+                ax: 94,
+                ay: 34,
+                bx: 22,
+                by: 67
+            },
+            (8400, 5400)
+        )
+    );
+}
+
+#[test]
+fn example_token_costs() {
+    let costs: Vec<Option<i64>> = ClawMachine::parse_all(EXAMPLE)
+        .iter()
+        .map(|(machine, (px, py))| machine.solve(*px, *py))
+        .collect();
+    assert_eq!(costs, [Some(280), None, Some(200), None]);
+}
+
+#[test]
+fn offset_changes_solvability() {
+    let solvable: Vec<bool> = ClawMachine::parse_all(EXAMPLE)
+        .iter()
+        .map(|(machine, (px, py))| machine.solve_with_offset(*px, *py, OFFSET).is_some())
+        .collect();
+    // Avec le décalage, seules la deuxième et la quatrième machine ont une solution
+    assert_eq!(solvable, [false, true, false, true]);
+    let (machine, (px, py)) = ClawMachine::parse_all(EXAMPLE)[1];
+    assert_eq!(
+        machine.solve_with_offset(px, py, OFFSET),
+        Some(459236326669)
+    );
+    assert_eq!(machine.solve_with_offset(px, py, 0), machine.solve(px, py));
+}
+
+#[test]
+fn offset_overflow_has_no_solution() {
+    let (machine, (px, py)) = ClawMachine::parse_all(EXAMPLE)[0];
+    assert_eq!(machine.solve_with_offset(px, py, i64::MAX), None);
+}
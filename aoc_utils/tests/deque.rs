@@ -0,0 +1,60 @@
+//! Tests des files à double extrémité
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::deque::{CircularDeque, Deque}; // Les files testées
+
+#[test]
+fn rotates_left() {
+    let mut deque: Deque<u8> = (1..=5).collect();
+    deque.rotate_left(2);
+    assert_eq!(deque.to_vec(), [3, 4, 5, 1, 2]);
+    // Le décalage est pris modulo la longueur
+    deque.rotate_left(13);
+    assert_eq!(deque.to_vec(), [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn rotates_right() {
+    let mut deque = Deque::from(vec!['a', 'b', 'c', 'd']);
+    deque.rotate_right(1);
+    assert_eq!(deque.to_vec(), ['d', 'a', 'b', 'c']);
+    deque.rotate_right(4);
+    assert_eq!(deque.to_vec(), ['d', 'a', 'b', 'c']);
+    // Une rotation à droite annule une rotation à gauche
+    deque.rotate_left(3);
+    deque.rotate_right(3);
+    assert_eq!(deque.to_vec(), ['d', 'a', 'b', 'c']);
+}
+
+#[test]
+fn rotating_an_empty_deque_does_nothing() {
+    let mut deque: Deque<i32> = Deque::new();
+    deque.rotate_left(3);
+    deque.rotate_right(7);
+    assert!(deque.to_vec().is_empty());
+    // Les méthodes de `VecDeque` restent accessibles
+    deque.push_front(1);
+    deque.push_back(2);
+    assert_eq!(deque.pop_front(), Some(1));
+    assert_eq!(deque.into_inner().into_iter().collect::<Vec<_>>(), [2]);
+}
+
+#[test]
+fn circular_deque_evicts_at_the_other_end() {
+    let mut deque = CircularDeque::new(3);
+    assert_eq!(deque.push_back(1), None);
+    assert_eq!(deque.push_back(2), None);
+    assert_eq!(deque.push_back(3), None);
+    assert!(deque.is_full());
+    // Pleine : un ajout à la fin évince le début, et inversement
+    assert_eq!(deque.push_back(4), Some(1));
+    assert_eq!(deque.push_front(0), Some(4));
+    assert_eq!(deque.iter().copied().collect::<Vec<_>>(), [0, 2, 3]);
+    assert_eq!(
+        (deque.front(), deque.back(), deque.get(1)),
+        (Some(&0), Some(&3), Some(&2))
+    );
+    assert_eq!(deque.pop_back(), Some(3));
+    assert_eq!(deque.pop_front(), Some(0));
+    assert_eq!((deque.len(), deque.capacity()), (1, 3));
+}
@@ -0,0 +1,38 @@
+//! Tests de la défragmentation du disque du jour 9
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::disk::{checksum, compact_blocks, compact_files, disk_map_expand}; // Les fonctions testées
+
+const EXAMPLE: &str = "2333133121414131402";
+
+/// Représentation du disque de l'énoncé : un chiffre par bloc de fichier, `.` pour un bloc libre
+fn render(disk: &[Option<u64>]) -> String {
+    disk.iter()
+        .map(|block| block.map_or('.', |id| char::from_digit(id as u32, 10).unwrap()))
+        .collect()
+}
+
+#[test]
+fn expands_dense_map() {
+    assert_eq!(render(&disk_map_expand("12345")), "0..111....22222");
+    assert_eq!(
+        render(&disk_map_expand(EXAMPLE)),
+        "00...111...2...333.44.5555.6666.777.888899"
+    );
+}
+
+#[test]
+fn block_compaction_checksum() {
+    let mut disk = disk_map_expand(EXAMPLE);
+    compact_blocks(&mut disk);
+    assert_eq!(render(&disk), "0099811188827773336446555566..............");
+    assert_eq!(checksum(&disk), 1928);
+}
+
+#[test]
+fn file_compaction_checksum() {
+    let mut disk = disk_map_expand(EXAMPLE);
+    compact_files(&mut disk);
+    assert_eq!(render(&disk), "00992111777.44.333....5555.6666.....8888..");
+    assert_eq!(checksum(&disk), 2858);
+}
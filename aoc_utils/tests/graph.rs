@@ -0,0 +1,241 @@
+//! Tests des utilitaires de graphes
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::graph::{
+    build_directed, build_undirected, is_valid_subset_order, maximum_clique,
+    maximum_clique_password, parse_adjacency_pairs, parse_multi_adjacency,
+    topological_evaluation_order, topological_sort_subset, triangle_count_with_prefix,
+}; // Les fonctions testées
+use std::collections::{HashMap, HashSet}; // Pour le graphe à clés possédées
+
+/// Réseau de l'exemple du jour 23
+const LAN: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn";
+
+#[test]
+fn parses_the_lan_format() {
+    let pairs = parse_adjacency_pairs(LAN, "-");
+    assert_eq!(pairs.len(), 32);
+    assert_eq!(pairs[0], ("kh", "tc"));
+    assert_eq!(pairs[31], ("td", "yn"));
+    // Les lignes vides ou sans séparateur sont ignorées
+    assert_eq!(parse_adjacency_pairs("ab-cd\n\nxyz\n", "-"), [("ab", "cd")]);
+}
+
+#[test]
+fn undirected_edges_go_both_ways() {
+    let graph = build_undirected(&parse_adjacency_pairs("ab-cd\ncd-ef", "-"));
+    assert!(graph["ab"].contains("cd"));
+    assert!(graph["cd"].contains("ab"));
+    assert_eq!(graph["cd"], HashSet::from(["ab", "ef"]));
+    // Le réseau de l'exemple : `kh` a quatre voisins
+    let lan = build_undirected(&parse_adjacency_pairs(LAN, "-"));
+    assert_eq!(lan.len(), 16);
+    assert_eq!(lan["kh"], HashSet::from(["tc", "qp", "ub", "ta"]));
+}
+
+#[test]
+fn directed_edges_go_one_way() {
+    let graph = build_directed(&parse_adjacency_pairs("ab-cd", "-"));
+    assert!(graph["ab"].contains("cd"));
+    // La cible apparaît sans successeur
+    assert!(graph["cd"].is_empty());
+}
+
+#[test]
+fn parses_nodes_with_their_neighbors() {
+    let graph = parse_multi_adjacency("a: b c d\nb: c\ne:\n", ":", " ");
+    assert_eq!(graph["a"], ["b", "c", "d"]);
+    assert_eq!(graph["b"], ["c"]);
+    // Un nœud sans voisin a une liste vide
+    assert!(graph["e"].is_empty());
+    assert_eq!(graph.len(), 3);
+}
+
+#[test]
+fn lan_example_triangles() {
+    let graph = build_undirected(&parse_adjacency_pairs(LAN, "-"));
+    assert_eq!(triangle_count_with_prefix(&graph, 't'), 7);
+    // Aucun ordinateur ne commence par `z`
+    assert_eq!(triangle_count_with_prefix(&graph, 'z'), 0);
+}
+
+#[test]
+fn lan_example_password() {
+    let graph = build_undirected(&parse_adjacency_pairs(LAN, "-"));
+    assert_eq!(maximum_clique(&graph), ["co", "de", "ka", "ta"]);
+    let owned: HashMap<String, HashSet<String>> = graph
+        .iter()
+        .map(|(node, neighbors)| {
+            let neighbors = neighbors.iter().map(|n| n.to_string()).collect();
+            (node.to_string(), neighbors)
+        })
+        .collect();
+    assert_eq!(maximum_clique_password(&owned), "co,de,ka,ta");
+}
+
+#[test]
+fn complete_triangle_of_t_nodes() {
+    let graph = build_undirected(&parse_adjacency_pairs("ta-tb\ntb-tc\ntc-ta", "-"));
+    assert_eq!(triangle_count_with_prefix(&graph, 't'), 1);
+    assert_eq!(maximum_clique(&graph), ["ta", "tb", "tc"]);
+}
+
+/// Règles d'ordre de l'exemple du jour 5
+const PAGE_RULES: &str = "47|53 97|13 97|61 97|47 75|29 61|13 75|53 29|13 97|29 53|29 61|53 97|53 \
+                          61|29 47|13 75|47 97|75 47|61 75|61 47|29 75|13 53|13";
+
+/// Mises à jour de l'exemple du jour 5
+const UPDATES: [&[u32]; 6] = [
+    &[75, 47, 61, 53, 29],
+    &[97, 61, 53, 29, 13],
+    &[75, 29, 13],
+    &[75, 97, 47, 61, 53],
+    &[61, 13, 29],
+    &[97, 13, 75, 29, 47],
+];
+
+fn page_rules() -> Vec<(u32, u32)> {
+    PAGE_RULES
+        .split_whitespace()
+        .map(|rule| {
+            let (before, after) = rule.split_once('|').unwrap();
+            (before.parse().unwrap(), after.parse().unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn checks_the_day_5_updates() {
+    let rules = page_rules();
+    let valid: Vec<bool> = UPDATES
+        .iter()
+        .map(|update| is_valid_subset_order(update, &rules))
+        .collect();
+    assert_eq!(valid, [true, true, true, false, false, false]);
+}
+
+#[test]
+fn reorders_the_day_5_updates() {
+    let rules = page_rules();
+    let fixed: Vec<Vec<u32>> = UPDATES[3..]
+        .iter()
+        .map(|update| topological_sort_subset(update, &rules).unwrap())
+        .collect();
+    assert_eq!(
+        fixed,
+        [
+            vec![97, 75, 47, 61, 53],
+            vec![61, 29, 13],
+            vec![97, 75, 47, 29, 13]
+        ]
+    );
+    assert!(fixed
+        .iter()
+        .all(|order| is_valid_subset_order(order, &rules)));
+    // Somme des pages du milieu pour la partie 2
+    assert_eq!(
+        fixed
+            .iter()
+            .map(|order| order[order.len() / 2])
+            .sum::<u32>(),
+        123
+    );
+    // Une mise à jour déjà correcte est inchangée
+    assert_eq!(
+        topological_sort_subset(UPDATES[0], &rules).unwrap(),
+        UPDATES[0]
+    );
+}
+
+#[test]
+fn ignores_edges_outside_the_subset() {
+    // 1 -> 2 -> 3 : sans 2, aucune règle ne relie 1 et 3
+    let edges = [(1, 2), (2, 3)];
+    assert!(is_valid_subset_order(&[3, 1], &edges));
+    assert_eq!(topological_sort_subset(&[3, 1], &edges), Ok(vec![3, 1]));
+    assert!(!is_valid_subset_order(&[3, 2, 1], &edges));
+}
+
+#[test]
+fn reports_the_nodes_of_a_cycle() {
+    let edges = [("a", "b"), ("b", "c"), ("c", "b"), ("d", "a")];
+    assert_eq!(
+        topological_sort_subset(&["a", "b", "c", "d"], &edges),
+        Err(vec!["b", "c"])
+    );
+}
+
+/// Ordre d'évaluation de `nodes`, chaque arête `(a, b)` signifiant que `a` dépend de `b`
+fn evaluation_order<'a>(
+    nodes: &'a [&'a str],
+    depends_on: &[(&str, &str)],
+) -> Result<Vec<&'a str>, &'a str> {
+    topological_evaluation_order(nodes, |node| {
+        nodes
+            .iter()
+            .filter(|dep| depends_on.contains(&(*node, **dep)))
+            .collect()
+    })
+    .map(|order| order.into_iter().copied().collect())
+    .map_err(|node| *node)
+}
+
+#[test]
+fn evaluates_a_chain_from_its_end() {
+    let nodes = ["z00", "y", "x"];
+    let order = evaluation_order(&nodes, &[("z00", "y"), ("y", "x")]);
+    assert_eq!(order, Ok(vec!["x", "y", "z00"]));
+}
+
+#[test]
+fn evaluates_a_diamond_once_per_node() {
+    // `d` dépend de `b` et `c`, qui dépendent tous deux de `a`
+    let nodes = ["d", "c", "b", "a"];
+    let order =
+        evaluation_order(&nodes, &[("d", "b"), ("d", "c"), ("b", "a"), ("c", "a")]).unwrap();
+    assert_eq!(order.len(), 4);
+    let position = |node| order.iter().position(|&n| n == node).unwrap();
+    assert_eq!(position("a"), 0);
+    assert!(position("b") < position("d") && position("c") < position("d"));
+}
+
+#[test]
+fn reports_a_dependency_cycle() {
+    let nodes = ["a", "b", "c", "d"];
+    let cycle = evaluation_order(&nodes, &[("a", "b"), ("b", "c"), ("c", "a"), ("d", "a")]);
+    assert!(matches!(cycle, Err("a" | "b" | "c")), "{:?}", cycle);
+    // Un nœud qui dépend de lui-même est un cycle
+    assert_eq!(evaluation_order(&nodes, &[("d", "d")]), Err("d"));
+}
@@ -0,0 +1,221 @@
+//! Tests de la grille
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::direction::Direction; // Les côtés exposés d'une case
+use aoc_utils::grid::{assert_grid_eq, format_grid_diff, Grid}; // La grille et ses outils de comparaison
+use aoc_utils::point::Point2D; // Les positions des cases
+use std::collections::HashSet; // Pour les cases mises en évidence
+
+#[test]
+fn highlights_the_center_row() {
+    let grid = Grid::parse("abcde\nfghij\nklmno\npqrst\nuvwxy", |c| c);
+    // Toute la ligne du milieu d'une grille 5×5
+    let highlight: HashSet<Point2D<i64>> = (0..5).map(|col| Point2D::new(2, col)).collect();
+    let output = grid.display_with_highlight(&highlight, |&c| c.to_ascii_uppercase(), '#');
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 5);
+    assert!(lines[2].chars().all(|c| c == '#'));
+    assert_eq!(lines[2].len(), 5);
+    // Les autres lignes sont rendues par `cell_fn`
+    assert_eq!(lines[0], "ABCDE");
+    assert_eq!(lines[1], "FGHIJ");
+    assert_eq!(lines[3], "PQRST");
+    assert_eq!(lines[4], "UVWXY");
+    assert!(output.ends_with('\n'));
+}
+
+#[test]
+fn renders_the_grid_unchanged_without_highlight() {
+    let grid = Grid::parse("#.\n.#", |c| c == '#');
+    let output =
+        grid.display_with_highlight(&HashSet::new(), |&wall| if wall { '#' } else { '.' }, 'X');
+    assert_eq!(output, "#.\n.#\n");
+}
+
+#[test]
+fn diff_marks_the_changed_cells() {
+    let expected = Grid::parse("abc\ndef\nghi", |c| c);
+    let actual = Grid::parse("abc\ndXf\nghZ", |c| c);
+    assert_eq!(
+        format_grid_diff(&expected, &actual),
+        "\
+attendu  obtenu  diff
+abc      abc     ...
+def      dXf     .!.
+ghi      ghZ     ..!
+"
+    );
+}
+
+#[test]
+fn diff_of_grids_with_different_sizes() {
+    let expected = Grid::parse("12\n34", |c| c.to_digit(10).unwrap());
+    let actual = Grid::parse("12", |c| c.to_digit(10).unwrap());
+    let diff = format_grid_diff(&expected, &actual);
+    // La ligne absente de la grille obtenue est entièrement différente
+    assert_eq!(diff.lines().nth(1), Some("12       12      .."));
+    assert_eq!(diff.lines().nth(2), Some("34               !!"));
+}
+
+#[test]
+fn equal_grids_pass_the_assertion() {
+    let grid = Grid::parse("#.\n.#", |c| c);
+    assert_grid_eq(&grid, &grid.clone());
+}
+
+#[test]
+#[should_panic(expected = "les grilles diffèrent")]
+fn different_grids_fail_the_assertion() {
+    assert_grid_eq(&Grid::parse("#.", |c| c), &Grid::parse(".#", |c| c));
+}
+
+#[test]
+fn map_transforms_every_cell() {
+    let walls = Grid::parse("#.#\n..#", |c| c).map(|c| c == '#');
+    assert_eq!(
+        walls,
+        Grid::from_vec(3, 2, vec![true, false, true, false, false, true])
+    );
+    // Avec la position : la somme ligne + colonne
+    let sums = walls.map_with_pos(|pos, _| pos.x + pos.y);
+    assert_eq!(sums, Grid::from_vec(3, 2, vec![0, 1, 2, 1, 2, 3]));
+}
+
+#[test]
+fn zip_with_combines_two_grids() {
+    let before = Grid::parse("123\n456", |c| c.to_digit(10).unwrap());
+    let after = Grid::parse("103\n056", |c| c.to_digit(10).unwrap());
+    let changed = before.zip_with(after, |a, b| if a == b { '.' } else { 'x' });
+    assert_eq!(changed, Grid::parse(".x.\nx..", |c| c));
+}
+
+#[test]
+#[should_panic(expected = "grilles de tailles différentes")]
+fn zip_with_rejects_different_sizes() {
+    let _ = Grid::parse("ab", |c| c).zip_with(Grid::parse("a\nb", |c| c), |a, b| a == b);
+}
+
+#[test]
+fn filter_map_keeps_matching_positions() {
+    let digits = Grid::parse("a1b\n2c3", |c| c).filter_map_positions(|_, c| c.to_digit(10));
+    assert_eq!(
+        digits,
+        [
+            (Point2D::new(0, 1), 1),
+            (Point2D::new(1, 0), 2),
+            (Point2D::new(1, 2), 3)
+        ]
+    );
+    // La position est aussi fournie à la fonction
+    let diagonal =
+        Grid::parse("ab\ncd", |c| c).filter_map_positions(|pos, c| (pos.x == pos.y).then_some(c));
+    assert_eq!(
+        diagonal,
+        [(Point2D::new(0, 0), 'a'), (Point2D::new(1, 1), 'd')]
+    );
+}
+
+/// Plus grand exemple de régions du jour 12 : 11 régions
+const GARDEN: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+#[test]
+fn uniform_grid_is_one_component() {
+    let grid = Grid::parse("aaaa\naaaa\naaaa", |c| c);
+    assert_eq!(grid.connected_components_count(|a, b| a == b), 1);
+    assert_eq!(
+        grid.component_sizes(|a, b| a == b)
+            .into_values()
+            .collect::<Vec<_>>(),
+        [12]
+    );
+}
+
+#[test]
+fn checkerboard_has_one_component_per_cell() {
+    let (rows, cols) = (5, 6);
+    let cells = (0..rows * cols)
+        .map(|i| (i / cols + i % cols) % 2 == 0)
+        .collect();
+    let grid = Grid::from_vec(cols, rows, cells);
+    assert_eq!(grid.connected_components_count(|a, b| a == b), rows * cols);
+    assert!(grid
+        .component_sizes(|a, b| a == b)
+        .values()
+        .all(|&size| size == 1));
+}
+
+#[test]
+fn component_sizes_cover_the_whole_grid() {
+    let grid = Grid::parse(GARDEN, |c| c);
+    let sizes = grid.component_sizes(|a, b| a == b);
+    assert_eq!(grid.connected_components_count(|a, b| a == b), 11);
+    assert_eq!(sizes.len(), 11);
+    assert_eq!(sizes.values().sum::<usize>(), 100);
+    // Les deux régions `C` sont distinctes : l'une de 14 cases, l'autre d'une seule
+    let mut sizes: Vec<usize> = sizes.into_values().collect();
+    sizes.sort_unstable();
+    assert_eq!(sizes, [1, 3, 4, 5, 10, 11, 12, 13, 13, 14, 14]);
+}
+
+/// Cases d'une région, données par leurs coordonnées `(ligne, colonne)`
+fn region(cells: &[(i64, i64)]) -> HashSet<Point2D<i64>> {
+    cells
+        .iter()
+        .map(|&(row, col)| Point2D::new(row, col))
+        .collect()
+}
+
+#[test]
+fn perimeter_of_squares() {
+    let grid = Grid::parse("...\n...\n...", |c| c);
+    assert_eq!(grid.perimeter_of_region(&region(&[(1, 1)])), 4);
+    assert_eq!(
+        grid.perimeter_of_region(&region(&[(0, 0), (0, 1), (1, 0), (1, 1)])),
+        8
+    );
+    assert_eq!(grid.perimeter_of_region(&HashSet::new()), 0);
+}
+
+#[test]
+fn perimeter_of_an_l_shape() {
+    // Trois cases verticales et une à droite de la dernière : 10 bords
+    let grid = Grid::parse("...\n...\n...", |c| c);
+    let l_shape = region(&[(0, 0), (1, 0), (2, 0), (2, 1)]);
+    assert_eq!(grid.perimeter_of_region(&l_shape), 10);
+    // Le périmètre est celui de l'exemple du jour 12 (région `A` : 4 cases, 10 bords)
+    let garden = Grid::parse("AAAA\nBBCD\nBBCC\nEEEC", |c| c);
+    let a: HashSet<_> = garden.find_all(&'A').into_iter().collect();
+    assert_eq!(garden.perimeter_of_region(&a), 10);
+}
+
+#[test]
+fn grid_boundary_edges_are_exposed() {
+    // La case du coin touche deux bords de la grille, qui comptent comme exposés
+    let grid = Grid::parse("ab\ncd", |c| c);
+    let edges = grid.exposed_edges_of_region(&region(&[(0, 0), (0, 1)]));
+    assert_eq!(
+        edges,
+        [
+            (Point2D::new(0, 0), Direction::North),
+            (Point2D::new(0, 0), Direction::South),
+            (Point2D::new(0, 0), Direction::West),
+            (Point2D::new(0, 1), Direction::North),
+            (Point2D::new(0, 1), Direction::East),
+            (Point2D::new(0, 1), Direction::South),
+        ]
+    );
+    // Toute la grille : seuls ses bords extérieurs sont exposés
+    let all: HashSet<_> = grid.positions().collect();
+    assert_eq!(grid.perimeter_of_region(&all), 8);
+}
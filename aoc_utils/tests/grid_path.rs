@@ -0,0 +1,106 @@
+//! Tests des chemins dans une grille
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::grid::Grid; // Le labyrinthe
+use aoc_utils::grid_path::GridPath; // Le chemin testé
+use aoc_utils::point::Point2D; // Pour les cases du chemin
+
+const MAZE: &str = "\
+#########
+#S..#...#
+###.#.#.#
+#...#.#.#
+#.###.#.#
+#.....#E#
+#########";
+
+fn maze_path() -> (Grid<char>, GridPath) {
+    let grid = Grid::parse(MAZE, |c| c);
+    let (start, goal) = (grid.find(&'S').unwrap(), grid.find(&'E').unwrap());
+    let path = grid.bfs_path(start, goal, |&c| c != '#').unwrap();
+    (grid, path)
+}
+
+#[test]
+fn bfs_path_follows_the_maze() {
+    let (_, path) = maze_path();
+    assert_eq!(path.len(), 23);
+    assert_eq!(path.cost, 22);
+    assert_eq!(path.positions[0], Point2D::new(1, 1));
+    assert_eq!(path.positions[22], Point2D::new(5, 7));
+    assert!(path.contains(&Point2D::new(5, 3)));
+    assert!(!path.contains(&Point2D::new(1, 4)));
+    assert_eq!(path.visited_set().len(), path.len());
+}
+
+#[test]
+fn path_is_displayed_on_the_maze() {
+    let (grid, path) = maze_path();
+    let expected = "\
+#########
+#OOO#OOO#
+###O#O#O#
+#OOO#O#O#
+#O###O#O#
+#OOOOO#O#
+#########
+";
+    assert_eq!(path.display_on(&grid, 'O'), expected);
+}
+
+#[test]
+fn overlay_marks_only_path_cells() {
+    let (grid, path) = maze_path();
+    let overlay = path.to_grid_overlay(&grid.clone().map(|c| c == '#'), true);
+    // Les murs et le chemin sont marqués, il ne reste aucune case libre
+    assert_eq!(overlay.find_all(&false), []);
+    let overlay = path.to_grid_overlay(&grid, '*');
+    assert_eq!(overlay.find(&'S'), None);
+    assert_eq!(overlay.find_all(&'*').len(), 23);
+    assert_eq!(overlay.find_all(&'#'), grid.find_all(&'#'));
+}
+
+#[test]
+fn dijkstra_path_avoids_expensive_cells() {
+    let grid = Grid::parse("131\n191\n111", |c| c.to_digit(10).unwrap() as u64);
+    let path = grid
+        .dijkstra_path(Point2D::new(0, 0), Point2D::new(2, 2), |&cost| Some(cost))
+        .unwrap();
+    assert_eq!(path.cost, 4);
+    assert_eq!(
+        path.positions,
+        [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)].map(|(x, y)| Point2D::new(x, y))
+    );
+}
+
+#[test]
+fn unreachable_goal_has_no_path() {
+    let grid = Grid::parse("S#E", |c| c);
+    let (start, goal) = (Point2D::new(0, 0), Point2D::new(0, 2));
+    assert_eq!(grid.bfs_path(start, goal, |&c| c != '#'), None);
+    assert_eq!(
+        grid.dijkstra_path(start, goal, |&c| (c != '#').then_some(1)),
+        None
+    );
+    // Le chemin vers le départ lui-même ne contient que le départ
+    let path = grid.bfs_path(start, start, |&c| c != '#').unwrap();
+    assert_eq!((path.len(), path.cost), (1, 0));
+}
+
+#[test]
+fn zero_cost_path_terminates() {
+    // Toutes les cases sont à distance nulle : chaque voisin est un prédécesseur possible
+    let grid = Grid::parse("000\n000", |c| c);
+    let (start, goal) = (Point2D::new(0, 0), Point2D::new(1, 2));
+    let path = grid.dijkstra_path(start, goal, |_| Some(0)).unwrap();
+    assert_eq!(path.cost, 0);
+    assert_eq!(path.len(), 4);
+    assert_eq!(path.positions[0], start);
+    assert_eq!(path.positions[3], goal);
+    // Chaque pas relie deux cases voisines, sans repasser par une case
+    assert!(path
+        .positions
+        .windows(2)
+        .all(|step| (step[0].x - step[1].x).abs() + (step[0].y - step[1].y).abs() == 1));
+    assert_eq!(path.visited_set().len(), path.len());
+}
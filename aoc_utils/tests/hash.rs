@@ -0,0 +1,63 @@
+//! Tests des fonctions de hachage
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::hash::{aoc_hash, rolling_hash, rolling_hash_update}; // Les fonctions testées
+
+/// Base et module du hachage glissant testé
+const BASE: u64 = 131;
+const MOD: u64 = 1_000_000_007;
+
+#[test]
+fn hash_of_the_day_15_examples() {
+    assert_eq!(aoc_hash("HASH"), 52);
+    assert_eq!(aoc_hash(""), 0);
+    assert_eq!(aoc_hash("rn=1"), 30);
+    assert_eq!(aoc_hash("cm-"), 253);
+    let sequence = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+    let total: u32 = sequence
+        .split(',')
+        .map(|step| u32::from(aoc_hash(step)))
+        .sum();
+    assert_eq!(total, 1320);
+}
+
+#[test]
+fn rolling_hash_is_polynomial() {
+    assert_eq!(rolling_hash::<BASE, MOD>(""), 0);
+    assert_eq!(rolling_hash::<BASE, MOD>("a"), 97);
+    assert_eq!(rolling_hash::<BASE, MOD>("ab"), 97 * BASE + 98);
+    // Le résultat est réduit modulo `MOD` à chaque caractère
+    assert_eq!(rolling_hash::<10, 7>("123"), ((49 * 10 + 50) * 10 + 51) % 7);
+}
+
+#[test]
+fn sliding_window_matches_the_naive_hash() {
+    let text: Vec<char> = "le renard brun rapide saute par-dessus le chien paresseux"
+        .chars()
+        .collect();
+    for len in [1, 3, 8] {
+        let window = |start: usize| text[start..start + len].iter().collect::<String>();
+        let mut hash = rolling_hash::<BASE, MOD>(&window(0));
+        for start in 1..=text.len() - len {
+            hash =
+                rolling_hash_update::<BASE, MOD>(hash, text[start - 1], text[start + len - 1], len);
+            assert_eq!(
+                hash,
+                rolling_hash::<BASE, MOD>(&window(start)),
+                "fenêtre {:?}",
+                window(start)
+            );
+        }
+    }
+}
+
+#[test]
+fn equal_windows_have_equal_hashes() {
+    // Rabin-Karp : les deux occurrences de « le » ont le même hachage
+    let needle = rolling_hash::<BASE, MOD>("le ");
+    let text = "le chat et le chien";
+    let matches: Vec<usize> = (0..=text.len() - 3)
+        .filter(|&i| rolling_hash::<BASE, MOD>(&text[i..i + 3]) == needle)
+        .collect();
+    assert_eq!(matches, [0, 11]);
+}
@@ -0,0 +1,53 @@
+//! Tests de la grille infinie
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::infinite_grid::InfiniteGrid; // La grille testée
+use aoc_utils::point::Point2D; // Les positions des cases
+
+#[test]
+fn unset_cells_have_the_default_value() {
+    let mut grid: InfiniteGrid<u32> = InfiniteGrid::new();
+    assert_eq!(*grid.get(&Point2D::new(0, 0)), 0);
+    assert_eq!(grid.get_or_default(&Point2D::new(-1_000_000, 1_000_000)), 0);
+    grid.set(Point2D::new(3, -4), 7);
+    assert_eq!(*grid.get(&Point2D::new(3, -4)), 7);
+    assert_eq!(grid.get_or_default(&Point2D::new(3, -4)), 7);
+    // Les autres cases ne changent pas
+    assert_eq!(*grid.get(&Point2D::new(3, -3)), 0);
+}
+
+#[test]
+fn bounding_box_covers_every_set_cell() {
+    let mut grid: InfiniteGrid<bool> = InfiniteGrid::new();
+    assert_eq!(grid.active_bounding_box(), None);
+    grid.set(Point2D::new(2, 5), true);
+    assert_eq!(
+        grid.active_bounding_box(),
+        Some((Point2D::new(2, 5), Point2D::new(2, 5)))
+    );
+    grid.set(Point2D::new(-1, 8), true);
+    grid.set(Point2D::new(4, -3), true);
+    assert_eq!(
+        grid.active_bounding_box(),
+        Some((Point2D::new(-1, -3), Point2D::new(4, 8)))
+    );
+}
+
+#[test]
+fn region_iteration_covers_the_whole_rectangle() {
+    let mut grid: InfiniteGrid<char> = InfiniteGrid::new();
+    grid.set(Point2D::new(0, 1), '#');
+    grid.set(Point2D::new(1, -1), '#');
+    let cells: Vec<(Point2D<i64>, char)> = grid
+        .iter_region(Point2D::new(-1, -1), Point2D::new(1, 1))
+        .map(|(pos, &c)| (pos, c))
+        .collect();
+
+    // 3×3 cases, ligne par ligne, y compris celles jamais modifiées
+    assert_eq!(cells.len(), 9);
+    assert_eq!(cells[0], (Point2D::new(-1, -1), '\0'));
+    assert_eq!(cells[5], (Point2D::new(0, 1), '#'));
+    assert_eq!(cells[6], (Point2D::new(1, -1), '#'));
+    assert_eq!(cells[8].0, Point2D::new(1, 1));
+    assert_eq!(cells.iter().filter(|&&(_, c)| c == '#').count(), 2);
+}
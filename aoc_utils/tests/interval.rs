@@ -0,0 +1,103 @@
+//! Tests des intervalles d'entiers
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::interval::{Interval, MergeableIntervals}; // Les intervalles testés
+
+#[test]
+fn point_and_interval_containment() {
+    let interval = Interval::new(3, 7);
+    assert_eq!(interval.length(), 5);
+    assert!(interval.contains(3) && interval.contains(7));
+    assert!(!interval.contains(2) && !interval.contains(8));
+    assert!(interval.contains_interval(&Interval::new(4, 7)));
+    assert!(interval.contains_interval(&interval));
+    assert!(!interval.contains_interval(&Interval::new(2, 5)));
+}
+
+#[test]
+fn overlapping_intervals() {
+    let a = Interval::new(1, 5);
+    let b = Interval::new(4, 9);
+    assert!(a.overlaps(&b) && b.overlaps(&a));
+    assert_eq!(a.intersect(&b), Some(Interval::new(4, 5)));
+    assert_eq!(a.union(&b), Some(Interval::new(1, 9)));
+    assert_eq!(a.gap_between(&b), None);
+}
+
+#[test]
+fn adjacent_intervals() {
+    let a = Interval::new(1, 3);
+    let b = Interval::new(4, 6);
+    assert!(!a.overlaps(&b));
+    assert_eq!(a.intersect(&b), None);
+    assert_eq!(b.union(&a), Some(Interval::new(1, 6)));
+    assert_eq!(a.gap_between(&b), Some(0));
+}
+
+#[test]
+fn disjoint_intervals() {
+    let a = Interval::new(1, 3);
+    let b = Interval::new(7, 8);
+    assert!(!a.overlaps(&b));
+    assert_eq!(a.intersect(&b), None);
+    assert_eq!(a.union(&b), None);
+    assert_eq!(a.gap_between(&b), Some(3));
+    assert_eq!(b.gap_between(&a), Some(3));
+}
+
+#[test]
+fn extreme_bounds_do_not_overflow() {
+    let high = Interval::new(i64::MAX - 1, i64::MAX);
+    let low = Interval::new(i64::MIN, i64::MIN + 1);
+    assert_eq!(high.union(&Interval::new(0, 10)), None);
+    assert_eq!(
+        Interval::new(0, i64::MAX - 2).union(&high),
+        Some(Interval::new(0, i64::MAX))
+    );
+    assert_eq!(
+        low.union(&Interval::new(i64::MIN + 2, 0)),
+        Some(Interval::new(i64::MIN, 0))
+    );
+
+    let mut set = MergeableIntervals::new();
+    set.push_and_merge(high);
+    set.push_and_merge(low);
+    set.push_and_merge(Interval::new(i64::MIN, i64::MIN));
+    assert_eq!(set.intervals(), [low, high]);
+}
+
+#[test]
+fn mergeable_intervals_stay_minimal() {
+    let mut set = MergeableIntervals::new();
+    // Ajouts dans le désordre, avec chevauchements et adjacences
+    let pushes = [
+        (10, 12),
+        (1, 2),
+        (5, 6),
+        (3, 3),
+        (20, 25),
+        (13, 14),
+        (7, 8),
+        (22, 30),
+        (40, 40),
+    ];
+    for (start, end) in pushes {
+        set.push_and_merge(Interval::new(start, end));
+        // Jamais deux intervalles qui se chevauchent ou se touchent
+        assert!(set
+            .intervals()
+            .windows(2)
+            .all(|pair| pair[0].end + 1 < pair[1].start));
+    }
+    assert_eq!(
+        set.intervals(),
+        [(1, 3), (5, 8), (10, 14), (20, 30), (40, 40)].map(|(s, e)| Interval::new(s, e))
+    );
+    assert_eq!(set.total_length(), 3 + 4 + 5 + 11 + 1);
+    assert!(set.contains(13) && set.contains(40));
+    assert!(!set.contains(4) && !set.contains(35));
+
+    // Un intervalle qui couvre tout fusionne le reste
+    set.push_and_merge(Interval::new(0, 50));
+    assert_eq!(set.intervals(), [Interval::new(0, 50)]);
+}
@@ -0,0 +1,57 @@
+//! Tests des adaptateurs d'itérateurs
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::iter::{is_safe_after_any_removal, skip_one}; // Les fonctions testées
+
+/// Un rapport du jour 2 est sûr s'il est strictement monotone, par pas de 1 à 3
+fn is_safe(levels: &[i32]) -> bool {
+    let increasing = levels.windows(2).all(|w| (1..=3).contains(&(w[1] - w[0])));
+    let decreasing = levels.windows(2).all(|w| (1..=3).contains(&(w[0] - w[1])));
+    increasing || decreasing
+}
+
+#[test]
+fn skips_the_first_element() {
+    assert_eq!(
+        skip_one([1, 2, 3, 4].iter(), 0).collect::<Vec<_>>(),
+        [&2, &3, &4]
+    );
+}
+
+#[test]
+fn skips_the_last_element() {
+    assert_eq!(skip_one(1..=4, 3).collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn skips_a_middle_element() {
+    assert_eq!(skip_one("abcde".chars(), 2).collect::<String>(), "abde");
+}
+
+#[test]
+fn skipping_the_only_element_yields_nothing() {
+    assert_eq!(skip_one(std::iter::once(7), 0).count(), 0);
+    // Un indice hors de la liste ne retire rien
+    assert_eq!(skip_one(1..=3, 10).collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn dampener_on_the_day_2_example() {
+    let reports: [&[i32]; 6] = [
+        &[7, 6, 4, 2, 1],
+        &[1, 2, 7, 8, 9],
+        &[9, 7, 6, 2, 1],
+        &[1, 3, 2, 4, 5],
+        &[8, 6, 4, 4, 1],
+        &[1, 3, 6, 7, 9],
+    ];
+    let safe: Vec<bool> = reports
+        .iter()
+        .map(|report| is_safe_after_any_removal(report, is_safe))
+        .collect();
+    assert_eq!(safe, [true, false, false, true, true, true]);
+    // Retirer le premier ou le dernier niveau suffit parfois
+    assert!(is_safe_after_any_removal(&[9, 1, 2, 3], is_safe));
+    assert!(is_safe_after_any_removal(&[1, 2, 3, 9], is_safe));
+    assert!(!is_safe_after_any_removal(&[], is_safe));
+}
@@ -0,0 +1,52 @@
+//! Tests des claviers de robots du jour 21
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::keypad::{build_keypad_graph, min_presses_at_depth}; // Les fonctions testées
+use std::collections::HashMap; // Pour le cache des appuis
+
+const NUMERIC: [&str; 4] = ["789", "456", "123", " 0A"];
+const DIRECTIONAL: [&str; 2] = [" ^A", "<v>"];
+
+#[test]
+fn numeric_graph_knows_adjacent_moves() {
+    let graph = build_keypad_graph(&NUMERIC);
+    assert_eq!(graph[&'A'][&'0'], ["<A"]);
+    assert_eq!(graph[&'0'][&'2'], ["^A"]);
+    assert_eq!(graph[&'5'][&'6'], [">A"]);
+    assert_eq!(graph[&'8'][&'5'], ["vA"]);
+    assert_eq!(graph[&'3'][&'3'], ["A"]);
+    // Une diagonale a deux chemins les plus courts
+    let mut diagonal = graph[&'2'][&'9'].clone();
+    diagonal.sort();
+    assert_eq!(diagonal, [">^^A", "^>^A", "^^>A"]);
+}
+
+#[test]
+fn moves_never_cross_the_gap() {
+    let numeric = build_keypad_graph(&NUMERIC);
+    // De `A` à `1`, passer d'abord à gauche mènerait au trou
+    assert!(!numeric[&'A'][&'1'].contains(&"<<^A".to_string()));
+    assert_eq!(numeric[&'A'][&'1'].len(), 2);
+    let directional = build_keypad_graph(&DIRECTIONAL);
+    assert_eq!(directional[&'<'][&'^'], [">^A"]);
+}
+
+#[test]
+fn example_codes_have_known_complexity() {
+    let graphs = [
+        build_keypad_graph(&NUMERIC),
+        build_keypad_graph(&DIRECTIONAL),
+    ];
+    let mut memo = HashMap::new();
+    let lengths: Vec<u64> = ["029A", "980A", "179A", "456A", "379A"]
+        .iter()
+        .map(|code| min_presses_at_depth(code, 2, &graphs, &mut memo))
+        .collect();
+    assert_eq!(lengths, [68, 60, 68, 64, 64]);
+    let complexity: u64 = ["029A", "980A", "179A", "456A", "379A"]
+        .iter()
+        .zip(&lengths)
+        .map(|(code, length)| code[..3].parse::<u64>().unwrap() * length)
+        .sum();
+    assert_eq!(complexity, 126384);
+}
@@ -0,0 +1,79 @@
+//! Tests des serrures et des clés du jour 25
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::locks::{batch_fit_count, fits, parse_lock_or_key}; // Les fonctions testées
+
+const EXAMPLE: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####";
+
+fn parse_example() -> (Vec<[u8; 5]>, Vec<[u8; 5]>) {
+    let mut locks = Vec::new();
+    let mut keys = Vec::new();
+    for schematic in EXAMPLE.split("\n\n") {
+        match parse_lock_or_key(schematic).unwrap() {
+            (heights, true) => locks.push(heights),
+            (heights, false) => keys.push(heights),
+        }
+    }
+    (locks, keys)
+}
+
+#[test]
+fn example_heights() {
+    let (locks, keys) = parse_example();
+    assert_eq!(locks, [[0, 5, 3, 4, 3], [1, 2, 0, 5, 3]]);
+    assert_eq!(keys, [[5, 0, 2, 1, 3], [4, 3, 4, 0, 2], [3, 0, 2, 0, 1]]);
+}
+
+#[test]
+fn example_has_three_fitting_pairs() {
+    let (locks, keys) = parse_example();
+    assert_eq!(batch_fit_count(&locks, &keys), 3);
+    // La dernière colonne déborde : 3 + 3 > 5
+    assert!(!fits(&locks[0], &keys[0]));
+    assert!(fits(&locks[0], &keys[2]));
+}
+
+#[test]
+fn malformed_schematic_is_an_error() {
+    assert!(parse_lock_or_key("#####\n.....").is_err());
+    assert!(parse_lock_or_key(".....\n.....\n.....\n.....\n.....\n.....\n.....").is_err());
+}
@@ -0,0 +1,60 @@
+//! Tests des tables de correspondance indexées
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::lookup::{CharTable, LookupTable}; // Les tables testées
+
+#[test]
+fn unset_keys_keep_the_default() {
+    let table = LookupTable::new(10, -1);
+    assert_eq!(table.len(), 10);
+    assert!(!table.is_empty());
+    assert!((0..10usize).all(|key| *table.get(key) == -1));
+    assert!(LookupTable::new(0, 'x').is_empty());
+}
+
+#[test]
+fn sets_and_gets_at_the_boundaries() {
+    let mut table = LookupTable::new(256, 0u32);
+    table.set(0usize, 11);
+    table.set(255u8, 22);
+    assert_eq!(*table.get(0usize), 11);
+    assert_eq!(*table.get(255u8), 22);
+    // Les voisines ne changent pas
+    assert_eq!(*table.get(1usize), 0);
+    assert_eq!(*table.get(254usize), 0);
+}
+
+#[test]
+#[should_panic]
+fn rejects_a_key_past_the_end() {
+    LookupTable::new(4, false).get(4usize);
+}
+
+#[test]
+fn char_table_maps_lowercase_letters() {
+    let mut table = CharTable::new(None);
+    for (i, c) in (b'a'..=b'z').enumerate() {
+        table.set(c, Some(i));
+    }
+    assert_eq!(*table.get(b'a'), Some(0));
+    assert_eq!(*table.get(b'm'), Some(12));
+    assert_eq!(*table.get(b'z'), Some(25));
+    // Les caractères autour de l'alphabet gardent la valeur par défaut
+    assert_eq!(*table.get(b'`'), None);
+    assert_eq!(*table.get(b'{'), None);
+    assert_eq!(*table.get(b'A'), None);
+}
+
+#[test]
+fn char_table_covers_all_ascii() {
+    let mut table = CharTable::new(0u8);
+    table.set(0, 1);
+    table.set(127, 2);
+    assert_eq!((*table.get(0), *table.get(127)), (1, 2));
+}
+
+#[test]
+#[should_panic(expected = "caractère non ASCII")]
+fn char_table_rejects_non_ascii_bytes() {
+    CharTable::new(0u8).get(128);
+}
@@ -0,0 +1,212 @@
+//! Tests des utilitaires mathématiques : exponentiation de matrices, systèmes linéaires et
+//! sommes de sous-ensembles
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::math::{
+    can_subset_sum, count_subsets_summing_to, count_subsets_summing_to_mod, fibonacci_mod,
+    linear_recurrence_term, mat_identity, mat_mul_mod, mat_pow_mod, solve_linear_system_2x2,
+    solve_linear_system_2x2_rational,
+}; // Les fonctions testées
+
+/// Le module premier habituel des énoncés
+const MOD: u64 = 1_000_000_007;
+
+#[test]
+fn fibonacci_terms() {
+    assert_eq!(fibonacci_mod(0, MOD), 0);
+    assert_eq!(fibonacci_mod(1, MOD), 1);
+    assert_eq!(fibonacci_mod(10, MOD), 55);
+    assert_eq!(fibonacci_mod(1000, MOD), 517_691_607);
+}
+
+#[test]
+fn products_do_not_overflow_with_a_large_modulus() {
+    // Le plus grand nombre premier sur 64 bits : les produits intermédiaires dépassent `u64`
+    let modulus = u64::MAX - 58;
+    // F(93) est le plus grand nombre de Fibonacci qui tienne dans un `u64`, il est donc inchangé
+    assert_eq!(fibonacci_mod(93, modulus), 12_200_160_415_121_876_738);
+    let a = vec![vec![modulus - 1]];
+    assert_eq!(mat_mul_mod(&a, &a, modulus), [[1]]);
+}
+
+#[test]
+fn entries_are_reduced_before_multiplying() {
+    // Des coefficients supérieurs au module donnent le même produit que leurs restes
+    let big = vec![vec![u64::MAX, u64::MAX], vec![u64::MAX, u64::MAX]];
+    let reduced: Vec<Vec<u64>> = big
+        .iter()
+        .map(|row| row.iter().map(|v| v % MOD).collect())
+        .collect();
+    assert_eq!(
+        mat_mul_mod(&big, &big, MOD),
+        mat_mul_mod(&reduced, &reduced, MOD)
+    );
+    let modulus = u64::MAX - 58;
+    assert_eq!(
+        mat_mul_mod(&[vec![u64::MAX]], &[vec![u64::MAX]], modulus),
+        [[58 * 58]]
+    );
+}
+
+#[test]
+fn identity_powers_stay_identity() {
+    let identity = mat_identity(3);
+    for k in [0, 1, 2, 7, 1_000_000_000_000] {
+        assert_eq!(mat_pow_mod(&identity, k, MOD), identity);
+    }
+}
+
+#[test]
+fn matrix_powers() {
+    let fib = vec![vec![1, 1], vec![1, 0]];
+    // Q^n = [[F(n+1), F(n)], [F(n), F(n-1)]]
+    assert_eq!(mat_pow_mod(&fib, 10, MOD), [[89, 55], [55, 34]]);
+    assert_eq!(mat_pow_mod(&fib, 0, MOD), mat_identity(2));
+    // Produit d'une matrice 1×2 par une matrice 2×1
+    assert_eq!(mat_mul_mod(&[vec![2, 3]], &[vec![4], vec![5]], MOD), [[23]]);
+}
+
+#[test]
+fn tribonacci_terms() {
+    // 0, 0, 1, 1, 2, 4, 7, 13, 24, 44, 81
+    let term = |n| linear_recurrence_term(&[1, 1, 1], &[0, 0, 1], n, MOD);
+    assert_eq!(
+        (0..11).map(term).collect::<Vec<_>>(),
+        [0, 0, 1, 1, 2, 4, 7, 13, 24, 44, 81]
+    );
+    // Les termes initiaux sont eux aussi réduits
+    assert_eq!(linear_recurrence_term(&[2], &[12], 0, 5), 2);
+    assert_eq!(linear_recurrence_term(&[2], &[1], 10, 1000), 24);
+}
+
+/// Les quatre machines de l'exemple du jour 13 : `(ax, ay, bx, by, px, py)`
+const MACHINES: [(i64, i64, i64, i64, i64, i64); 4] = [
+    (94, 34, 22, 67, 8400, 5400),
+    (26, 66, 67, 21, 12748, 12176),
+    (17, 86, 84, 37, 7870, 6450),
+    (69, 23, 27, 71, 18641, 10279),
+];
+
+fn solve((ax, ay, bx, by, px, py): (i64, i64, i64, i64, i64, i64)) -> Option<(i64, i64)> {
+    solve_linear_system_2x2(ax, bx, ay, by, px, py)
+}
+
+#[test]
+fn first_claw_machine_is_solvable() {
+    assert_eq!(solve(MACHINES[0]), Some((80, 40)));
+}
+
+#[test]
+fn second_claw_machine_has_no_solution() {
+    assert_eq!(solve(MACHINES[1]), None);
+}
+
+#[test]
+fn third_claw_machine_is_solvable() {
+    assert_eq!(solve(MACHINES[2]), Some((38, 86)));
+}
+
+#[test]
+fn fourth_claw_machine_has_no_solution() {
+    assert_eq!(solve(MACHINES[3]), None);
+}
+
+#[test]
+fn degenerate_and_negative_systems_have_no_solution() {
+    // Déterminant nul : les deux équations sont proportionnelles
+    assert_eq!(solve_linear_system_2x2(1, 2, 2, 4, 3, 6), None);
+    // x = 3, y = -1 : on ne peut pas appuyer un nombre négatif de fois
+    assert_eq!(solve_linear_system_2x2(1, 1, 1, -1, 2, 4), None);
+}
+
+#[test]
+fn rational_solution_is_reduced() {
+    // 2x = 1 et 3y = 2
+    assert_eq!(
+        solve_linear_system_2x2_rational(2, 0, 0, 3, 1, 2),
+        Some(((1, 2), (2, 3)))
+    );
+    // Le signe est porté par le numérateur : -x = 1 et 2y = -4
+    assert_eq!(
+        solve_linear_system_2x2_rational(-1, 0, 0, 2, 1, -4),
+        Some(((-1, 1), (-2, 1)))
+    );
+    assert_eq!(solve_linear_system_2x2_rational(1, 2, 2, 4, 3, 6), None);
+}
+
+/// Nombre de sous-ensembles de somme `target`, par énumération de tous les sous-ensembles
+fn subsets_exhaustive(values: &[u64], target: u64) -> u64 {
+    (0..1u32 << values.len())
+        .filter(|mask| {
+            let sum: u64 = (0..values.len())
+                .filter(|i| mask >> i & 1 == 1)
+                .map(|i| values[i])
+                .sum();
+            sum == target
+        })
+        .count() as u64
+}
+
+#[test]
+fn counts_small_subsets() {
+    // {3} et {1, 2}
+    assert_eq!(count_subsets_summing_to(&[1, 2, 3], 3), 2);
+    assert_eq!(count_subsets_summing_to(&[1, 2, 3], 7), 0);
+    // Les éléments égaux sont distingués
+    assert_eq!(count_subsets_summing_to(&[1, 1], 1), 2);
+    assert!(can_subset_sum(&[1, 2, 3], 6));
+    assert!(!can_subset_sum(&[1, 2, 3], 7));
+}
+
+#[test]
+fn counts_subsets_of_empty_and_single_lists() {
+    // Seul le sous-ensemble vide a une somme nulle
+    assert_eq!(count_subsets_summing_to(&[], 0), 1);
+    assert_eq!(count_subsets_summing_to(&[], 5), 0);
+    assert_eq!(count_subsets_summing_to(&[5], 5), 1);
+    assert_eq!(count_subsets_summing_to(&[5], 4), 0);
+    assert!(can_subset_sum(&[], 0));
+    assert!(!can_subset_sum(&[], 1));
+    assert!(can_subset_sum(&[200], 200));
+}
+
+#[test]
+fn subset_counts_match_exhaustive_search() {
+    let mut rng = fastrand::Rng::with_seed(136);
+    for _ in 0..100 {
+        let values: Vec<u64> = (0..rng.usize(0..12)).map(|_| rng.u64(0..40)).collect();
+        let target = rng.u64(0..150);
+        let expected = subsets_exhaustive(&values, target);
+        assert_eq!(
+            count_subsets_summing_to(&values, target),
+            expected,
+            "{:?} -> {}",
+            values,
+            target
+        );
+        assert_eq!(count_subsets_summing_to_mod(&values, target, MOD), expected);
+        assert_eq!(can_subset_sum(&values, target), expected > 0);
+    }
+}
+
+#[test]
+fn modular_count_matches_the_plain_count() {
+    let values: Vec<u64> = (1..=40).collect();
+    let plain = count_subsets_summing_to(&values, 410);
+    assert_eq!(count_subsets_summing_to_mod(&values, 410, MOD), plain % MOD);
+    assert_eq!(count_subsets_summing_to_mod(&values, 410, 1), 0);
+    // 64 uns : C(64, 32) sous-ensembles de somme 32, qui tient encore dans un `u64`
+    let ones = vec![1; 64];
+    assert_eq!(
+        count_subsets_summing_to_mod(&ones, 32, MOD),
+        count_subsets_summing_to(&ones, 32) % MOD
+    );
+}
+
+#[test]
+fn subset_counts_saturate_instead_of_overflowing() {
+    // C(70, 35) dépasse `u64::MAX`, mais seules les sommes intermédiaires sont concernées
+    let ones = vec![1; 70];
+    assert_eq!(count_subsets_summing_to(&ones, 69), 70);
+    assert_eq!(count_subsets_summing_to(&ones, 35), u64::MAX);
+}
@@ -0,0 +1,120 @@
+//! Tests des méthodes d'optimisation
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::optimize::{simulated_annealing, BranchAndBound}; // Les méthodes testées
+
+/// Énergie d'un état : un puits quadratique centré sur 42
+fn quadratic(x: &i64) -> f64 {
+    ((x - 42) * (x - 42)) as f64
+}
+
+/// Voisin aléatoire d'autant plus éloigné que la température est haute
+fn neighbor(x: &i64, temperature: f64) -> i64 {
+    let step = temperature.sqrt().max(1.0) as i64;
+    x + fastrand::i64(-step..=step)
+}
+
+#[test]
+fn annealing_finds_the_quadratic_minimum() {
+    fastrand::seed(2024);
+    let start = -10_000;
+    let best = simulated_annealing(start, neighbor, quadratic, 20_000, 10_000.0, 0.01);
+    assert!(quadratic(&best) < quadratic(&start));
+    // Le meilleur état rencontré est au fond du puits (à une case près)
+    assert!((best - 42).abs() <= 1, "minimum trouvé en {}", best);
+}
+
+#[test]
+fn annealing_never_returns_a_worse_state() {
+    fastrand::seed(7);
+    // Déjà au minimum : aucun voisin ne peut faire mieux
+    assert_eq!(
+        simulated_annealing(42, neighbor, quadratic, 1_000, 100.0, 0.1),
+        42
+    );
+    // Sans itération, l'état initial est retourné tel quel
+    assert_eq!(
+        simulated_annealing(-5, neighbor, quadratic, 0, 100.0, 0.1),
+        -5
+    );
+}
+
+/// Sac à dos : objets `(poids, valeur)`, on cherche la valeur maximale sans dépasser `capacity`
+/// L'état `(index, poids, valeur)` indique les objets déjà décidés et le contenu du sac
+fn knapsack_branch_and_bound(
+    items: &[(u32, i64)],
+    capacity: u32,
+) -> Option<((usize, u32, i64), i64)> {
+    let mut solver = BranchAndBound::new(
+        (0, 0, 0),
+        |&(index, weight, value): &(usize, u32, i64)| {
+            let (item_weight, item_value) = items[index];
+            let mut children = vec![(index + 1, weight, value)]; // Sans l'objet
+            if weight + item_weight <= capacity {
+                children.push((index + 1, weight + item_weight, value + item_value));
+                // Avec l'objet
+            }
+            children
+        },
+        // Borne inférieure du coût : prendre tous les objets restants, sans tenir compte du poids
+        |&(index, _, value)| -(value + items[index..].iter().map(|&(_, v)| v).sum::<i64>()),
+        |&(index, _, _)| index == items.len(),
+    );
+    solver.solve()
+}
+
+/// Meilleure valeur par énumération de tous les sous-ensembles d'objets
+fn knapsack_exhaustive(items: &[(u32, i64)], capacity: u32) -> i64 {
+    (0..1u32 << items.len())
+        .filter_map(|mask| {
+            let chosen = items
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| mask >> i & 1 == 1);
+            let (weight, value) = chosen.fold((0, 0), |(w, v), (_, &(iw, iv))| (w + iw, v + iv));
+            (weight <= capacity).then_some(value)
+        })
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn branch_and_bound_solves_a_knapsack() {
+    let items = [(12, 4), (2, 2), (1, 1), (1, 2), (4, 10)];
+    // Tous les objets sauf le plus lourd : poids 8, valeur 15
+    let ((_, weight, value), cost) = knapsack_branch_and_bound(&items, 15).unwrap();
+    assert_eq!((weight, value, cost), (8, 15, -15));
+    // Un sac vide ne peut rien contenir
+    assert_eq!(knapsack_branch_and_bound(&items, 0).unwrap().1, 0);
+}
+
+#[test]
+fn branch_and_bound_matches_exhaustive_search() {
+    let mut rng = fastrand::Rng::with_seed(133);
+    for _ in 0..50 {
+        let items: Vec<(u32, i64)> = (0..rng.usize(1..=10))
+            .map(|_| (rng.u32(1..20), rng.i64(1..30)))
+            .collect();
+        let capacity = rng.u32(0..60);
+        let (_, cost) = knapsack_branch_and_bound(&items, capacity).unwrap();
+        assert_eq!(
+            -cost,
+            knapsack_exhaustive(&items, capacity),
+            "{:?}, capacité {}",
+            items,
+            capacity
+        );
+    }
+}
+
+#[test]
+fn branch_and_bound_without_terminal_state() {
+    // Aucun état terminal n'est atteignable depuis la racine
+    let mut solver = BranchAndBound::new(
+        0u8,
+        |&n| if n < 3 { vec![n + 1] } else { vec![] },
+        |_| 0,
+        |_| false,
+    );
+    assert_eq!(solver.solve(), None);
+}
@@ -0,0 +1,254 @@
+//! Tests de la lecture des entrées
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::direction::Direction; // Les mouvements du robot du jour 15
+use aoc_utils::parse::{
+    extract_first_number, extract_numbers, parse_grid_enumerate, parse_grid_filter_map,
+    parse_initial_and_rules, parse_initial_counts, parse_template, read_grid_and_commands,
+    read_grid_and_directions,
+}; // Les fonctions testées
+use aoc_utils::point::Point2D; // La position du robot
+use std::cell::Cell; // Pour compter les cases converties
+use std::collections::HashMap; // Les comptes de l'état initial
+
+#[test]
+fn extracts_button_offsets() {
+    // L'exemple du jour 13
+    assert_eq!(extract_numbers::<i64>("Button A: X+94, Y+34"), vec![94, 34]);
+    assert_eq!(
+        extract_first_number::<i64>("Button A: X+94, Y+34"),
+        Some(94)
+    );
+}
+
+#[test]
+fn extracts_negative_numbers_in_context() {
+    // Les vitesses négatives du jour 14
+    assert_eq!(extract_numbers::<i64>("p=0,4 v=3,-3"), vec![0, 4, 3, -3]);
+    assert_eq!(extract_first_number::<i32>("x=-12 y=5"), Some(-12));
+    // Pour un type non signé, le signe est ignoré
+    assert_eq!(extract_numbers::<u32>("v=3,-3"), vec![3, 3]);
+}
+
+#[test]
+fn extracts_adjacent_numbers() {
+    // Des nombres séparés par une seule lettre ou collés par un signe
+    assert_eq!(extract_numbers::<u8>("1a2b3"), vec![1, 2, 3]);
+    assert_eq!(extract_numbers::<i64>("12-34"), vec![12, -34]);
+}
+
+#[test]
+fn finds_nothing_without_digits() {
+    assert!(extract_numbers::<i64>("aucun nombre ici - vraiment").is_empty());
+    assert!(extract_numbers::<i64>("").is_empty());
+    assert_eq!(extract_first_number::<i64>("---"), None);
+}
+
+#[test]
+fn skips_numbers_that_overflow() {
+    // Un nombre de 100 chiffres ne tient pas dans un `i64` : il est ignoré sans panique
+    let huge = "9".repeat(100);
+    let line = format!("a=1 b={} c=3", huge);
+    assert_eq!(extract_numbers::<i64>(&line), vec![1, 3]);
+    assert_eq!(extract_first_number::<u8>("300 42"), Some(42));
+}
+
+#[test]
+fn template_captures_the_placeholders() {
+    assert_eq!(
+        parse_template("Button A: X+94, Y+34", "Button A: X+{}, Y+{}"),
+        Some(vec!["94", "34"])
+    );
+    assert_eq!(
+        parse_template("p=0,4 v=3,-3", "p={},{} v={},{}"),
+        Some(vec!["0", "4", "3", "-3"])
+    );
+    // Une capture peut être vide
+    assert_eq!(parse_template("a=,b=2", "a={},b={}"), Some(vec!["", "2"]));
+}
+
+#[test]
+fn template_rejects_other_lines() {
+    assert_eq!(
+        parse_template("Button B: X+22, Y+67", "Button A: X+{}, Y+{}"),
+        None
+    );
+    assert_eq!(parse_template("Prize: X=8400", "Prize: X={}, Y={}"), None);
+    assert_eq!(parse_template("", "x={}"), None);
+}
+
+#[test]
+fn template_without_placeholders_must_match_exactly() {
+    assert_eq!(parse_template("Register A", "Register A"), Some(vec![]));
+    assert_eq!(parse_template("Register AB", "Register A"), None);
+    assert_eq!(parse_template("Register", "Register A"), None);
+}
+
+#[test]
+fn template_checks_leading_and_trailing_literals() {
+    assert_eq!(parse_template("[12]", "[{}]"), Some(vec!["12"]));
+    assert_eq!(parse_template("[12", "[{}]"), None);
+    assert_eq!(parse_template(" 12]", "[{}]"), None);
+    // Sans littéral autour, la capture prend toute la ligne
+    assert_eq!(parse_template("tout", "{}"), Some(vec!["tout"]));
+    assert_eq!(
+        parse_template("12 -> z00", "{} -> {}"),
+        Some(vec!["12", "z00"])
+    );
+}
+
+/// Carte topographique de l'exemple du jour 10
+const TOPOGRAPHY: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+#[test]
+fn enumerates_cells_in_row_major_order() {
+    let cells: Vec<(usize, usize, char)> = parse_grid_enumerate("ab\ncd\n", |c| c).collect();
+    assert_eq!(cells, [(0, 0, 'a'), (0, 1, 'b'), (1, 0, 'c'), (1, 1, 'd')]);
+    // Les lignes vides sont ignorées sans décaler les numéros de ligne suivants
+    let cells: Vec<_> = parse_grid_enumerate("\nx\n\ny", |c| c).collect();
+    assert_eq!(cells, [(0, 0, 'x'), (1, 0, 'y')]);
+    assert_eq!(parse_grid_enumerate(TOPOGRAPHY, |c| c).count(), 64);
+}
+
+#[test]
+fn keeps_only_the_trailheads() {
+    let trailheads: Vec<(usize, usize, u32)> =
+        parse_grid_filter_map(TOPOGRAPHY, |c| c.to_digit(10).filter(|&h| h == 0)).collect();
+    assert_eq!(trailheads.len(), 9);
+    assert_eq!(trailheads[0], (0, 2, 0));
+    assert_eq!(trailheads[8], (7, 1, 0));
+}
+
+#[test]
+fn enumeration_is_lazy() {
+    let converted = Cell::new(0);
+    let mut cells = parse_grid_enumerate(TOPOGRAPHY, |c| {
+        converted.set(converted.get() + 1);
+        c
+    });
+    // Seules les cases consommées sont converties
+    assert_eq!(converted.get(), 0);
+    assert_eq!(cells.nth(2), Some((0, 2, '0')));
+    assert_eq!(converted.get(), 3);
+}
+
+/// Petit exemple du jour 15
+const WAREHOUSE: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+#[test]
+fn reads_the_day_15_small_example() {
+    let (grid, commands) = read_grid_and_commands(WAREHOUSE).unwrap();
+    assert_eq!((grid.width(), grid.height()), (8, 8));
+    assert_eq!(grid.get(Point2D::new(2, 2)), Some(&'@'));
+    assert_eq!(grid.find_all(&'O').len(), 6);
+    assert_eq!(commands.iter().collect::<String>(), "<^^>>>vv<v>>v<<");
+
+    let (_, directions) = read_grid_and_directions(WAREHOUSE).unwrap();
+    assert_eq!(directions.len(), 15);
+    assert_eq!(
+        directions[..3],
+        [Direction::West, Direction::North, Direction::North]
+    );
+}
+
+#[test]
+fn joins_multi_line_commands() {
+    let (grid, commands) = read_grid_and_commands("\n#@#\n\n<>\nv^\r\n\n<\n").unwrap();
+    assert_eq!(grid.width(), 3);
+    assert_eq!(commands, ['<', '>', 'v', '^', '<']);
+}
+
+#[test]
+fn reports_a_missing_separator() {
+    let error = read_grid_and_commands("#@#\n#.#").unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("ligne vide séparant la grille des commandes introuvable"));
+    // Une grille irrégulière est signalée avec sa ligne
+    let error = read_grid_and_commands("###\n#@\n\n<").unwrap_err();
+    assert!(error.to_string().contains("ligne 2"), "{}", error);
+    // Une flèche inconnue est refusée
+    let error = read_grid_and_directions("#@#\n\n<x").unwrap_err();
+    assert!(error.to_string().contains("direction inconnue"));
+}
+
+/// Gabarit et règles d'insertion d'un polymère (jour 14 de 2021)
+const POLYMER: &str = "\
+NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+";
+
+#[test]
+fn reads_a_day_11_style_input() {
+    // Pas de règle : seule la ligne des pierres compte
+    let (stones, rules) =
+        parse_initial_and_rules("125 17\n", extract_numbers::<u64>, |rule| rule.to_string())
+            .unwrap();
+    assert_eq!(stones, [125, 17]);
+    assert!(rules.is_empty());
+
+    let counts: HashMap<u64, u64> = parse_initial_counts("0 1 10 99 999 1 1\n").unwrap();
+    assert_eq!(counts.len(), 5);
+    assert_eq!(counts[&1], 3);
+    assert_eq!(counts[&999], 1);
+}
+
+#[test]
+fn reads_a_day_14_style_input() {
+    let (template, rules) = parse_initial_and_rules(
+        POLYMER,
+        |state| state.to_string(),
+        |rule| {
+            parse_template(rule, "{} -> {}")
+                .map(|parts| (parts[0].to_string(), parts[1].chars().next()))
+        },
+    )
+    .unwrap();
+    assert_eq!(template, "NNCB");
+    assert_eq!(rules.len(), 4);
+    assert_eq!(rules[0], Some(("CH".to_string(), Some('B'))));
+    assert_eq!(rules[3], Some(("NH".to_string(), Some('C'))));
+}
+
+#[test]
+fn first_line_is_the_state_without_blank_line() {
+    let (state, rules) =
+        parse_initial_and_rules("\nabc\nr1\nr2", |s| s.len(), str::to_string).unwrap();
+    assert_eq!(state, 3);
+    assert_eq!(rules, ["r1", "r2"]);
+}
+
+#[test]
+fn rejects_empty_inputs() {
+    assert!(parse_initial_and_rules("\n\n", |s| s.len(), |r| r.len()).is_err());
+    assert!(parse_initial_counts::<u64>("  \n").is_err());
+    let error = parse_initial_counts::<u64>("1 deux 3").unwrap_err();
+    assert!(
+        error.to_string().contains("clé initiale invalide"),
+        "{}",
+        error
+    );
+}
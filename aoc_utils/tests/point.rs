@@ -0,0 +1,49 @@
+//! Tests des conventions de coordonnées
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::direction::Direction; // Les directions associées aux pas d'écran
+use aoc_utils::grid::Grid; // Pour suivre les pas sur une vraie carte
+use aoc_utils::point::{
+    rowcol_to_xy, xy_to_rowcol, RowCol, SCREEN_EAST, SCREEN_NORTH, SCREEN_SOUTH, SCREEN_WEST, XY,
+}; // Les conventions testées
+
+#[test]
+fn conversions_are_inverses() {
+    let mut rng = fastrand::Rng::with_seed(127);
+    for _ in 0..100 {
+        let p = XY {
+            x: rng.i64(-1000..1000),
+            y: rng.i64(-1000..1000),
+        };
+        assert_eq!(rowcol_to_xy(xy_to_rowcol(p)), p);
+        assert_eq!(xy_to_rowcol(rowcol_to_xy(p)), p);
+    }
+}
+
+#[test]
+fn conversion_swaps_the_axes() {
+    // `p=3,1` dans l'énoncé du jour 14 : colonne 3, ligne 1
+    let rc = xy_to_rowcol(XY { x: 3, y: 1 });
+    assert_eq!(rc, RowCol { x: 1, y: 3 });
+    let grid = Grid::parse("....\n...#", |c| c);
+    assert_eq!(grid.get(rc), Some(&'#'));
+}
+
+#[test]
+fn screen_steps_move_as_expected() {
+    // Carte 3×3 dont le centre est `o`, et chaque voisin porte la direction qui y mène
+    let grid = Grid::parse(".N.\nWoE\n.S.", |c| c);
+    let center = grid.iter().find(|&(_, &c)| c == 'o').unwrap().0;
+    assert_eq!(grid.get(center + SCREEN_NORTH), Some(&'N'));
+    assert_eq!(grid.get(center + SCREEN_SOUTH), Some(&'S'));
+    assert_eq!(grid.get(center + SCREEN_EAST), Some(&'E'));
+    assert_eq!(grid.get(center + SCREEN_WEST), Some(&'W'));
+    // Le nord diminue la ligne, l'est augmente la colonne
+    assert_eq!(SCREEN_NORTH.x, -1);
+    assert_eq!(SCREEN_EAST.y, 1);
+    // Les directions s'appuient sur les mêmes pas
+    assert_eq!(Direction::North.delta(), SCREEN_NORTH);
+    assert_eq!(Direction::West.delta(), SCREEN_WEST);
+    assert_eq!(SCREEN_NORTH + SCREEN_SOUTH, RowCol::default());
+    assert_eq!(SCREEN_EAST + SCREEN_WEST, RowCol::default());
+}
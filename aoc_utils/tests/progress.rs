@@ -0,0 +1,33 @@
+//! Tests des barres de progression (fonctionnalité `progress`)
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::progress::{ProgressIter, ProgressIteratorExt}; // L'itérateur testé
+
+#[test]
+fn yields_every_item_with_a_known_length() {
+    let items: Vec<u32> = (0..1000).progress(1000).collect();
+    assert_eq!(items, (0..1000).collect::<Vec<_>>());
+}
+
+#[test]
+fn yields_every_item_with_a_spinner() {
+    let words = ["a", "b", "c"];
+    let items: Vec<&str> = ProgressIter::new_spinner(words.into_iter()).collect();
+    assert_eq!(items, words);
+}
+
+#[test]
+fn keeps_the_size_hint_of_the_wrapped_iterator() {
+    let iter = vec![1, 2, 3].into_iter().progress(3);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.sum::<i32>(), 6);
+}
+
+#[test]
+fn can_be_dropped_before_the_end() {
+    // La barre est nettoyée même si l'itérateur n'est pas consommé entièrement
+    let mut iter = ProgressIter::new(0..10, 10);
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.find(|&n| n > 4), Some(5));
+    drop(iter);
+}
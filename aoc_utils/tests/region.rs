@@ -0,0 +1,78 @@
+//! Tests du découpage en régions, du comptage des côtés et du prix des clôtures du jour 12
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::grid::Grid; // Pour les cartes des exemples
+use aoc_utils::point::Point2D; // Les cases d'une région
+use aoc_utils::region::{count_fence_sides, count_runs, total_perimeter_price, total_side_price}; // Les fonctions testées
+use std::collections::HashSet; // Pour les cases d'une région
+
+const SIMPLE: &str = "AAAA\nBBCD\nBBCC\nEEEC";
+
+const NESTED: &str = "OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO";
+
+const E_SHAPED: &str = "EEEEE\nEXXXX\nEEEEE\nEXXXX\nEEEEE";
+
+const LARGER: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE";
+
+/// Cases portant la lettre `plant` dans la carte
+fn region_of(input: &str, plant: char) -> HashSet<Point2D<i64>> {
+    Grid::parse(input, |c| c)
+        .find_all(&plant)
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn counts_runs_of_consecutive_values() {
+    assert_eq!(count_runs(&[1, 2, 3]), 1);
+    assert_eq!(count_runs(&[1, 3, 5]), 3);
+    assert_eq!(count_runs(&[1, 2, 3, 5, 6]), 2);
+    assert_eq!(count_runs(&[-2, -1, 0]), 1);
+    assert_eq!(count_runs(&[]), 0);
+}
+
+#[test]
+fn counts_the_sides_of_the_e_shaped_region() {
+    // La région en forme de E a 12 côtés, chaque rangée de X en a 4
+    assert_eq!(count_fence_sides(&region_of(E_SHAPED, 'E')), 12);
+    let x = region_of(E_SHAPED, 'X');
+    let first_row: HashSet<_> = x.iter().copied().filter(|p| p.x == 1).collect();
+    assert_eq!(count_fence_sides(&first_row), 4);
+    // Une case seule a quatre côtés
+    assert_eq!(count_fence_sides(&HashSet::from([Point2D::new(0, 0)])), 4);
+}
+
+fn prices(input: &str) -> (u64, u64) {
+    let grid = Grid::parse(input, |c| c);
+    (total_perimeter_price(&grid), total_side_price(&grid))
+}
+
+#[test]
+fn simple_example_prices() {
+    assert_eq!(prices(SIMPLE), (140, 80));
+}
+
+#[test]
+fn nested_example_prices() {
+    assert_eq!(prices(NESTED), (772, 436));
+}
+
+#[test]
+fn e_shaped_example_side_price() {
+    assert_eq!(prices(E_SHAPED).1, 236);
+}
+
+#[test]
+fn larger_example_prices() {
+    assert_eq!(prices(LARGER), (1930, 1206));
+}
@@ -0,0 +1,95 @@
+//! Tests du robot sur une grille, avec la patrouille du garde du jour 6
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::direction::Direction; // Orientation du garde
+use aoc_utils::grid::Grid; // La carte du laboratoire
+use aoc_utils::point::Point2D; // Position du garde
+use aoc_utils::robot_grid::RobotGrid; // Le robot testé
+use std::collections::HashSet; // Pour les cases et les états déjà vus
+
+const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...";
+
+fn guard(input: &str) -> RobotGrid<char> {
+    let grid = Grid::parse(input, |c| c);
+    let start = grid.iter().find(|&(_, &c)| c == '^').unwrap().0;
+    RobotGrid::new(grid, start, Direction::North, |&c| c == '#')
+}
+
+/// Fait patrouiller le garde : il avance jusqu'à un obstacle puis tourne à droite, jusqu'à sortir
+/// Retourne les cases visitées, ou `None` si le garde tourne en rond
+fn patrol(mut robot: RobotGrid<char>) -> Option<HashSet<Point2D<i64>>> {
+    let mut visited = HashSet::from([robot.pos]);
+    let mut states = HashSet::from([(robot.pos, robot.dir)]);
+    while !robot.is_out_of_bounds() {
+        while robot.move_forward() {
+            visited.insert(robot.pos);
+        }
+        if !robot.is_out_of_bounds() {
+            robot.turn_right();
+            // Un état déjà vu au pied d'un obstacle signifie une boucle
+            if !states.insert((robot.pos, robot.dir)) {
+                return None;
+            }
+        }
+    }
+    Some(visited)
+}
+
+#[test]
+fn guard_visits_forty_one_cells() {
+    assert_eq!(patrol(guard(EXAMPLE)).unwrap().len(), 41);
+}
+
+#[test]
+fn six_obstructions_trap_the_guard() {
+    let robot = guard(EXAMPLE);
+    let start = robot.pos;
+    let candidates = patrol(guard(EXAMPLE)).unwrap();
+    let loops = candidates
+        .into_iter()
+        .filter(|&pos| pos != start)
+        .filter(|&pos| {
+            let mut trapped = guard(EXAMPLE);
+            trapped.grid.set(pos, '#');
+            patrol(trapped).is_none()
+        })
+        .count();
+    assert_eq!(loops, 6);
+}
+
+#[test]
+fn step_until_blocked_stops_before_obstacles() {
+    let mut robot = guard(EXAMPLE);
+    assert_eq!(robot.look_ahead(), Some(&'.'));
+    // Le garde monte de (6, 4) jusqu'à (1, 4), sous l'obstacle de la première ligne
+    assert_eq!(robot.step_until_blocked(), 5);
+    assert_eq!(robot.pos, Point2D::new(1, 4));
+    assert_eq!(robot.look_ahead(), Some(&'#'));
+    assert!(!robot.move_forward());
+
+    robot.turn_right();
+    assert_eq!(robot.dir, Direction::East);
+    assert_eq!(robot.step_until_blocked(), 4);
+    robot.turn_left();
+    robot.turn_left();
+    assert_eq!(robot.dir, Direction::West);
+}
+
+#[test]
+fn leaving_the_grid_is_out_of_bounds() {
+    let mut robot = guard("...\n.^.\n...");
+    assert_eq!(robot.step_until_blocked(), 1);
+    assert!(robot.is_out_of_bounds());
+    assert_eq!(robot.look_ahead(), None);
+    assert!(!robot.move_forward());
+}
@@ -0,0 +1,134 @@
+//! Tests de la simulation des robots du jour 14
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::parse::extract_numbers; // Pour lire les robots de l'exemple
+use aoc_utils::point::XY; // Positions et vitesses des robots
+use aoc_utils::robots::{find_minimum_safety_step, safety_factor, simulate_robot, simulate_robots}; // Les fonctions testées
+
+/// Largeur et hauteur de la grille de l'exemple
+const WIDTH: i64 = 11;
+const HEIGHT: i64 = 7;
+
+/// Robots de l'exemple du jour 14
+const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+
+fn example_robots() -> Vec<(XY, XY)> {
+    EXAMPLE
+        .lines()
+        .map(|line| match extract_numbers::<i64>(line)[..] {
+            [px, py, vx, vy] => (xy(px, py), xy(vx, vy)),
+            _ => panic!("robot illisible : {}", line),
+        })
+        .collect()
+}
+
+fn xy(x: i64, y: i64) -> XY {
+    XY { x, y }
+}
+
+#[test]
+fn follows_the_example_robot() {
+    // Le robot `p=2,4 v=2,-3` de l'énoncé, seconde par seconde
+    let expected = [xy(2, 4), xy(4, 1), xy(6, 5), xy(8, 2), xy(10, 6), xy(1, 3)];
+    for (steps, &pos) in expected.iter().enumerate() {
+        assert_eq!(
+            simulate_robot(xy(2, 4), xy(2, -3), WIDTH, HEIGHT, steps as i64),
+            pos
+        );
+    }
+}
+
+#[test]
+fn wraps_at_every_edge() {
+    // Sortie par la droite, par le bas, par la gauche et par le haut
+    assert_eq!(
+        simulate_robot(xy(10, 0), xy(1, 0), WIDTH, HEIGHT, 1),
+        xy(0, 0)
+    );
+    assert_eq!(
+        simulate_robot(xy(0, 6), xy(0, 1), WIDTH, HEIGHT, 1),
+        xy(0, 0)
+    );
+    assert_eq!(
+        simulate_robot(xy(0, 0), xy(-1, 0), WIDTH, HEIGHT, 1),
+        xy(10, 0)
+    );
+    assert_eq!(
+        simulate_robot(xy(0, 0), xy(0, -1), WIDTH, HEIGHT, 1),
+        xy(0, 6)
+    );
+    // Un tour complet ramène au départ, même après un grand nombre de secondes
+    assert_eq!(
+        simulate_robot(xy(3, 3), xy(-4, 5), WIDTH, HEIGHT, 77 * 1_000_000),
+        xy(3, 3)
+    );
+}
+
+#[test]
+fn negative_velocities_stay_in_the_grid() {
+    for steps in 0..200 {
+        let pos = simulate_robot(xy(1, 1), xy(-7, -9), WIDTH, HEIGHT, steps);
+        assert!(
+            (0..WIDTH).contains(&pos.x) && (0..HEIGHT).contains(&pos.y),
+            "{:?}",
+            pos
+        );
+    }
+    // Plusieurs robots à la fois
+    let robots = [(xy(0, 4), xy(3, -3)), (xy(6, 3), xy(-1, -3))];
+    assert_eq!(
+        simulate_robots(&robots, WIDTH, HEIGHT, 2),
+        [xy(6, 5), xy(4, 4)]
+    );
+}
+
+#[test]
+fn rem_euclid_wraps_negative_coordinates() {
+    assert_eq!(xy(-1, -1).rem_euclid(WIDTH, HEIGHT), xy(10, 6));
+    assert_eq!(xy(-23, 15).rem_euclid(WIDTH, HEIGHT), xy(10, 1));
+    assert_eq!(xy(11, 7).rem_euclid(WIDTH, HEIGHT), xy(0, 0));
+    assert_eq!(xy(5, 3).rem_euclid(WIDTH, HEIGHT), xy(5, 3));
+}
+
+#[test]
+fn safety_factor_of_the_example() {
+    let positions = simulate_robots(&example_robots(), WIDTH, HEIGHT, 100);
+    // 1, 3, 4 et 1 robots dans les quatre quadrants
+    assert_eq!(safety_factor(&positions, WIDTH, HEIGHT), 12);
+}
+
+#[test]
+fn robots_on_the_middle_lines_are_ignored() {
+    let positions = [xy(0, 0), xy(10, 0), xy(0, 6), xy(10, 6), xy(5, 0), xy(0, 3)];
+    assert_eq!(safety_factor(&positions, WIDTH, HEIGHT), 1);
+    // Un quadrant vide annule le produit
+    assert_eq!(safety_factor(&positions[1..], WIDTH, HEIGHT), 0);
+}
+
+#[test]
+fn finds_the_step_with_the_lowest_safety_factor() {
+    // Trois robots immobiles et un quatrième qui atteint la ligne du milieu à la seconde 2,
+    // vidant le quadrant en bas à droite
+    let robots = [
+        (xy(1, 1), xy(0, 0)),
+        (xy(9, 1), xy(0, 0)),
+        (xy(1, 5), xy(0, 0)),
+        (xy(9, 5), xy(0, -1)),
+    ];
+    assert_eq!(find_minimum_safety_step(&robots, WIDTH, HEIGHT, 7), 2);
+    // La recherche ne va pas au-delà de `max_steps` : en cas d'égalité, la première seconde l'emporte
+    assert_eq!(find_minimum_safety_step(&robots, WIDTH, HEIGHT, 2), 0);
+    assert_eq!(find_minimum_safety_step(&robots, WIDTH, HEIGHT, 0), 0);
+}
@@ -0,0 +1,436 @@
+//! Tests des algorithmes de recherche
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::search::{
+    beam_search, beam_search_unique, bfs_all_paths, bfs_distance_between_all_pairs,
+    bfs_distance_to_all, binary_search_first_true, binary_search_last_false, weighted_bfs,
+    BeamResult,
+}; // Les fonctions testées
+use std::cell::Cell; // Pour compter les états développés
+use std::cmp::Reverse; // Pour préférer les cases proches du but
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque}; // Pour les recherches de référence
+use std::hash::Hash; // Pour les états de la recherche de référence
+
+const MAZE: &str = "\
+S.#......
+.##.####.
+....#..#.
+.####.##.
+......#G.";
+
+const ROOM: &str = "\
+S.........
+..........
+..####....
+..#.......
+..#..####.
+..#.......
+..........
+.....###..
+..........
+.........G";
+
+/// Une case `(ligne, colonne)` du labyrinthe
+type Pos = (i64, i64);
+
+/// Labyrinthe lu ligne par ligne
+struct Maze(Vec<Vec<char>>);
+
+impl Maze {
+    fn parse(input: &str) -> Self {
+        Maze(input.lines().map(|line| line.chars().collect()).collect())
+    }
+
+    /// Position du premier caractère `target`
+    fn find(&self, target: char) -> Pos {
+        self.0
+            .iter()
+            .enumerate()
+            .find_map(|(row, line)| {
+                let col = line.iter().position(|&c| c == target)?;
+                Some((row as i64, col as i64))
+            })
+            .unwrap()
+    }
+
+    /// Indique si la case existe et n'est pas un mur
+    fn is_open(&self, (row, col): Pos) -> bool {
+        let cell = usize::try_from(row)
+            .ok()
+            .and_then(|row| self.0.get(row)?.get(usize::try_from(col).ok()?));
+        matches!(cell, Some(&c) if c != '#')
+    }
+
+    /// Cases libres voisines d'une case du labyrinthe
+    fn open_neighbors(&self, (row, col): Pos) -> Vec<Pos> {
+        [
+            (row - 1, col),
+            (row + 1, col),
+            (row, col - 1),
+            (row, col + 1),
+        ]
+        .into_iter()
+        .filter(|&next| self.is_open(next))
+        .collect()
+    }
+
+    /// Distances BFS de référence depuis `start`
+    fn distances(&self, start: Pos) -> HashMap<Pos, u32> {
+        let mut distances = HashMap::from([(start, 0)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(pos) = queue.pop_front() {
+            for next in self.open_neighbors(pos) {
+                if !distances.contains_key(&next) {
+                    distances.insert(next, distances[&pos] + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+        distances
+    }
+}
+
+#[test]
+fn narrow_beam_may_be_sub_optimal() {
+    // États `(attrait, profondeur, nom)` : le chemin le plus attirant (`a`) est aussi le plus long
+    let successors = |&(_, depth, name): &(u8, u32, char)| match name {
+        's' => vec![(9, depth + 1, 'a'), (1, depth + 1, 'b')],
+        'a' => vec![(9, depth + 1, 'c')],
+        'c' | 'b' => vec![(0, depth + 1, 'g')],
+        _ => vec![],
+    };
+    let is_goal = |&(_, _, name): &(u8, u32, char)| name == 'g';
+
+    let narrow = beam_search((0, 0, 's'), 1, successors, is_goal).unwrap();
+    assert_eq!(narrow, BeamResult::Approximate((0, 3, 'g')));
+    let wide = beam_search((0, 0, 's'), usize::MAX, successors, is_goal).unwrap();
+    assert_eq!(wide, BeamResult::Exact((0, 2, 'g')));
+}
+
+#[test]
+fn unbounded_beam_matches_bfs() {
+    let maze = Maze::parse(MAZE);
+    let (start, goal) = (maze.find('S'), maze.find('G'));
+    let distances = maze.distances(start);
+
+    // Chaque état porte sa profondeur : la profondeur du but trouvé est sa distance
+    let found = beam_search(
+        (0, start),
+        usize::MAX,
+        |&(depth, pos)| {
+            maze.open_neighbors(pos)
+                .into_iter()
+                .map(move |next| (depth + 1, next))
+        },
+        |&(_, pos)| pos == goal,
+    )
+    .unwrap();
+    assert!(found.is_exact());
+    assert_eq!(found.state().0, distances[&goal]);
+
+    // Avec la déduplication globale, les états n'ont pas besoin de porter leur profondeur
+    let unique = beam_search_unique(
+        start,
+        usize::MAX,
+        |&pos| maze.open_neighbors(pos),
+        |&pos| pos == goal,
+    );
+    assert_eq!(unique, Some(BeamResult::Exact(goal)));
+    let unreachable = beam_search_unique(
+        start,
+        usize::MAX,
+        |&pos| maze.open_neighbors(pos),
+        |_| false,
+    );
+    assert_eq!(unreachable, None);
+}
+
+#[test]
+fn heuristic_beam_crosses_room_with_bounded_work() {
+    let maze = Maze::parse(ROOM);
+    let (start, goal) = (maze.find('S'), maze.find('G'));
+    let distance_to_goal = |(row, col): Pos| (row - goal.0).abs() + (col - goal.1).abs();
+    let expanded = Cell::new(0);
+    let width = 2;
+
+    // Les états les plus proches du but (à vol d'oiseau) sont les meilleurs ; la profondeur est
+    // bornée pour que le test échoue au lieu de boucler si l'heuristique se perdait
+    let found = beam_search(
+        (Reverse(distance_to_goal(start)), 0, start),
+        width,
+        |&(_, depth, pos)| {
+            expanded.set(expanded.get() + 1);
+            let next = if depth < 100 {
+                maze.open_neighbors(pos)
+            } else {
+                Vec::new()
+            };
+            next.into_iter()
+                .map(move |next| (Reverse(distance_to_goal(next)), depth + 1, next))
+        },
+        |&(_, _, pos)| pos == goal,
+    )
+    .unwrap();
+    let (_, depth, pos) = found.into_state();
+    assert_eq!(pos, goal);
+    // Le faisceau va droit au but et ne développe jamais plus de `width` états par niveau,
+    // bien moins que les cases de la pièce qu'un BFS visiterait
+    assert_eq!(depth, 18);
+    assert!(expanded.get() <= width * depth);
+    assert!(expanded.get() < maze.distances(start).len());
+}
+
+/// Tous les plus courts chemins de `S` à `G` dans une carte
+fn all_paths(map: &str) -> Vec<Vec<Pos>> {
+    let maze = Maze::parse(map);
+    let (start, goal) = (maze.find('S'), maze.find('G'));
+    let mut paths = bfs_all_paths(start, &goal, |&pos| maze.open_neighbors(pos));
+    paths.sort();
+    paths
+}
+
+#[test]
+fn all_paths_around_a_square() {
+    // Deux chemins de même longueur : par la droite ou par le bas
+    let paths = all_paths("S.\n.G");
+    assert_eq!(
+        paths,
+        [vec![(0, 0), (0, 1), (1, 1)], vec![(0, 0), (1, 0), (1, 1)]]
+    );
+    // Dans une salle 3×3 vide, il y a C(4, 2) = 6 plus courts chemins d'un coin à l'autre
+    let paths = all_paths("S..\n...\n..G");
+    assert_eq!(paths.len(), 6);
+    assert!(paths.iter().all(|path| path.len() == 5));
+}
+
+#[test]
+fn all_paths_in_a_corridor() {
+    let paths = all_paths("S..\n##.\nG..");
+    assert_eq!(paths.len(), 1);
+    assert_eq!(paths[0].len(), 7);
+    assert_eq!(paths[0][0], (0, 0));
+    assert_eq!(paths[0][6], (2, 0));
+    // Le départ est lui-même le but
+    let start = (0, 0);
+    assert_eq!(bfs_all_paths(start, &start, |_| Vec::new()), [vec![start]]);
+}
+
+#[test]
+fn all_paths_to_an_unreachable_goal() {
+    assert!(all_paths("S#G").is_empty());
+}
+
+/// Premier exemple du jour 16 (score minimal 7036)
+const REINDEER_MAZE: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############";
+
+/// Second exemple du jour 16 (score minimal 11048)
+const REINDEER_MAZE_2: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################";
+
+/// État du renne : sa case et son orientation `(dligne, dcolonne)`
+type Reindeer = (Pos, Pos);
+
+/// Déplacements du renne : avancer coûte 1, tourner d'un quart de tour coûte 1000
+fn reindeer_moves(maze: &Maze, &(pos, (dr, dc)): &Reindeer) -> Vec<(Reindeer, usize)> {
+    let mut moves = vec![((pos, (-dc, dr)), 1000), ((pos, (dc, -dr)), 1000)];
+    let ahead = (pos.0 + dr, pos.1 + dc);
+    if maze.is_open(ahead) {
+        moves.push(((ahead, (dr, dc)), 1));
+    }
+    moves
+}
+
+/// Dijkstra classique avec un tas binaire, la référence de `weighted_bfs`
+fn dijkstra<S, FN, IT>(start: S, successors: FN) -> HashMap<S, usize>
+where
+    S: Hash + Eq + Ord + Clone,
+    FN: Fn(&S) -> IT,
+    IT: IntoIterator<Item = (S, usize)>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut heap = BinaryHeap::from([Reverse((0, start))]);
+    while let Some(Reverse((distance, state))) = heap.pop() {
+        if distance > distances[&state] {
+            continue;
+        }
+        for (next, cost) in successors(&state) {
+            let candidate = distance + cost;
+            if distances.get(&next).is_none_or(|&d| candidate < d) {
+                distances.insert(next.clone(), candidate);
+                heap.push(Reverse((candidate, next)));
+            }
+        }
+    }
+    distances
+}
+
+/// Distances de chaque état `(case, orientation)` depuis le départ, avec les deux recherches à coûts
+fn reindeer_distances(maze: &Maze) -> (HashMap<Reindeer, usize>, HashMap<Reindeer, usize>) {
+    // Le renne part vers l'est
+    let start = (maze.find('S'), (0, 1));
+    let buckets = weighted_bfs(start, 1000, |state| reindeer_moves(maze, state));
+    let heap = dijkstra(start, |state| reindeer_moves(maze, state));
+    (buckets, heap)
+}
+
+/// Score minimal du jour 16 (quelle que soit l'orientation à l'arrivée), avec les deux recherches
+fn reindeer_scores(map: &str) -> (usize, usize) {
+    let maze = Maze::parse(map);
+    let goal = maze.find('E');
+    let (buckets, heap) = reindeer_distances(&maze);
+    let best = |distances: &HashMap<Reindeer, usize>| {
+        [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .iter()
+            .filter_map(|&dir| distances.get(&(goal, dir)).copied())
+            .min()
+            .unwrap()
+    };
+    (best(&buckets), best(&heap))
+}
+
+#[test]
+fn weighted_bfs_solves_the_day_16_examples() {
+    assert_eq!(reindeer_scores(REINDEER_MAZE), (7036, 7036));
+    assert_eq!(reindeer_scores(REINDEER_MAZE_2), (11048, 11048));
+}
+
+#[test]
+fn weighted_bfs_matches_dijkstra_everywhere() {
+    let (buckets, heap) = reindeer_distances(&Maze::parse(REINDEER_MAZE));
+    // Même ensemble d'états atteints, aux mêmes distances
+    assert_eq!(buckets, heap);
+}
+
+/// Parcours en largeur naïf, arrêté dès que `target` est atteinte
+fn naive_distance(maze: &Maze, start: Pos, target: Pos) -> Option<usize> {
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((pos, distance)) = queue.pop_front() {
+        if pos == target {
+            return Some(distance);
+        }
+        for next in maze.open_neighbors(pos) {
+            if seen.insert(next) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    None
+}
+
+#[test]
+fn distances_to_all_match_per_target_bfs() {
+    let maze = Maze::parse(MAZE);
+    let start = maze.find('S');
+    let distances = bfs_distance_to_all(start, |&pos| maze.open_neighbors(pos));
+    let cells: Vec<Pos> = (0..5)
+        .flat_map(|row| (0..9).map(move |col| (row, col)))
+        .collect();
+    for &target in &cells {
+        assert_eq!(
+            distances.get(&target).copied(),
+            naive_distance(&maze, start, target),
+            "case {:?}",
+            target
+        );
+    }
+    // Seules les cases libres sont atteintes
+    let open = cells.iter().filter(|&&pos| maze.is_open(pos)).count();
+    assert_eq!(distances.len(), open);
+}
+
+#[test]
+fn distances_between_all_pairs_match_per_target_bfs() {
+    let maze = Maze::parse(MAZE);
+    // Un mur n'atteint rien et n'est atteint par personne, sauf par lui-même
+    let sources = [maze.find('S'), maze.find('G'), (2, 5), (0, 2)];
+    let pairs = bfs_distance_between_all_pairs(&sources, |&pos| maze.open_neighbors(pos));
+    for (i, &from) in sources.iter().enumerate().take(3) {
+        for (j, &to) in sources.iter().enumerate().take(3) {
+            assert_eq!(
+                pairs.get(&(i, j)).copied(),
+                naive_distance(&maze, from, to),
+                "paire ({}, {})",
+                i,
+                j
+            );
+        }
+        assert_eq!(pairs.get(&(i, 3)), None);
+    }
+    assert_eq!(pairs[&(3, 3)], 0);
+    assert_eq!(pairs[&(0, 1)], pairs[&(1, 0)]);
+}
+
+#[test]
+fn binary_search_on_a_step_function() {
+    // Faux jusqu'à 41, vrai à partir de 42
+    let step = |n: usize| n >= 42;
+    assert_eq!(binary_search_first_true(0, 100, step), Some(42));
+    assert_eq!(binary_search_last_false(0, 100, step), Some(41));
+    // Le premier vrai est aussi trouvé au bord de l'intervalle
+    assert_eq!(binary_search_first_true(42, 100, step), Some(42));
+    assert_eq!(binary_search_last_false(42, 100, step), None);
+    assert_eq!(binary_search_first_true(0, 43, step), Some(42));
+    assert_eq!(binary_search_last_false(0, 42, step), Some(41));
+}
+
+#[test]
+fn binary_search_when_always_false() {
+    assert_eq!(binary_search_first_true(0, 100, |_| false), None);
+    assert_eq!(binary_search_last_false(0, 100, |_| false), Some(99));
+    // Intervalle vide
+    assert_eq!(binary_search_first_true(5, 5, |_| true), None);
+    assert_eq!(binary_search_last_false(5, 5, |_| false), None);
+}
+
+#[test]
+fn binary_search_when_always_true() {
+    assert_eq!(binary_search_first_true(3, 100, |_| true), Some(3));
+    assert_eq!(binary_search_last_false(3, 100, |_| true), None);
+}
+
+#[test]
+fn binary_search_takes_logarithmic_steps() {
+    // Premier octet qui bloque la sortie, parmi un million
+    let calls = Cell::new(0);
+    let blocked = |n: usize| {
+        calls.set(calls.get() + 1);
+        n >= 765_432
+    };
+    assert_eq!(
+        binary_search_first_true(0, 1_000_000, blocked),
+        Some(765_432)
+    );
+    assert!(calls.get() <= 20, "{} appels", calls.get());
+}
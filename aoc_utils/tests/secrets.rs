@@ -0,0 +1,78 @@
+//! Tests du générateur de nombres secrets et de la vente de bananes du jour 22
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::secrets::{
+    best_sequence, first_occurrence_map, generate_secrets, mine_prng_next, mine_prng_nth,
+    mine_prng_sequence, prices_and_changes, total_bananas_for_sequence,
+}; // Les fonctions testées
+
+#[test]
+fn secrets_match_ten_step_example() {
+    let expected = [
+        15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432, 5908254,
+    ];
+    // Le départ est suivi des secrets générés
+    let secrets = generate_secrets(123, 10);
+    assert_eq!(secrets[0], 123);
+    assert_eq!(secrets[1..], expected);
+    assert_eq!(generate_secrets(2024, 2000).last(), Some(&8667524));
+}
+
+#[test]
+fn next_secret_matches_example() {
+    assert_eq!(mine_prng_next(123), 15887950);
+}
+
+#[test]
+fn sequence_matches_ten_step_example() {
+    let expected = [
+        15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432, 5908254,
+    ];
+    assert_eq!(mine_prng_sequence(123, 10), expected);
+}
+
+#[test]
+fn two_thousandth_secret_matches_example() {
+    assert_eq!(mine_prng_nth(1, 2000), 8685429);
+    assert_eq!(mine_prng_nth(10, 2000), 4700978);
+    assert_eq!(mine_prng_nth(100, 2000), 15273692);
+    assert_eq!(mine_prng_nth(2024, 2000), 8667524);
+    assert_eq!(mine_prng_nth(123, 0), 123);
+}
+
+#[test]
+fn next_secret_never_overflows() {
+    // Seuls les 24 bits de poids faible comptent : un nombre énorme se comporte comme son élagage
+    assert_eq!(
+        mine_prng_next(u64::MAX),
+        mine_prng_next(u64::MAX % 16_777_216)
+    );
+    assert_eq!(mine_prng_next((1 << 60) + 123), 15887950);
+}
+
+#[test]
+fn best_sequence_matches_part_two_example() {
+    let first_occurrences: Vec<_> = [1, 2, 3, 2024]
+        .iter()
+        .map(|&start| first_occurrence_map(&prices_and_changes(&generate_secrets(start, 2000))))
+        .collect();
+    assert_eq!(
+        best_sequence(&first_occurrences),
+        Some(([-2, 1, -1, 3], 23))
+    );
+    assert_eq!(
+        total_bananas_for_sequence(&first_occurrences, [-2, 1, -1, 3]),
+        23
+    );
+}
+
+#[test]
+fn prices_follow_the_123_example() {
+    let pairs = prices_and_changes(&generate_secrets(123, 9));
+    let prices: Vec<u8> = pairs.iter().map(|&(price, _)| price).collect();
+    let changes: Vec<i8> = pairs.iter().map(|&(_, change)| change).collect();
+    assert_eq!(prices, [0, 6, 5, 4, 4, 6, 4, 4, 2]);
+    assert_eq!(changes, [-3, 6, -1, -1, 0, 2, -2, 0, -2]);
+    // La suite -1,-1,0,2 apparaît pour la première fois au prix 6
+    assert_eq!(first_occurrence_map(&pairs).get(&[-1, -1, 0, 2]), Some(&6));
+}
@@ -0,0 +1,50 @@
+//! Tests des algorithmes de sélection
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::select::{kth_largest, kth_smallest, median}; // Les fonctions testées
+
+#[test]
+fn smallest_of_the_example() {
+    assert_eq!(kth_smallest(&mut [3, 1, 4, 1, 5, 9], 0), 1);
+    assert_eq!(kth_smallest(&mut [3, 1, 4, 1, 5, 9], 1), 1);
+    assert_eq!(kth_smallest(&mut [3, 1, 4, 1, 5, 9], 5), 9);
+    assert_eq!(kth_largest(&mut [3, 1, 4, 1, 5, 9], 0), 9);
+    assert_eq!(kth_largest(&mut [3, 1, 4, 1, 5, 9], 2), 4);
+}
+
+#[test]
+fn selects_in_a_single_element_slice() {
+    assert_eq!(kth_smallest(&mut ["seul"], 0), "seul");
+    assert_eq!(kth_largest(&mut [7], 0), 7);
+    assert_eq!(median(&mut [7]), 7);
+}
+
+#[test]
+fn median_is_the_lower_one_for_even_lengths() {
+    assert_eq!(median(&mut [5, 2, 9]), 5);
+    // Pour une longueur paire, c'est la médiane inférieure : [1, 2, 3, 4] donne 2
+    assert_eq!(median(&mut [4, 1, 3, 2]), 2);
+    assert_eq!(median(&mut [8, 1]), 1);
+}
+
+#[test]
+fn matches_a_sorted_reference() {
+    let mut rng = fastrand::Rng::with_seed(121);
+    for len in 1..60 {
+        let data: Vec<i32> = (0..len).map(|_| rng.i32(-20..20)).collect();
+        let mut sorted = data.clone();
+        sorted.sort_unstable();
+        for k in 0..data.len() {
+            let mut copy = data.clone();
+            assert_eq!(
+                kth_smallest(&mut copy, k),
+                sorted[k],
+                "k = {} dans {:?}",
+                k,
+                data
+            );
+            let mut copy = data.clone();
+            assert_eq!(kth_largest(&mut copy, k), sorted[len - 1 - k]);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Tests du pavage de motifs du jour 19
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::tiling::{can_tile, count_tilings}; // Les fonctions testées
+
+/// Serviettes de l'exemple du jour 19
+const TOWELS: [&str; 8] = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+
+/// Motifs à reproduire de l'exemple
+const DESIGNS: [&str; 8] = [
+    "brwrr", "bggr", "gbbr", "rrbgbr", "ubwu", "bwurrg", "brgr", "bbrgwb",
+];
+
+#[test]
+fn counts_the_day_19_arrangements() {
+    let ways: Vec<u64> = DESIGNS
+        .iter()
+        .map(|design| count_tilings(design, &TOWELS))
+        .collect();
+    assert_eq!(ways, [2, 1, 4, 6, 0, 1, 2, 0]);
+    assert_eq!(ways.iter().sum::<u64>(), 16);
+}
+
+#[test]
+fn finds_the_possible_day_19_designs() {
+    let possible = DESIGNS
+        .iter()
+        .filter(|design| can_tile(design, &TOWELS))
+        .count();
+    assert_eq!(possible, 6);
+}
+
+#[test]
+fn empty_target_has_one_tiling() {
+    assert_eq!(count_tilings("", &TOWELS), 1);
+    assert_eq!(count_tilings("", &[]), 1);
+    assert!(can_tile("", &[]));
+}
+
+#[test]
+fn impossible_targets_have_no_tiling() {
+    assert_eq!(count_tilings("x", &TOWELS), 0);
+    assert_eq!(count_tilings("abc", &[]), 0);
+    // Un motif plus long que la cible ne sert à rien
+    assert_eq!(count_tilings("ab", &["abc"]), 0);
+    assert!(!can_tile("ubwu", &TOWELS));
+}
+
+#[test]
+fn counts_many_arrangements_without_enumerating_them() {
+    // Avec `a` et `aa`, les pavages de `a` répété n fois suivent la suite de Fibonacci
+    let target = "a".repeat(80);
+    assert_eq!(count_tilings(&target, &["a", "aa"]), 37_889_062_373_143_906);
+}
+
+#[test]
+fn huge_counts_saturate_instead_of_overflowing() {
+    // F(94) pavages pour 93 `a` : le premier nombre de Fibonacci qui dépasse `u64::MAX`
+    assert_eq!(
+        count_tilings(&"a".repeat(92), &["a", "aa"]),
+        12_200_160_415_121_876_738
+    );
+    assert_eq!(count_tilings(&"a".repeat(93), &["a", "aa"]), u64::MAX);
+    assert_eq!(count_tilings(&"a".repeat(200), &["a", "aa"]), u64::MAX);
+}
@@ -0,0 +1,48 @@
+//! Tests des sentiers de randonnée du jour 10
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::grid::Grid; // La carte topographique
+use aoc_utils::point::Point2D; // Pour les départs de sentier
+use aoc_utils::trails::{all_trailhead_metrics, trail_rating, trail_score}; // Les fonctions testées
+
+const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732";
+
+fn parse(input: &str) -> Grid<u8> {
+    // Les `.` des petits exemples deviennent des cases infranchissables
+    Grid::parse(input, |c| c.to_digit(10).map_or(u8::MAX, |d| d as u8))
+}
+
+fn trailheads(grid: &Grid<u8>) -> Vec<Point2D<i64>> {
+    grid.find_all(&0)
+}
+
+#[test]
+fn example_totals() {
+    assert_eq!(all_trailhead_metrics(&parse(EXAMPLE)), (36, 81));
+}
+
+#[test]
+fn example_individual_trailheads() {
+    let grid = parse(EXAMPLE);
+    let starts = trailheads(&grid);
+    let scores: Vec<usize> = starts.iter().map(|&s| trail_score(&grid, s)).collect();
+    let ratings: Vec<usize> = starts.iter().map(|&s| trail_rating(&grid, s)).collect();
+    assert_eq!(scores, [5, 6, 5, 3, 1, 3, 5, 3, 5]);
+    assert_eq!(ratings, [20, 24, 10, 4, 1, 4, 5, 8, 5]);
+}
+
+#[test]
+fn small_examples() {
+    let two_summits = "...0...\n...1...\n...2...\n6543456\n7.....7\n8.....8\n9.....9";
+    assert_eq!(all_trailhead_metrics(&parse(two_summits)).0, 2);
+    let three_trails = ".....0.\n..4321.\n..5..2.\n..6543.\n..7..4.\n..8765.\n..9....";
+    assert_eq!(all_trailhead_metrics(&parse(three_trails)), (1, 3));
+}
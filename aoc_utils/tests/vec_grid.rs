@@ -0,0 +1,85 @@
+//! Tests de la grille ligne par ligne, comparée à la grille plate
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::grid::{Grid, GridLike}; // La grille plate et l'interface commune
+use aoc_utils::point::Point2D; // Les positions des cases
+use aoc_utils::vec_grid::VecGrid; // La grille testée
+
+const MAP: &str = "\
+89010123
+78121874
+87430965
+96549874";
+
+/// Nombre de cases valant `value` autour de chaque case, pour n'importe quelle grille
+fn count_around<G: GridLike<u32>>(grid: &G, value: u32) -> Vec<usize> {
+    let mut counts = Vec::new();
+    for row in 0..grid.height() as i64 {
+        for col in 0..grid.width() as i64 {
+            let pos = Point2D::new(row, col);
+            counts.push(
+                grid.neighbors8(pos)
+                    .filter(|&p| grid.get(p) == Some(&value))
+                    .count(),
+            );
+        }
+    }
+    counts
+}
+
+#[test]
+fn neighbors_match_the_flat_grid() {
+    let flat = Grid::parse(MAP, |c| c.to_digit(10).unwrap());
+    let rows = VecGrid::parse(MAP, |c| c.to_digit(10).unwrap());
+    assert_eq!((rows.width(), rows.height()), (flat.width(), flat.height()));
+    for pos in flat
+        .positions()
+        .chain([Point2D::new(-1, 0), Point2D::new(4, 8)])
+    {
+        assert_eq!(rows.get(pos), flat.get(pos), "case {:?}", pos);
+        assert_eq!(
+            rows.neighbors4(pos).collect::<Vec<_>>(),
+            flat.neighbors4(pos).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            rows.neighbors8(pos).collect::<Vec<_>>(),
+            flat.neighbors8(pos).collect::<Vec<_>>()
+        );
+    }
+    assert_eq!(rows.find_all(&0), flat.find_all(&0));
+    // Un algorithme générique donne le même résultat sur les deux grilles
+    assert_eq!(count_around(&rows, 9), count_around(&flat, 9));
+}
+
+#[test]
+fn round_trip_preserves_every_cell() {
+    let flat = Grid::parse(MAP, |c| c);
+    let rows = VecGrid::from(flat.clone());
+    assert_eq!(
+        rows.row_slice(1),
+        "78121874".chars().collect::<Vec<_>>().as_slice()
+    );
+    assert_eq!(rows.row_slice(3), flat.row_slice(3));
+    assert_eq!(Grid::from(rows.clone()), flat);
+    assert_eq!(VecGrid::from(Grid::from(rows.clone())), rows);
+}
+
+#[test]
+fn set_changes_only_one_cell() {
+    let mut rows = VecGrid::parse(MAP, |c| c);
+    rows.set(Point2D::new(2, 5), '#');
+    assert_eq!(rows.get(Point2D::new(2, 5)), Some(&'#'));
+    assert_eq!(
+        rows.row_slice(2),
+        "87430#65".chars().collect::<Vec<_>>().as_slice()
+    );
+    assert_eq!(
+        rows,
+        VecGrid::from_rows(
+            MAP.replace("87430965", "87430#65")
+                .lines()
+                .map(|l| l.chars().collect())
+                .collect()
+        )
+    );
+}
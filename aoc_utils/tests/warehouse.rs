@@ -0,0 +1,164 @@
+//! Tests des poussées de caisses dans l'entrepôt du jour 15
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::direction::Direction; // Les directions de poussée
+use aoc_utils::grid::Grid; // La carte de l'entrepôt
+use aoc_utils::point::Point2D; // Pour repérer le robot
+use aoc_utils::warehouse::{
+    collect_push_set, gps_sum, gps_sum_from_grid, move_robot, widen_warehouse,
+}; // Les fonctions testées
+
+const SMALL_WIDE: &str = "\
+#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+
+const LARGE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^";
+
+/// Sépare la carte de l'entrepôt de la liste des mouvements
+fn read_input(input: &str) -> (Grid<char>, Vec<Direction>) {
+    let (map, moves) = input.split_once("\n\n").unwrap();
+    (
+        chars(map),
+        moves.chars().filter_map(Direction::from_arrow).collect(),
+    )
+}
+
+/// Exécute tous les mouvements dans l'entrepôt et retourne son état final
+fn run(mut grid: Grid<char>, moves: Vec<Direction>) -> Grid<char> {
+    let mut robot = grid.iter().find(|&(_, &c)| c == '@').unwrap().0;
+    for dir in moves {
+        robot = move_robot(&mut grid, robot, dir);
+    }
+    grid
+}
+
+/// Exécute tous les mouvements dans l'entrepôt élargi et retourne son état final
+fn run_wide(input: &str) -> Grid<char> {
+    let (grid, moves) = read_input(input);
+    run(widen_warehouse(&grid), moves)
+}
+
+fn chars(input: &str) -> Grid<char> {
+    Grid::parse(input, |c| c)
+}
+
+#[test]
+fn small_wide_example() {
+    let grid = run_wide(SMALL_WIDE);
+    let expected = "\
+##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############";
+    assert_eq!(grid, chars(expected));
+}
+
+#[test]
+fn gps_of_a_single_box() {
+    assert_eq!(gps_sum([Point2D::new(1, 2)], 100, 1), 102);
+    assert_eq!(gps_sum([], 100, 1), 0);
+}
+
+#[test]
+fn gps_forms_agree() {
+    let grid = chars("#######\n#.O.O.#\n#..O..#\n#######");
+    let boxes = grid.find_all(&'O');
+    assert_eq!(
+        gps_sum_from_grid(&grid, &'O', 100, 1),
+        gps_sum(boxes, 100, 1)
+    );
+    assert_eq!(gps_sum_from_grid(&grid, &'O', 100, 1), 102 + 104 + 203);
+}
+
+#[test]
+fn large_example() {
+    let (grid, moves) = read_input(LARGE);
+    let grid = run(grid, moves);
+    assert_eq!(gps_sum_from_grid(&grid, &'O', 100, 1), 10092);
+}
+
+#[test]
+fn large_wide_example() {
+    let grid = run_wide(LARGE);
+    assert_eq!(gps_sum_from_grid(&grid, &'[', 100, 1), 9021);
+}
+
+#[test]
+fn convergent_push_moves_each_box_once() {
+    // Deux caisses poussées vers le haut convergent sur la même caisse du dessus
+    let mut grid = chars(
+        "\
+##########
+##......##
+##..[]..##
+##.[][].##
+##..[]..##
+##...@..##
+##########",
+    );
+    let robot = Point2D::new(5, 5);
+    let push_set = collect_push_set(&grid, robot, Direction::North).unwrap();
+    assert_eq!(push_set.len(), 8, "quatre caisses de deux cases");
+
+    let robot = move_robot(&mut grid, robot, Direction::North);
+    assert_eq!(robot, Point2D::new(4, 5));
+    let expected = "\
+##########
+##..[]..##
+##.[][].##
+##..[]..##
+##...@..##
+##......##
+##########";
+    assert_eq!(grid, chars(expected));
+}
+
+#[test]
+fn blocked_push_leaves_the_grid_unchanged() {
+    // La caisse du dessus touche le mur : aucune des autres caisses ne doit bouger
+    let mut grid = chars(
+        "\
+##########
+##..[]..##
+##.[][].##
+##..[]..##
+##...@..##
+##########",
+    );
+    let before = grid.clone();
+    let robot = Point2D::new(4, 5);
+    assert_eq!(collect_push_set(&grid, robot, Direction::North), None);
+    assert_eq!(move_robot(&mut grid, robot, Direction::North), robot);
+    assert_eq!(grid, before);
+}
@@ -0,0 +1,68 @@
+//! Tests de la recherche de mots du jour 4
+
+// Importation des bibliothèques nécessaires
+use aoc_utils::direction::Direction8; // Les directions de lecture
+use aoc_utils::grid::Grid; // La grille de lettres
+use aoc_utils::point::Point2D; // Position de départ d'un mot
+use aoc_utils::word_search::{find_x_pattern, word_search, word_search_count}; // Les fonctions testées
+
+const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX";
+
+fn letters(input: &str) -> Grid<char> {
+    Grid::parse(input, |c| c)
+}
+
+#[test]
+fn example_has_eighteen_xmas() {
+    assert_eq!(word_search_count(&letters(EXAMPLE), "XMAS"), 18);
+}
+
+#[test]
+fn single_row_has_exactly_one_match() {
+    let grid = letters("XMAS");
+    assert_eq!(
+        word_search(&grid, "XMAS"),
+        [(Point2D::new(0, 0), Direction8::East)]
+    );
+    // Lu à l'envers, le mot sortirait de la grille dans toutes les autres directions
+    assert_eq!(word_search_count(&grid, "SAMX"), 1);
+}
+
+#[test]
+fn words_leaving_the_grid_do_not_match() {
+    assert_eq!(word_search_count(&letters("XMA\nMMS\nAAA"), "XMAS"), 0);
+    assert_eq!(word_search_count(&letters("XMAS"), ""), 0);
+}
+
+#[test]
+fn example_has_nine_x_mas() {
+    assert_eq!(find_x_pattern(&letters(EXAMPLE), "MAS"), 9);
+}
+
+#[test]
+fn minimal_grid_has_one_x_mas() {
+    assert_eq!(find_x_pattern(&letters("M.S\n.A.\nM.S"), "MAS"), 1);
+    // Une seule diagonale ne suffit pas
+    assert_eq!(find_x_pattern(&letters("M.M\n.A.\nM.S"), "MAS"), 0);
+}
+
+#[test]
+fn longer_arms_cross_at_their_center() {
+    let grid = letters("X...S\n.M.A.\n..A..\n.M.A.\nX...S");
+    assert_eq!(find_x_pattern(&grid, "XMAAS"), 1);
+    assert_eq!(find_x_pattern(&grid, "XMASX"), 0);
+    assert_eq!(
+        find_x_pattern(&letters("A...A\n.B.B.\n..C..\n.B.B.\nA...A"), "ABCBA"),
+        1
+    );
+}